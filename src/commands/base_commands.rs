@@ -1,5 +1,5 @@
 use chrono::{Local, NaiveDate};
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use clap_complete::Shell;
 
 #[derive(Parser)]
@@ -35,10 +35,75 @@ pub enum Commands {
 
 #[derive(Subcommand)]
 pub enum GetCommands {
-    /// Get throughput data from Jira and serialize to YAML
+    /// Get throughput data from an issue tracker and serialize to YAML
     Throughput(GetThroughputArgs),
-    /// Get project data from Jira and serialize to YAML
+    /// Get project data from an issue tracker and serialize to YAML
     Project(GetProjectArgs),
+    /// Get per-issue cycle times from an issue tracker and serialize to YAML
+    #[command(name = "cycle-time")]
+    CycleTime(GetCycleTimeArgs),
+}
+
+/// Issue tracker the `get` commands read from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DataSourceKind {
+    Jira,
+    #[value(name = "github")]
+    GitHub,
+    #[value(name = "gitlab")]
+    GitLab,
+    Csv,
+}
+
+impl std::fmt::Display for DataSourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DataSourceKind::Jira => "Jira",
+            DataSourceKind::GitHub => "GitHub",
+            DataSourceKind::GitLab => "GitLab",
+            DataSourceKind::Csv => "CSV",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Period each value of `get throughput` sums completed issues over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ThroughputBucketKind {
+    Day,
+    Week,
+    Sprint,
+}
+
+/// Which done issues `simulate project --cycle-times` samples a work package from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CycleTimeGroupingKind {
+    /// Issues with the same story points
+    Size,
+    /// Issues sharing a label
+    Label,
+}
+
+/// Which work packages `simulate project --correlation` correlates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CorrelationGroupingKind {
+    /// Work packages with the same correlation_group
+    CorrelationGroup,
+    /// Work packages in the same subgraph
+    Subgraph,
+}
+
+/// Distribution `simulate project` samples three-point estimates from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DistributionKind {
+    BetaPert,
+    /// PERT with the weight of the most likely value set by --pert-shape
+    ModifiedPert,
+    Triangular,
+    /// Ignores the most likely value
+    Uniform,
+    /// Optimistic and pessimistic values are the 10th and 90th percentiles
+    LogNormal,
 }
 
 #[derive(Subcommand)]
@@ -54,6 +119,8 @@ pub enum PlotCommands {
     SimulationGantt(PlotSimulationGanttArgs),
     /// Plot a burndown chart using project and simulation result YAML files
     Burndown(PlotBurndownArgs),
+    /// List the risks of a simulation report YAML as a Markdown table
+    Risks(PlotRisksArgs),
 }
 
 #[derive(Subcommand)]
@@ -62,6 +129,8 @@ pub enum SimulateCommands {
     Project(SimulateProjectArgs),
     /// Simulate completion dates from throughput data
     Throughput(SimulateThroughputArgs),
+    /// Simulate how many items are finished by a date from throughput data
+    Items(SimulateItemsArgs),
 }
 
 #[derive(Subcommand)]
@@ -73,24 +142,90 @@ pub enum UtilCommands {
     Completions(CompletionsArgs),
 }
 
+/// Options of the on-disk cache of fetched issues.
+#[derive(Args, Clone, Debug, Default)]
+pub struct CacheArgs {
+    /// Directory caching fetched issues per query; disabled if omitted
+    #[arg(long)]
+    pub cache_dir: Option<String>,
+    /// Hours after which cached issues are fetched again
+    #[arg(long, default_value_t = 24)]
+    pub cache_max_age: u64,
+    /// Only use cached issues and fail instead of contacting the issue tracker
+    #[arg(long, requires = "cache_dir", conflicts_with = "refresh")]
+    pub offline: bool,
+    /// Fetch all issues again and update the cache
+    #[arg(long, requires = "cache_dir")]
+    pub refresh: bool,
+}
+
 #[derive(Args)]
 pub struct GetThroughputArgs {
-    /// Path to Jira config YAML
+    /// Path to the data source config YAML
     #[arg(short, long)]
     pub config: String,
     /// Output YAML file
     #[arg(short, long)]
     pub output: String,
+    /// Issue tracker the config file describes
+    #[arg(long, value_enum, default_value_t = DataSourceKind::Jira)]
+    pub source: DataSourceKind,
+    /// Extend an existing output file with issues done since its last date
+    #[arg(long, visible_alias = "append")]
+    pub update: bool,
+    /// Count issues by created instead of done date, recording how fast new
+    /// issues arrive; the query must then return open issues as well
+    #[arg(long, conflicts_with = "update")]
+    pub created: bool,
+    /// Optional path to a calendar directory; days without team capacity are
+    /// skipped and the capacity of each day is recorded
+    #[arg(long)]
+    pub calendar_dir: Option<String>,
+    /// Period each throughput value covers; an updated file keeps its own
+    #[arg(long, value_enum, default_value_t = ThroughputBucketKind::Day)]
+    pub bucket: ThroughputBucketKind,
+    /// Length of a sprint in days
+    #[arg(long, default_value_t = 14, value_parser = clap::value_parser!(u32).range(1..))]
+    pub sprint_length: u32,
+    /// First day of any sprint, aligning all sprint buckets (YYYY-MM-DD)
+    #[arg(long, required_if_eq("bucket", "sprint"))]
+    pub sprint_start: Option<NaiveDate>,
+    #[command(flatten)]
+    pub cache: CacheArgs,
 }
 
 #[derive(Args)]
 pub struct GetProjectArgs {
-    /// Path to Jira config YAML
+    /// Path to the data source config YAML
+    #[arg(short, long)]
+    pub config: String,
+    /// Output YAML file
+    #[arg(short, long)]
+    pub output: String,
+    /// Issue tracker the config file describes
+    #[arg(long, value_enum, default_value_t = DataSourceKind::Jira)]
+    pub source: DataSourceKind,
+    #[command(flatten)]
+    pub cache: CacheArgs,
+    /// Jira initiative to fetch instead of the project query; writes one project per epic
+    /// next to the output file, which then references the epics' simulation reports
+    #[arg(long)]
+    pub initiative: Option<String>,
+}
+
+#[derive(Args)]
+pub struct GetCycleTimeArgs {
+    /// Path to the data source config YAML
     #[arg(short, long)]
     pub config: String,
     /// Output YAML file
     #[arg(short, long)]
     pub output: String,
+    /// Issue tracker the config file describes
+    #[arg(long, value_enum, default_value_t = DataSourceKind::Jira)]
+    pub source: DataSourceKind,
+    #[command(flatten)]
+    pub cache: CacheArgs,
 }
 
 #[derive(Args)]
@@ -101,6 +236,9 @@ pub struct PlotThroughputArgs {
     /// Output PNG file
     #[arg(short, long)]
     pub output: String,
+    /// Throughput simulation report whose sample window is highlighted
+    #[arg(short, long)]
+    pub report: Option<String>,
 }
 
 #[derive(Args)]
@@ -158,6 +296,16 @@ pub struct PlotBurndownArgs {
     pub calendar_dir: Option<String>,
 }
 
+#[derive(Args)]
+pub struct PlotRisksArgs {
+    /// Simulation report YAML file
+    #[arg(short, long)]
+    pub report: String,
+    /// Output Markdown file
+    #[arg(short, long)]
+    pub output: String,
+}
+
 #[derive(Args)]
 pub struct SimulateProjectArgs {
     /// Project YAML file
@@ -175,6 +323,39 @@ pub struct SimulateProjectArgs {
     /// Optional path to a calendar directory
     #[arg(short, long)]
     pub calendar_dir: Option<String>,
+    /// Number of worker threads the iterations are split across
+    #[arg(short, long, default_value_t = default_threads())]
+    pub threads: usize,
+    /// Seed for the random number generator (random if omitted)
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Percentiles to report in addition to P0, P15, P50, P85 and P100, e.g. 70,90,95
+    #[arg(long, value_delimiter = ',', value_parser = parse_percentile)]
+    pub percentiles: Vec<f64>,
+    /// Project deadline (YYYY-MM-DD) whose hit probability is reported
+    #[arg(long)]
+    pub deadline: Option<NaiveDate>,
+    /// Cycle time YAML file; samples durations from it instead of the estimates
+    #[arg(long)]
+    pub cycle_times: Option<String>,
+    /// Sample cycle times of done issues with the same size or a shared label
+    #[arg(long, value_enum, requires = "cycle_times")]
+    pub group_by: Option<CycleTimeGroupingKind>,
+    /// Maximum number of work packages in progress at the same time
+    #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    pub wip_limit: Option<usize>,
+    /// Distribution of three-point estimates that do not select their own
+    #[arg(long, value_enum, default_value_t = DistributionKind::BetaPert)]
+    pub distribution: DistributionKind,
+    /// Weight of the most likely value for the modified-pert distribution [default: 4]
+    #[arg(long, value_parser = parse_pert_shape)]
+    pub pert_shape: Option<f32>,
+    /// Correlation of the estimation errors of grouped work packages, from 0 to 1
+    #[arg(long, value_parser = parse_correlation, conflicts_with = "cycle_times")]
+    pub correlation: Option<f32>,
+    /// Group work packages by their correlation_group field or their subgraph
+    #[arg(long, value_enum, default_value_t = CorrelationGroupingKind::CorrelationGroup, requires = "correlation")]
+    pub correlate_by: CorrelationGroupingKind,
 }
 
 #[derive(Args)]
@@ -197,6 +378,53 @@ pub struct SimulateThroughputArgs {
     /// Optional path to a calendar directory
     #[arg(short, long)]
     pub calendar_dir: Option<String>,
+    /// Seed for the random number generator (random if omitted)
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Percentiles to report in addition to P0, P15, P50, P85 and P100, e.g. 70,90,95
+    #[arg(long, value_delimiter = ',', value_parser = parse_percentile)]
+    pub percentiles: Vec<f64>,
+    /// Only sample the throughput of the last weeks of the history
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub window_weeks: Option<u32>,
+    /// Weight samples by recency, halving the weight every this many days
+    #[arg(long, value_parser = parse_half_life)]
+    pub half_life_days: Option<f32>,
+    /// Range by which issues multiply while they are worked on, e.g. 1.1,1.5
+    #[arg(long, value_parser = parse_split_factor)]
+    pub split_factor: Option<(f32, f32)>,
+    /// Throughput YAML file counting created issues (see `get throughput
+    /// --created`), from which the rate of new issues is learned
+    #[arg(long, conflicts_with = "split_factor")]
+    pub arrivals: Option<String>,
+}
+
+#[derive(Args)]
+pub struct SimulateItemsArgs {
+    /// Throughput YAML file
+    #[arg(short = 'f', long)]
+    pub throughput: String,
+    /// Output YAML file
+    #[arg(short, long)]
+    pub output: String,
+    /// Number of simulation iterations
+    #[arg(short = 'n', long, default_value_t = 10000)]
+    pub iterations: usize,
+    /// Target date up to which finished items are counted (YYYY-MM-DD, inclusive)
+    #[arg(short, long)]
+    pub until: NaiveDate,
+    /// Simulation start date (YYYY-MM-DD)
+    #[arg(short, long, default_value_t = default_start_date())]
+    pub start_date: NaiveDate,
+    /// Optional path to a calendar directory
+    #[arg(short, long)]
+    pub calendar_dir: Option<String>,
+    /// Seed for the random number generator (random if omitted)
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Percentiles to report in addition to P0, P15, P50, P85 and P100, e.g. 70,90,95
+    #[arg(long, value_delimiter = ',', value_parser = parse_percentile)]
+    pub percentiles: Vec<f64>,
 }
 
 #[derive(Args)]
@@ -210,10 +438,104 @@ fn default_start_date() -> NaiveDate {
     Local::now().date_naive()
 }
 
+fn parse_percentile(value: &str) -> Result<f64, String> {
+    let percentile: f64 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{value}' is not a number"))?;
+    if !(0.0..=100.0).contains(&percentile) {
+        return Err(format!("percentile {percentile} is not between 0 and 100"));
+    }
+    Ok(percentile)
+}
+
+/// Parses `MIN,MAX`, or a single factor used as both.
+fn parse_split_factor(value: &str) -> Result<(f32, f32), String> {
+    let factors = value
+        .split(',')
+        .map(|factor| {
+            factor
+                .trim()
+                .parse::<f32>()
+                .map_err(|_| format!("'{factor}' is not a number"))
+        })
+        .collect::<Result<Vec<f32>, String>>()?;
+    let (min, max) = match factors.as_slice() {
+        [factor] => (*factor, *factor),
+        [min, max] => (*min, *max),
+        _ => return Err(format!("expected MIN,MAX but got '{value}'")),
+    };
+    if !(min > 0.0 && min <= max) {
+        return Err(format!(
+            "split factors must be positive with MIN <= MAX, got {min},{max}"
+        ));
+    }
+    Ok((min, max))
+}
+
+fn parse_half_life(value: &str) -> Result<f32, String> {
+    let half_life: f32 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{value}' is not a number"))?;
+    if !(half_life > 0.0 && half_life.is_finite()) {
+        return Err(format!(
+            "half-life must be greater than zero, got {half_life}"
+        ));
+    }
+    Ok(half_life)
+}
+
+fn parse_pert_shape(value: &str) -> Result<f32, String> {
+    let shape: f32 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{value}' is not a number"))?;
+    if !(shape.is_finite() && shape >= 0.0) {
+        return Err(format!("PERT shape must not be negative, got {shape}"));
+    }
+    Ok(shape)
+}
+
+fn parse_correlation(value: &str) -> Result<f32, String> {
+    let correlation: f32 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{value}' is not a number"))?;
+    if !(0.0..=1.0).contains(&correlation) {
+        return Err(format!(
+            "correlation must be between 0 and 1, got {correlation}"
+        ));
+    }
+    Ok(correlation)
+}
+
+fn default_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn offline_requires_cache_dir() {
+        let result = CliArgs::try_parse_from([
+            "forecasts",
+            "get",
+            "throughput",
+            "-c",
+            "jira.yaml",
+            "-o",
+            "out.yaml",
+            "--offline",
+        ]);
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn simulate_project_defaults_start_date_to_today() {
         let args = CliArgs::parse_from([
@@ -232,11 +554,368 @@ mod tests {
         {
             assert_eq!(simulate.start_date, default_start_date());
             assert_eq!(simulate.iterations, 10000);
+            assert_eq!(simulate.threads, default_threads());
+        } else {
+            panic!("expected simulate project command");
+        }
+    }
+
+    #[test]
+    fn simulate_project_accepts_thread_count() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "simulate",
+            "project",
+            "-i",
+            "input.yaml",
+            "-o",
+            "output.yaml",
+            "--threads",
+            "4",
+        ]);
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Project(simulate),
+        } = args.command
+        {
+            assert_eq!(simulate.threads, 4);
+            assert_eq!(simulate.seed, None);
         } else {
             panic!("expected simulate project command");
         }
     }
 
+    #[test]
+    fn simulate_throughput_accepts_seed() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "simulate",
+            "throughput",
+            "-f",
+            "throughput.yaml",
+            "-o",
+            "output.yaml",
+            "-k",
+            "5",
+            "--seed",
+            "1234",
+        ]);
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Throughput(simulate),
+        } = args.command
+        {
+            assert_eq!(simulate.seed, Some(1234));
+        } else {
+            panic!("expected simulate throughput command");
+        }
+    }
+
+    #[test]
+    fn simulate_items_requires_until_date() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "simulate",
+            "items",
+            "-f",
+            "throughput.yaml",
+            "-o",
+            "output.yaml",
+            "--until",
+            "2026-03-31",
+        ]);
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Items(simulate),
+        } = args.command
+        {
+            assert_eq!(
+                simulate.until,
+                NaiveDate::from_ymd_opt(2026, 3, 31).unwrap()
+            );
+            assert_eq!(simulate.start_date, default_start_date());
+            assert_eq!(simulate.iterations, 10000);
+        } else {
+            panic!("expected simulate items command");
+        }
+
+        let missing_until = CliArgs::try_parse_from([
+            "forecasts",
+            "simulate",
+            "items",
+            "-f",
+            "throughput.yaml",
+            "-o",
+            "output.yaml",
+        ]);
+        assert!(missing_until.is_err());
+    }
+
+    #[test]
+    fn simulate_project_accepts_percentile_list() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "simulate",
+            "project",
+            "-i",
+            "input.yaml",
+            "-o",
+            "output.yaml",
+            "--percentiles",
+            "50,70,85,97.5",
+        ]);
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Project(simulate),
+        } = args.command
+        {
+            assert_eq!(simulate.percentiles, vec![50.0, 70.0, 85.0, 97.5]);
+        } else {
+            panic!("expected simulate project command");
+        }
+    }
+
+    #[test]
+    fn get_throughput_defaults_to_jira_source() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "get",
+            "throughput",
+            "-c",
+            "config.yaml",
+            "-o",
+            "out.yaml",
+        ]);
+
+        if let Commands::Get {
+            command: GetCommands::Throughput(get),
+        } = args.command
+        {
+            assert_eq!(get.source, DataSourceKind::Jira);
+        } else {
+            panic!("expected get throughput command");
+        }
+    }
+
+    #[test]
+    fn get_project_accepts_github_source() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "get",
+            "project",
+            "-c",
+            "config.yaml",
+            "-o",
+            "out.yaml",
+            "--source",
+            "github",
+        ]);
+
+        if let Commands::Get {
+            command: GetCommands::Project(get),
+        } = args.command
+        {
+            assert_eq!(get.source, DataSourceKind::GitHub);
+        } else {
+            panic!("expected get project command");
+        }
+    }
+
+    #[test]
+    fn simulate_project_accepts_deadline() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "simulate",
+            "project",
+            "-i",
+            "input.yaml",
+            "-o",
+            "output.yaml",
+            "--deadline",
+            "2026-06-30",
+        ]);
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Project(simulate),
+        } = args.command
+        {
+            assert_eq!(simulate.deadline, NaiveDate::from_ymd_opt(2026, 6, 30));
+        } else {
+            panic!("expected simulate project command");
+        }
+    }
+
+    #[test]
+    fn simulate_project_accepts_distribution_and_pert_shape() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "simulate",
+            "project",
+            "-i",
+            "input.yaml",
+            "-o",
+            "output.yaml",
+            "--distribution",
+            "modified-pert",
+            "--pert-shape",
+            "6",
+        ]);
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Project(simulate),
+        } = args.command
+        {
+            assert_eq!(simulate.distribution, DistributionKind::ModifiedPert);
+            assert_eq!(simulate.pert_shape, Some(6.0));
+        } else {
+            panic!("expected simulate project command");
+        }
+    }
+
+    #[test]
+    fn simulate_project_rejects_negative_pert_shape() {
+        let result = CliArgs::try_parse_from([
+            "forecasts",
+            "simulate",
+            "project",
+            "-i",
+            "input.yaml",
+            "-o",
+            "output.yaml",
+            "--pert-shape",
+            "-1",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn simulate_project_accepts_correlation() {
+        let args = CliArgs::parse_from([
+            "forecasts",
+            "simulate",
+            "project",
+            "-i",
+            "input.yaml",
+            "-o",
+            "output.yaml",
+            "--correlation",
+            "0.4",
+            "--correlate-by",
+            "subgraph",
+        ]);
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Project(simulate),
+        } = args.command
+        {
+            assert_eq!(simulate.correlation, Some(0.4));
+            assert_eq!(simulate.correlate_by, CorrelationGroupingKind::Subgraph);
+        } else {
+            panic!("expected simulate project command");
+        }
+    }
+
+    #[test]
+    fn simulate_project_rejects_invalid_correlation() {
+        let parse = |extra: &[&str]| {
+            let mut args = vec![
+                "forecasts",
+                "simulate",
+                "project",
+                "-i",
+                "input.yaml",
+                "-o",
+                "output.yaml",
+            ];
+            args.extend_from_slice(extra);
+            CliArgs::try_parse_from(args)
+        };
+
+        assert!(parse(&["--correlation", "1.5"]).is_err());
+        assert!(parse(&["--correlate-by", "subgraph"]).is_err());
+        assert!(parse(&["--correlation", "0.5", "--cycle-times", "done.yaml"]).is_err());
+        assert!(parse(&[]).is_ok());
+    }
+
+    #[test]
+    fn simulate_rejects_percentiles_outside_range() {
+        let result = CliArgs::try_parse_from([
+            "forecasts",
+            "simulate",
+            "project",
+            "-i",
+            "input.yaml",
+            "-o",
+            "output.yaml",
+            "--percentiles",
+            "50,101",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn simulate_throughput_parses_split_factor_range() {
+        let parse = |value: &str| {
+            CliArgs::try_parse_from([
+                "forecasts",
+                "simulate",
+                "throughput",
+                "-f",
+                "throughput.yaml",
+                "-o",
+                "output.yaml",
+                "-k",
+                "5",
+                "--split-factor",
+                value,
+            ])
+        };
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Throughput(simulate),
+        } = parse("1.1,1.5").unwrap().command
+        {
+            assert_eq!(simulate.split_factor, Some((1.1, 1.5)));
+        } else {
+            panic!("expected simulate throughput command");
+        }
+        assert!(parse("1.5,1.1").is_err());
+        assert!(parse("0").is_err());
+    }
+
+    #[test]
+    fn simulate_throughput_parses_half_life() {
+        let parse = |value: &str| {
+            CliArgs::try_parse_from([
+                "forecasts",
+                "simulate",
+                "throughput",
+                "-f",
+                "throughput.yaml",
+                "-o",
+                "output.yaml",
+                "-k",
+                "5",
+                "--half-life-days",
+                value,
+            ])
+        };
+
+        if let Commands::Simulate {
+            command: SimulateCommands::Throughput(simulate),
+        } = parse("14").unwrap().command
+        {
+            assert_eq!(simulate.half_life_days, Some(14.0));
+        } else {
+            panic!("expected simulate throughput command");
+        }
+        assert!(parse("0").is_err());
+        assert!(parse("NaN").is_err());
+        assert!(parse("inf").is_err());
+    }
+
     #[test]
     fn simulate_throughput_defaults_start_date_to_today() {
         let args = CliArgs::parse_from([
@@ -0,0 +1,224 @@
+use chrono::{Duration, NaiveDate};
+
+use crate::commands::CommandError;
+use crate::commands::base_commands::{CacheArgs, DataSourceKind};
+use crate::services::cached_data_source::{CacheMode, CachedDataSource};
+use crate::services::csv_source::{CsvConfigParser, CsvDataSource};
+use crate::services::data_source::DataSource;
+use crate::services::github_api::{GitHubApiClient, GitHubAuthData, GitHubConfigParser};
+use crate::services::gitlab_api::{GitLabApiClient, GitLabAuthData, GitLabConfigParser};
+use crate::services::jira_api::{AuthData, JiraApiClient, JiraConfigParser};
+
+/// A data source created from a config file, together with the project name
+/// and the queries that file defines.
+pub(crate) struct SelectedDataSource {
+    pub data_source: Box<dyn DataSource>,
+    pub project_name: String,
+    pub throughput_query: String,
+    /// Builds the throughput query for issues done on or after a date.
+    pub throughput_query_since: QuerySince,
+    pub project_query: String,
+    /// Whether issue dependencies come from links, see
+    /// `ProjectFactory::with_linked_dependencies`.
+    pub linked_dependencies: bool,
+}
+
+pub(crate) type QuerySince = Box<dyn Fn(NaiveDate) -> String>;
+
+/// What the issues are fetched for. Some sources read the dependencies or
+/// start dates of issues with one request per issue, which is only worth it
+/// where they are used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DataPurpose {
+    Throughput,
+    CycleTime,
+    Project,
+}
+
+type Connect = Box<dyn FnOnce(DataPurpose) -> Result<Box<dyn DataSource>, CommandError>>;
+
+/// The parsed config file. Connecting is deferred, as offline runs must not
+/// need credentials.
+struct DataSourceConfig {
+    project_name: String,
+    throughput_query: String,
+    throughput_query_since: QuerySince,
+    project_query: String,
+    linked_dependencies: bool,
+    /// Local files the issues are read from.
+    data_files: Vec<String>,
+    connect: Connect,
+}
+
+pub(crate) fn open_data_source(
+    kind: DataSourceKind,
+    config: &str,
+    cache: &CacheArgs,
+    purpose: DataPurpose,
+) -> Result<SelectedDataSource, CommandError> {
+    let parsed = match kind {
+        DataSourceKind::Jira => parse_jira(config),
+        DataSourceKind::GitHub => parse_github(config),
+        DataSourceKind::GitLab => parse_gitlab(config),
+        DataSourceKind::Csv => parse_csv(config),
+    }?;
+
+    let live = if cache.offline {
+        None
+    } else {
+        Some((parsed.connect)(purpose)?)
+    };
+    let data_source = match (&cache.cache_dir, live) {
+        (Some(cache_dir), live) => {
+            let mode = if cache.offline {
+                CacheMode::Offline
+            } else if cache.refresh {
+                CacheMode::Refresh
+            } else {
+                CacheMode::Normal
+            };
+            // Editing the config, e.g. a field id, or a local data file must not
+            // replay stale issues, and queries for one purpose must not replay
+            // issues that lack the details another purpose needs.
+            let mut namespace = format!("{kind}\n{purpose:?}");
+            let files = std::iter::once(config).chain(parsed.data_files.iter().map(String::as_str));
+            for file in files {
+                let contents = std::fs::read(file).unwrap_or_default();
+                namespace.push('\n');
+                namespace.push_str(&String::from_utf8_lossy(&contents));
+            }
+            Box::new(CachedDataSource::new(
+                live,
+                cache_dir,
+                namespace,
+                Duration::hours(cache.cache_max_age as i64),
+                mode,
+            )) as Box<dyn DataSource>
+        }
+        (None, Some(live)) => live,
+        (None, None) => return Err(CommandError::OfflineWithoutCache),
+    };
+
+    Ok(SelectedDataSource {
+        data_source,
+        project_name: parsed.project_name,
+        throughput_query: parsed.throughput_query,
+        throughput_query_since: parsed.throughput_query_since,
+        project_query: parsed.project_query,
+        linked_dependencies: parsed.linked_dependencies,
+    })
+}
+
+fn parse_jira(config: &str) -> Result<DataSourceConfig, CommandError> {
+    let kind = DataSourceKind::Jira;
+    let jira_project = JiraConfigParser
+        .parse(config)
+        .map_err(|source| CommandError::ParseDataSourceConfig { kind, source })?;
+
+    Ok(DataSourceConfig {
+        project_name: jira_project.project_key.clone(),
+        throughput_query: jira_project.throughput_query.clone(),
+        throughput_query_since: {
+            let metadata = jira_project.clone();
+            Box::new(move |date| metadata.throughput_query_since(date))
+        },
+        project_query: jira_project.project_query.clone(),
+        linked_dependencies: jira_project.reads_dependency_links(),
+        data_files: Vec::new(),
+        connect: Box::new(move |_| {
+            let auth = AuthData::from_env(jira_project.auth_mode)
+                .map_err(|source| CommandError::LoadDataSourceAuth { kind, source })?;
+            let api_client = JiraApiClient::new(jira_project, auth)
+                .map_err(|source| CommandError::CreateDataSourceClient { kind, source })?;
+            Ok(Box::new(api_client) as Box<dyn DataSource>)
+        }),
+    })
+}
+
+fn parse_github(config: &str) -> Result<DataSourceConfig, CommandError> {
+    let kind = DataSourceKind::GitHub;
+    let github_project = GitHubConfigParser
+        .parse(config)
+        .map_err(|source| CommandError::ParseDataSourceConfig { kind, source })?;
+
+    Ok(DataSourceConfig {
+        project_name: github_project.repository.clone(),
+        throughput_query: github_project.throughput_query.clone(),
+        throughput_query_since: {
+            let metadata = github_project.clone();
+            Box::new(move |date| metadata.throughput_query_since(date))
+        },
+        project_query: github_project.project_query.clone(),
+        linked_dependencies: false,
+        data_files: Vec::new(),
+        connect: Box::new(move |purpose| {
+            let auth = GitHubAuthData::from_env()
+                .map_err(|source| CommandError::LoadDataSourceAuth { kind, source })?;
+            let api_client = GitHubApiClient::new(github_project, auth)
+                .map_err(|source| CommandError::CreateDataSourceClient { kind, source })?
+                .with_start_dates(purpose != DataPurpose::Throughput);
+            Ok(Box::new(api_client) as Box<dyn DataSource>)
+        }),
+    })
+}
+
+fn parse_gitlab(config: &str) -> Result<DataSourceConfig, CommandError> {
+    let kind = DataSourceKind::GitLab;
+    let gitlab_project = GitLabConfigParser
+        .parse(config)
+        .map_err(|source| CommandError::ParseDataSourceConfig { kind, source })?;
+
+    Ok(DataSourceConfig {
+        project_name: gitlab_project.project.clone(),
+        throughput_query: gitlab_project.throughput_query.clone(),
+        throughput_query_since: {
+            let metadata = gitlab_project.clone();
+            Box::new(move |date| metadata.throughput_query_since(date))
+        },
+        project_query: gitlab_project.project_query.clone(),
+        linked_dependencies: false,
+        data_files: Vec::new(),
+        connect: Box::new(move |purpose| {
+            let auth = GitLabAuthData::from_env()
+                .map_err(|source| CommandError::LoadDataSourceAuth { kind, source })?;
+            let api_client = GitLabApiClient::new(gitlab_project, auth)
+                .map_err(|source| CommandError::CreateDataSourceClient { kind, source })?
+                .with_issue_links(purpose == DataPurpose::Project);
+            Ok(Box::new(api_client) as Box<dyn DataSource>)
+        }),
+    })
+}
+
+fn parse_csv(config: &str) -> Result<DataSourceConfig, CommandError> {
+    let kind = DataSourceKind::Csv;
+    let csv_project = CsvConfigParser
+        .parse(config)
+        .map_err(|source| CommandError::ParseDataSourceConfig { kind, source })?;
+
+    let project_name = if csv_project.project_name.is_empty() {
+        std::path::Path::new(&csv_project.file_path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    } else {
+        csv_project.project_name.clone()
+    };
+
+    Ok(DataSourceConfig {
+        project_name,
+        throughput_query: csv_project.throughput_query.clone(),
+        // The whole file is read anyway; earlier issues are filtered out later.
+        throughput_query_since: {
+            let query = csv_project.throughput_query.clone();
+            Box::new(move |_| query.clone())
+        },
+        project_query: csv_project.project_query.clone(),
+        linked_dependencies: false,
+        data_files: vec![csv_project.file_path.clone()],
+        connect: Box::new(move |_| {
+            let data_source = CsvDataSource::new(csv_project)
+                .map_err(|source| CommandError::CreateDataSourceClient { kind, source })?;
+            Ok(Box::new(data_source) as Box<dyn DataSource>)
+        }),
+    })
+}
@@ -0,0 +1,28 @@
+use crate::commands::base_commands::GetCycleTimeArgs;
+use crate::commands::data_source_selection::{DataPurpose, open_data_source};
+use crate::commands::{CommandError, CommandResult};
+use crate::services::data_converter::DataConverter;
+use crate::services::data_source::DataQuery;
+use crate::services::parsing::cycle_time_yaml::serialize_cycle_times_to_yaml;
+
+pub fn get_cycle_time_command(args: GetCycleTimeArgs) -> CommandResult {
+    let GetCycleTimeArgs {
+        config,
+        output,
+        source,
+        cache,
+    } = args;
+    let selected = open_data_source(source, &config, &cache, DataPurpose::CycleTime)?;
+
+    let data_converter = DataConverter::new(selected.data_source);
+    let cycle_times = data_converter
+        .get_cycle_time_data(DataQuery::StringQuery(selected.throughput_query))
+        .map_err(CommandError::GetCycleTimeData)?;
+
+    let mut buffer = Vec::new();
+    serialize_cycle_times_to_yaml(&mut buffer, &cycle_times)
+        .map_err(CommandError::SerializeCycleTime)?;
+    std::fs::write(&output, buffer).map_err(CommandError::WriteOutput)?;
+
+    Ok(vec![format!("Cycle time data written to {output}")])
+}
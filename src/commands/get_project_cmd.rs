@@ -1,34 +1,109 @@
-use crate::commands::base_commands::GetProjectArgs;
+use std::path::Path;
+
+use crate::commands::base_commands::{DataSourceKind, GetProjectArgs};
+use crate::commands::data_source_selection::{DataPurpose, open_data_source};
 use crate::commands::{CommandError, CommandResult};
 use crate::services::data_source::DataQuery;
-use crate::services::jira_api::{AuthData, JiraApiClient, JiraConfigParser};
+use crate::services::jira_api::child_issues_query;
 use crate::services::parsing::project_yaml::serialize_project_to_yaml;
-use crate::services::project_factory::ProjectFactory;
+use crate::services::project_factory::{CreatedProject, ProjectFactory};
+use crate::services::project_hierarchy::ProjectHierarchyFactory;
 
 pub fn get_project_command(args: GetProjectArgs) -> CommandResult {
-    let GetProjectArgs { config, output } = args;
-    let config_parser = JiraConfigParser;
-    let jira_project = config_parser
-        .parse(&config)
-        .map_err(CommandError::ParseJiraConfig)?;
-
-    let auth = AuthData::from_env().map_err(CommandError::LoadJiraAuth)?;
-
-    let api_client = JiraApiClient::new(jira_project.clone(), auth)
-        .map_err(CommandError::CreateJiraApiClient)?;
-
-    let project_factory = ProjectFactory::new(&api_client);
-    let project = project_factory
-        .create_project(
-            jira_project.project_key.clone(),
-            DataQuery::StringQuery(jira_project.project_query),
-        )
-        .map_err(CommandError::GetProjectData)?;
+    let GetProjectArgs {
+        config,
+        output,
+        source,
+        cache,
+        initiative,
+    } = args;
+    if initiative.is_some() && source != DataSourceKind::Jira {
+        return Err(CommandError::UnsupportedHierarchy(source));
+    }
+    let selected = open_data_source(source, &config, &cache, DataPurpose::Project)?;
+
+    let mut messages = Vec::new();
+    if let Some(initiative) = initiative {
+        let hierarchy_factory = ProjectHierarchyFactory::new(selected.data_source.as_ref())
+            .with_linked_dependencies(selected.linked_dependencies);
+        let hierarchy = hierarchy_factory
+            .create_hierarchy(&initiative, child_issues_query, |epic_id| {
+                epic_file_path(&output, epic_id, "report.yaml")
+            })
+            .map_err(CommandError::GetProjectData)?;
+
+        for epic in &hierarchy.epics {
+            let epic_output = epic_file_path(&output, &epic.epic_id, "yaml");
+            write_project(&epic_output, &epic.created, &mut messages)?;
+            messages.push(format!(
+                "Epic {} project data written to {epic_output}, simulate it into {}",
+                epic.epic_id, epic.report_file_path
+            ));
+        }
+        write_project(&output, &hierarchy.top_level, &mut messages)?;
+    } else {
+        let project_factory = ProjectFactory::new(selected.data_source.as_ref())
+            .with_linked_dependencies(selected.linked_dependencies);
+        let created = project_factory
+            .create_project(
+                selected.project_name,
+                DataQuery::StringQuery(selected.project_query),
+            )
+            .map_err(CommandError::GetProjectData)?;
+        write_project(&output, &created, &mut messages)?;
+    }
+
+    messages.push(format!("Project data written to {output}"));
+    Ok(messages)
+}
 
+fn write_project(
+    output: &str,
+    created: &CreatedProject,
+    messages: &mut Vec<String>,
+) -> Result<(), CommandError> {
     let mut buffer = Vec::new();
-    serialize_project_to_yaml(&mut buffer, &project).map_err(CommandError::SerializeProject)?;
+    serialize_project_to_yaml(&mut buffer, &created.project)
+        .map_err(CommandError::SerializeProject)?;
+
+    std::fs::write(output, buffer).map_err(CommandError::WriteOutput)?;
+
+    messages.extend(created.external_dependencies.iter().map(|dependency| {
+        format!(
+            "Warning: dependency {} -> {} points outside the project query and was removed",
+            dependency.issue_id, dependency.dependency_id
+        )
+    }));
+    Ok(())
+}
+
+/// Path next to `output` named after the epic, e.g. `roadmap.ABC-1.yaml`
+/// for the output `roadmap.yaml`.
+fn epic_file_path(output: &str, epic_id: &str, extension: &str) -> String {
+    let output_path = Path::new(output);
+    let stem = output_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    output_path
+        .with_file_name(format!("{stem}.{epic_id}.{extension}"))
+        .to_string_lossy()
+        .into_owned()
+}
 
-    std::fs::write(&output, buffer).map_err(CommandError::WriteOutput)?;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(vec![format!("Project data written to {output}")])
+    #[test]
+    fn epic_file_path_is_placed_next_to_output() {
+        assert_eq!(
+            epic_file_path("out/roadmap.yaml", "ABC-1", "report.yaml"),
+            "out/roadmap.ABC-1.report.yaml"
+        );
+        assert_eq!(
+            epic_file_path("roadmap", "ABC-1", "yaml"),
+            "roadmap.ABC-1.yaml"
+        );
+    }
 }
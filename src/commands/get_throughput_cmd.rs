@@ -1,28 +1,80 @@
-use crate::commands::base_commands::GetThroughputArgs;
+use std::path::Path;
+
+use crate::commands::base_commands::{GetThroughputArgs, ThroughputBucketKind};
+use crate::commands::data_source_selection::{DataPurpose, open_data_source};
 use crate::commands::{CommandError, CommandResult};
+use crate::domain::throughput::{ThroughputBucket, ThroughputHistory};
 use crate::services::data_converter::DataConverter;
 use crate::services::data_source::DataQuery;
-use crate::services::jira_api::{AuthData, JiraApiClient, JiraConfigParser};
-use crate::services::parsing::throughput_yaml::serialize_throughput_to_yaml;
+use crate::services::parsing::team_calendar_yaml::load_team_calendar_from_yaml_dir;
+use crate::services::parsing::throughput_yaml::{
+    deserialize_throughput_from_yaml_str, serialize_throughput_to_yaml,
+};
 
 pub fn get_throughput_command(args: GetThroughputArgs) -> CommandResult {
-    let GetThroughputArgs { config, output } = args;
-    let config_parser = JiraConfigParser;
-    let jira_project = config_parser
-        .parse(&config)
-        .map_err(CommandError::ParseJiraConfig)?;
+    let GetThroughputArgs {
+        config,
+        output,
+        source,
+        update,
+        created,
+        calendar_dir,
+        bucket,
+        sprint_length,
+        sprint_start,
+        cache,
+    } = args;
+    let selected = open_data_source(source, &config, &cache, DataPurpose::Throughput)?;
+
+    let history = if update && Path::new(&output).exists() {
+        let yaml = std::fs::read_to_string(&output).map_err(CommandError::ReadThroughput)?;
+        Some(deserialize_throughput_from_yaml_str(&yaml).map_err(CommandError::ParseThroughput)?)
+    } else {
+        None
+    };
+    // An existing file keeps the bucket it was aggregated by.
+    let bucket = match (&history, bucket) {
+        (Some(history), _) => history.bucket,
+        (None, ThroughputBucketKind::Day) => ThroughputBucket::Day,
+        (None, ThroughputBucketKind::Week) => ThroughputBucket::Week,
+        // clap requires a sprint start for sprint buckets.
+        (None, ThroughputBucketKind::Sprint) => ThroughputBucket::Sprint {
+            start: sprint_start.unwrap_or_default(),
+            length_days: sprint_length,
+        },
+    };
 
-    let auth = AuthData::from_env().map_err(CommandError::LoadJiraAuth)?;
-    let api_client = JiraApiClient::new(jira_project.clone(), auth)
-        .map_err(CommandError::CreateJiraApiClient)?;
+    let mut data_converter = DataConverter::new(selected.data_source).with_bucket(bucket);
+    if let Some(calendar_dir) = calendar_dir {
+        let calendar =
+            load_team_calendar_from_yaml_dir(calendar_dir).map_err(CommandError::ReadCalendar)?;
+        data_converter = data_converter.with_calendar(calendar);
+    }
 
-    let data_converter = DataConverter::new(Box::new(api_client));
-    let throughput = data_converter
-        .get_throughput_data(DataQuery::StringQuery(jira_project.throughput_query))
-        .map_err(CommandError::GetThroughputData)?;
+    let history = history
+        .map(|history| history.throughput)
+        .unwrap_or_default();
+    let throughput = match history.last().map(|throughput| throughput.date) {
+        // The last recorded bucket is fetched again, as it may have been
+        // incomplete when it was recorded.
+        Some(last_date) => {
+            let since = data_converter.refetch_start(last_date);
+            let query = (selected.throughput_query_since)(since);
+            let new_throughput = data_converter
+                .get_throughput_data_since(DataQuery::StringQuery(query), since)
+                .map_err(CommandError::GetThroughputData)?;
+            data_converter.merge_throughput(history, new_throughput)
+        }
+        None if created => data_converter
+            .get_arrival_data(DataQuery::StringQuery(selected.throughput_query))
+            .map_err(CommandError::GetThroughputData)?,
+        None => data_converter
+            .get_throughput_data(DataQuery::StringQuery(selected.throughput_query))
+            .map_err(CommandError::GetThroughputData)?,
+    };
 
     let mut buffer = Vec::new();
-    serialize_throughput_to_yaml(&mut buffer, &throughput)
+    serialize_throughput_to_yaml(&mut buffer, &ThroughputHistory { bucket, throughput })
         .map_err(CommandError::SerializeThroughput)?;
     std::fs::write(&output, buffer).map_err(CommandError::WriteOutput)?;
 
@@ -0,0 +1,11 @@
+use crate::commands::base_commands::PlotRisksArgs;
+use crate::commands::{CommandError, CommandResult};
+use crate::services::plotting::risk_list::write_risk_list_markdown;
+
+pub fn plot_risks_command(args: PlotRisksArgs) -> CommandResult {
+    let PlotRisksArgs { report, output } = args;
+
+    write_risk_list_markdown(&report, &output).map_err(CommandError::PlotRisks)?;
+
+    Ok(vec![format!("List of risks written to {output}")])
+}
@@ -1,4 +1,10 @@
-use crate::services::project_simulation::simulation_types::SimulationReport;
+use crate::services::project_simulation::correlation::CorrelationGrouping;
+use crate::services::project_simulation::report_percentiles::{Percentiles, percentile_label};
+use crate::services::project_simulation::scope_growth::ScopeModel;
+use crate::services::project_simulation::simulation_types::{
+    ConstraintForecast, ConstraintType, CorrelationForecast, DeadlineForecast, ItemForecastReport,
+    RiskForecast, SampleWindow, SimulationPercentile, SimulationReport,
+};
 
 pub fn format_simulation_report(report: &SimulationReport) -> String {
     let velocity = match report.velocity {
@@ -16,35 +22,212 @@ pub fn format_simulation_report(report: &SimulationReport) -> String {
     lines.push(format!("Iterations: {}", report.iterations));
     lines.push(format!("Simulated items: {}", report.simulated_items));
     lines.push(format!("Velocity: {}", velocity));
+    if let Some(seed) = report.seed {
+        lines.push(format!("Seed: {seed}"));
+    }
+    if let Some(window) = &report.sample_window {
+        lines.push(format_sample_window(window));
+    }
     lines.push(String::new());
     lines.push("Percentiles:".to_string());
-    lines.push("Percentile | Days | Date".to_string());
-    lines.push("-----------|------|-----".to_string());
-    lines.push(format_percentile_row("P0", &report.p0));
-    lines.push(format_percentile_row("P15", &report.p15));
-    lines.push(format_percentile_row("P50", &report.p50));
-    lines.push(format_percentile_row("P85", &report.p85));
-    lines.push(format_percentile_row("P100", &report.p100));
+    let comparison = match (&report.scope_growth, &report.correlation) {
+        (Some(scope_growth), _) => {
+            lines.push(format!(
+                "Scope growth: {}",
+                format_scope_model(&scope_growth.model)
+            ));
+            Some(("growth", &scope_growth.percentiles_without_growth))
+        }
+        (None, Some(correlation)) => {
+            lines.push(format_correlation(correlation));
+            Some(("correlation", &correlation.percentiles_without_correlation))
+        }
+        (None, None) => None,
+    };
+    match comparison {
+        Some((without, baseline)) => {
+            push_comparison_table(&mut lines, &report.percentiles, baseline, without);
+        }
+        None => {
+            lines.push("Percentile | Days | Date".to_string());
+            lines.push("-----------|------|-----".to_string());
+            for (percentile, value) in report.percentiles.iter() {
+                lines.push(format_percentile_row(&percentile_label(percentile), value));
+            }
+        }
+    }
+    if let Some(deadlines) = &report.deadlines {
+        lines.push(String::new());
+        lines.push("Deadlines:".to_string());
+        lines.push("Target | Date | Probability | Slack at P85".to_string());
+        lines.push("-------|------|-------------|-------------".to_string());
+        for deadline in deadlines {
+            lines.push(format_deadline_row(deadline));
+        }
+    }
+    if let Some(constraints) = &report.constraints {
+        lines.push(String::new());
+        lines.push("Date constraints:".to_string());
+        lines.push("Work package | Constraint | Date | Violated | Total float at P85".to_string());
+        lines.push("-------------|------------|------|----------|-------------------".to_string());
+        for constraint in constraints {
+            lines.push(format_constraint_row(constraint, report.iterations));
+        }
+    }
+    if let Some(risks) = &report.risks {
+        lines.push(String::new());
+        lines.push("Risks:".to_string());
+        lines.push("Risk | Probability | Occurred | Added to P85".to_string());
+        lines.push("-----|-------------|----------|-------------".to_string());
+        for risk in risks {
+            lines.push(format_risk_row(risk));
+        }
+    }
 
     lines.join("\n")
 }
 
-fn format_percentile_row(
-    label: &str,
-    percentile: &crate::services::project_simulation::simulation_types::SimulationPercentile,
-) -> String {
+fn format_sample_window(window: &SampleWindow) -> String {
+    let half_life = match window.half_life_days {
+        Some(days) => format!(", half-life {days} days"),
+        None => String::new(),
+    };
+    format!(
+        "Sample window: {} - {} ({} samples{half_life})",
+        window.start_date.format("%Y-%m-%d"),
+        window.end_date.format("%Y-%m-%d"),
+        window.samples
+    )
+}
+
+/// Lists the percentiles next to those of a baseline run without `without`.
+fn push_comparison_table(
+    lines: &mut Vec<String>,
+    percentiles: &Percentiles,
+    baseline: &Percentiles,
+    without: &str,
+) {
+    let header =
+        format!("Percentile | Days | Date | Days without {without} | Date without {without}");
+    let separator: Vec<String> = header
+        .split('|')
+        .map(|column| "-".repeat(column.len()))
+        .collect();
+    lines.push(header.clone());
+    lines.push(separator.join("|"));
+    for (percentile, value) in percentiles.iter() {
+        let without_value = baseline
+            .get(percentile)
+            .map(|value| format_percentile_row("", value))
+            .unwrap_or_else(|| " | n/a | n/a".to_string());
+        lines.push(format!(
+            "{}{}",
+            format_percentile_row(&percentile_label(percentile), value),
+            without_value
+        ));
+    }
+}
+
+fn format_correlation(correlation: &CorrelationForecast) -> String {
+    let groups = match (correlation.grouping, correlation.groups) {
+        (CorrelationGrouping::CorrelationGroup, 1) => "correlation group",
+        (CorrelationGrouping::CorrelationGroup, _) => "correlation groups",
+        (CorrelationGrouping::Subgraph, 1) => "subgraph",
+        (CorrelationGrouping::Subgraph, _) => "subgraphs",
+    };
     format!(
-        "{label} | {days} | {date}",
+        "Correlation: {:.2} within {} {groups}",
+        correlation.strength, correlation.groups
+    )
+}
+
+fn format_scope_model(model: &ScopeModel) -> String {
+    match model {
+        ScopeModel::SplitFactor { min, max } => format!("split factor {min:.2} - {max:.2}"),
+        ScopeModel::ArrivalRate { issues_per_day } => {
+            format!("{issues_per_day:.2} new issues per working day")
+        }
+    }
+}
+
+pub fn format_item_forecast_report(report: &ItemForecastReport) -> String {
+    let mut lines = Vec::new();
+    lines.push("Item Forecast Report".to_string());
+    lines.push(format!("Data source: {}", report.data_source));
+    lines.push(format!(
+        "Period: {} - {}",
+        report.start_date.format("%Y-%m-%d"),
+        report.end_date.format("%Y-%m-%d")
+    ));
+    lines.push(format!("Iterations: {}", report.iterations));
+    if let Some(seed) = report.seed {
+        lines.push(format!("Seed: {seed}"));
+    }
+    lines.push(String::new());
+    lines.push("Percentiles (items finished at least):".to_string());
+    lines.push("Percentile | Items".to_string());
+    lines.push("-----------|------".to_string());
+    for entry in &report.percentiles {
+        lines.push(format!(
+            "{} | {}",
+            percentile_label(entry.percentile),
+            entry.items
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn format_percentile_row(label: &str, percentile: &SimulationPercentile) -> String {
+    format!(
+        "{label} | {days:.2} | {date}",
         label = label,
-        days = format!("{:.2}", percentile.days),
+        days = percentile.days,
         date = percentile.end_date.format("%Y-%m-%d")
     )
 }
 
+fn format_deadline_row(deadline: &DeadlineForecast) -> String {
+    format!(
+        "{target} | {date} | {probability:.1}% | {slack} days",
+        target = deadline.id.as_deref().unwrap_or("Project"),
+        date = deadline.due_date.format("%Y-%m-%d"),
+        probability = deadline.probability * 100.0,
+        slack = deadline.slack_days_p85
+    )
+}
+
+fn format_constraint_row(constraint: &ConstraintForecast, iterations: usize) -> String {
+    let constraint_type = match constraint.constraint_type {
+        ConstraintType::StartNoEarlierThan => "start no earlier than",
+        ConstraintType::MustFinishOnOrBefore => "must finish on or before",
+        ConstraintType::FixedDate => "fixed date",
+    };
+    format!(
+        "{id} | {constraint_type} | {date} | {violated:.1}% | {float:.1} days",
+        id = constraint.id,
+        date = constraint.date.format("%Y-%m-%d"),
+        violated = constraint.violations as f32 / iterations.max(1) as f32 * 100.0,
+        float = constraint.total_float_days_p85
+    )
+}
+
+fn format_risk_row(risk: &RiskForecast) -> String {
+    format!(
+        "{id} | {probability:.1}% | {occurred:.1}% | {days:.2} days",
+        id = risk.id,
+        probability = risk.probability * 100.0,
+        occurred = risk.occurrence_rate * 100.0,
+        days = risk.p85_contribution_days
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::services::project_simulation::simulation_types::SimulationPercentile;
+    use crate::services::project_simulation::simulation_types::{
+        ItemCountPercentile, ScopeGrowthForecast,
+    };
     use chrono::NaiveDate;
 
     fn build_report() -> SimulationReport {
@@ -55,28 +238,54 @@ mod tests {
             start_date,
             velocity: Some(2.5),
             iterations: 100,
+            seed: None,
             simulated_items: 12,
-            p0: SimulationPercentile {
-                days: 1.0,
-                end_date: NaiveDate::from_ymd_opt(2026, 2, 2).unwrap(),
-            },
-            p15: SimulationPercentile {
-                days: 2.0,
-                end_date: NaiveDate::from_ymd_opt(2026, 2, 3).unwrap(),
-            },
-            p50: SimulationPercentile {
-                days: 5.5,
-                end_date: NaiveDate::from_ymd_opt(2026, 2, 6).unwrap(),
-            },
-            p85: SimulationPercentile {
-                days: 10.0,
-                end_date: NaiveDate::from_ymd_opt(2026, 2, 11).unwrap(),
-            },
-            p100: SimulationPercentile {
-                days: 15.25,
-                end_date: NaiveDate::from_ymd_opt(2026, 2, 16).unwrap(),
-            },
+            percentiles: [
+                (
+                    0.0,
+                    SimulationPercentile {
+                        days: 1.0,
+                        end_date: NaiveDate::from_ymd_opt(2026, 2, 2).unwrap(),
+                    },
+                ),
+                (
+                    15.0,
+                    SimulationPercentile {
+                        days: 2.0,
+                        end_date: NaiveDate::from_ymd_opt(2026, 2, 3).unwrap(),
+                    },
+                ),
+                (
+                    50.0,
+                    SimulationPercentile {
+                        days: 5.5,
+                        end_date: NaiveDate::from_ymd_opt(2026, 2, 6).unwrap(),
+                    },
+                ),
+                (
+                    85.0,
+                    SimulationPercentile {
+                        days: 10.0,
+                        end_date: NaiveDate::from_ymd_opt(2026, 2, 11).unwrap(),
+                    },
+                ),
+                (
+                    100.0,
+                    SimulationPercentile {
+                        days: 15.25,
+                        end_date: NaiveDate::from_ymd_opt(2026, 2, 16).unwrap(),
+                    },
+                ),
+            ]
+            .into_iter()
+            .collect(),
             work_packages: None,
+            deadlines: None,
+            sample_window: None,
+            scope_growth: None,
+            correlation: None,
+            risks: None,
+            constraints: None,
         }
     }
 
@@ -107,4 +316,179 @@ mod tests {
         let output = format_simulation_report(&report);
         assert!(output.contains("Velocity: n/a"));
     }
+
+    #[test]
+    fn format_item_forecast_report_lists_item_counts() {
+        let report = ItemForecastReport {
+            data_source: "throughput.yaml".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 2, 28).unwrap(),
+            iterations: 100,
+            seed: Some(9),
+            percentiles: vec![
+                ItemCountPercentile {
+                    percentile: 50.0,
+                    items: 20,
+                },
+                ItemCountPercentile {
+                    percentile: 85.0,
+                    items: 14,
+                },
+            ],
+        };
+
+        let output = format_item_forecast_report(&report);
+
+        assert!(output.contains("Item Forecast Report"));
+        assert!(output.contains("Period: 2026-02-01 - 2026-02-28"));
+        assert!(output.contains("Seed: 9"));
+        assert!(output.contains("P50 | 20"));
+        assert!(output.contains("P85 | 14"));
+    }
+
+    #[test]
+    fn format_simulation_report_shows_seed_when_recorded() {
+        let mut report = build_report();
+        assert!(!format_simulation_report(&report).contains("Seed:"));
+
+        report.seed = Some(42);
+        let output = format_simulation_report(&report);
+        assert!(output.contains("Seed: 42"));
+    }
+
+    #[test]
+    fn format_simulation_report_shows_sample_window() {
+        let mut report = build_report();
+        assert!(!format_simulation_report(&report).contains("Sample window:"));
+
+        report.sample_window = Some(SampleWindow {
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 30).unwrap(),
+            samples: 20,
+            half_life_days: Some(14.0),
+        });
+        let output = format_simulation_report(&report);
+
+        assert!(
+            output
+                .contains("Sample window: 2026-01-05 - 2026-01-30 (20 samples, half-life 14 days)")
+        );
+    }
+
+    #[test]
+    fn format_simulation_report_compares_forecast_without_scope_growth() {
+        let mut report = build_report();
+        report.scope_growth = Some(ScopeGrowthForecast {
+            model: ScopeModel::SplitFactor { min: 1.1, max: 1.5 },
+            percentiles_without_growth: [(
+                50.0,
+                SimulationPercentile {
+                    days: 4.0,
+                    end_date: NaiveDate::from_ymd_opt(2026, 2, 5).unwrap(),
+                },
+            )]
+            .into_iter()
+            .collect(),
+        });
+
+        let output = format_simulation_report(&report);
+
+        assert!(output.contains("Scope growth: split factor 1.10 - 1.50"));
+        assert!(output.contains("Days without growth | Date without growth"));
+        assert!(output.contains("P50 | 5.50 | 2026-02-06 | 4.00 | 2026-02-05"));
+        assert!(output.contains("P85 | 10.00 | 2026-02-11 | n/a | n/a"));
+    }
+
+    #[test]
+    fn format_simulation_report_compares_forecast_without_correlation() {
+        let mut report = build_report();
+        report.correlation = Some(CorrelationForecast {
+            strength: 0.5,
+            grouping: CorrelationGrouping::Subgraph,
+            groups: 2,
+            percentiles_without_correlation: [(
+                85.0,
+                SimulationPercentile {
+                    days: 8.0,
+                    end_date: NaiveDate::from_ymd_opt(2026, 2, 9).unwrap(),
+                },
+            )]
+            .into_iter()
+            .collect(),
+        });
+
+        let output = format_simulation_report(&report);
+
+        assert!(output.contains("Correlation: 0.50 within 2 subgraphs"));
+        assert!(output.contains(
+            "Percentile | Days | Date | Days without correlation | Date without correlation\n\
+             -----------|------|------|--------------------------|-------------------------\n"
+        ));
+        assert!(output.contains("P85 | 10.00 | 2026-02-11 | 8.00 | 2026-02-09"));
+    }
+
+    #[test]
+    fn format_simulation_report_lists_risks() {
+        let mut report = build_report();
+        assert!(!format_simulation_report(&report).contains("Risks:"));
+
+        report.risks = Some(vec![RiskForecast {
+            id: "R1".to_string(),
+            description: None,
+            probability: 0.3,
+            occurrence_rate: 0.312,
+            affects: vec!["WP1".to_string()],
+            p85_contribution_days: 2.5,
+        }]);
+        let output = format_simulation_report(&report);
+
+        assert!(output.contains("Risk | Probability | Occurred | Added to P85"));
+        assert!(output.contains("R1 | 30.0% | 31.2% | 2.50 days"));
+    }
+
+    #[test]
+    fn format_simulation_report_lists_date_constraints() {
+        let mut report = build_report();
+        assert!(!format_simulation_report(&report).contains("Date constraints:"));
+
+        report.constraints = Some(vec![ConstraintForecast {
+            id: "WP1".to_string(),
+            constraint_type: ConstraintType::MustFinishOnOrBefore,
+            date: NaiveDate::from_ymd_opt(2026, 2, 5).unwrap(),
+            violations: report.iterations / 4,
+            total_float_days_p85: -2.0,
+        }]);
+        let output = format_simulation_report(&report);
+
+        assert!(
+            output.contains("Work package | Constraint | Date | Violated | Total float at P85")
+        );
+        assert!(output.contains("WP1 | must finish on or before | 2026-02-05 | 25.0% | -2.0 days"));
+    }
+
+    #[test]
+    fn format_simulation_report_lists_deadlines() {
+        let mut report = build_report();
+        assert!(!format_simulation_report(&report).contains("Deadlines:"));
+
+        report.deadlines = Some(vec![
+            DeadlineForecast {
+                id: None,
+                due_date: NaiveDate::from_ymd_opt(2026, 2, 14).unwrap(),
+                probability: 0.9,
+                slack_days_p85: 3,
+            },
+            DeadlineForecast {
+                id: Some("M1".to_string()),
+                due_date: NaiveDate::from_ymd_opt(2026, 2, 5).unwrap(),
+                probability: 0.25,
+                slack_days_p85: -4,
+            },
+        ]);
+        let output = format_simulation_report(&report);
+
+        assert!(output.contains("Target | Date | Probability | Slack at P85"));
+        assert!(output.contains("Project | 2026-02-14 | 90.0% | 3 days"));
+        assert!(output.contains("M1 | 2026-02-05 | 25.0% | -4 days"));
+    }
 }
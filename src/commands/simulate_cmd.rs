@@ -1,9 +1,19 @@
-use crate::commands::base_commands::SimulateProjectArgs;
+use crate::commands::base_commands::{
+    CorrelationGroupingKind, CycleTimeGroupingKind, DistributionKind, SimulateProjectArgs,
+};
 use crate::commands::report_format::format_simulation_report;
 use crate::commands::{CommandError, CommandResult};
+use crate::domain::estimate::DurationDistribution;
+use crate::services::parsing::cycle_time_yaml::load_cycle_times_from_yaml_file;
 use crate::services::plotting::histogram::write_histogram_png;
 use crate::services::plotting::milestone_plot::write_milestone_plot_png;
-use crate::services::project_simulation::project_simulation::simulate_project_from_yaml_file;
+use crate::services::plotting::tornado_chart::write_tornado_chart_png;
+use crate::services::project_simulation::beta_pert_sampler::PERT_SHAPE;
+use crate::services::project_simulation::correlation::{CorrelationGrouping, CorrelationOptions};
+use crate::services::project_simulation::cycle_time_sampler::CycleTimeGrouping;
+use crate::services::project_simulation::project_simulation::{
+    CycleTimeOptions, ProjectSimulationOptions, simulate_project_from_yaml_file,
+};
 
 pub fn simulate_command(args: SimulateProjectArgs) -> CommandResult {
     let SimulateProjectArgs {
@@ -12,9 +22,61 @@ pub fn simulate_command(args: SimulateProjectArgs) -> CommandResult {
         iterations,
         start_date,
         calendar_dir,
+        threads,
+        seed,
+        percentiles,
+        deadline,
+        cycle_times,
+        group_by,
+        wip_limit,
+        distribution,
+        pert_shape,
+        correlation,
+        correlate_by,
     } = args;
+    // Estimates that select modified PERT bring their own shape, so the
+    // option only applies to the default distribution.
+    if pert_shape.is_some() && distribution != DistributionKind::ModifiedPert {
+        return Err(CommandError::PertShapeWithoutModifiedPert);
+    }
 
-    let simulation = simulate_project_from_yaml_file(&input, iterations, start_date, calendar_dir.as_deref())
+    let cycle_times = match cycle_times {
+        Some(path) => Some(CycleTimeOptions {
+            history: load_cycle_times_from_yaml_file(&path)
+                .map_err(CommandError::ReadCycleTimes)?,
+            grouping: group_by.map(|kind| match kind {
+                CycleTimeGroupingKind::Size => CycleTimeGrouping::Size,
+                CycleTimeGroupingKind::Label => CycleTimeGrouping::Label,
+            }),
+        }),
+        None => None,
+    };
+    let options = ProjectSimulationOptions {
+        threads,
+        seed: seed.unwrap_or_else(rand::random),
+        percentiles,
+        deadline,
+        cycle_times,
+        wip_limit,
+        distribution: match distribution {
+            DistributionKind::BetaPert => DurationDistribution::BetaPert,
+            DistributionKind::ModifiedPert => DurationDistribution::ModifiedPert {
+                shape: pert_shape.unwrap_or(PERT_SHAPE),
+            },
+            DistributionKind::Triangular => DurationDistribution::Triangular,
+            DistributionKind::Uniform => DurationDistribution::Uniform,
+            DistributionKind::LogNormal => DurationDistribution::LogNormal,
+        },
+        correlation: correlation.map(|strength| CorrelationOptions {
+            strength,
+            grouping: match correlate_by {
+                CorrelationGroupingKind::CorrelationGroup => CorrelationGrouping::CorrelationGroup,
+                CorrelationGroupingKind::Subgraph => CorrelationGrouping::Subgraph,
+            },
+        }),
+        ..ProjectSimulationOptions::new(iterations, start_date)
+    };
+    let simulation = simulate_project_from_yaml_file(&input, calendar_dir.as_deref(), &options)
         .map_err(CommandError::SimulateProject)?;
 
     let histogram_path = format!("{output}.png");
@@ -31,6 +93,12 @@ pub fn simulate_command(args: SimulateProjectArgs) -> CommandResult {
         Err(error) => messages.push(format!("Warning: failed to write milestone plot: {error}")),
     }
 
+    let tornado_chart_path = format!("{output}.tornado.png");
+    match write_tornado_chart_png(&tornado_chart_path, &simulation.report) {
+        Ok(()) => messages.push(format!("Tornado chart written to {tornado_chart_path}")),
+        Err(error) => messages.push(format!("Warning: failed to write tornado chart: {error}")),
+    }
+
     let yaml = serde_yaml::to_string(&simulation.report).map_err(CommandError::SerializeSimulation)?;
     std::fs::write(&output, yaml).map_err(CommandError::WriteOutput)?;
 
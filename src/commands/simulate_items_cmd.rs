@@ -0,0 +1,43 @@
+use crate::commands::base_commands::SimulateItemsArgs;
+use crate::commands::report_format::format_item_forecast_report;
+use crate::commands::{CommandError, CommandResult};
+use crate::services::plotting::histogram::write_item_count_histogram_png;
+use crate::services::project_simulation::items_by_date_simulation::simulate_items_from_throughput_file;
+
+pub fn simulate_items_command(args: SimulateItemsArgs) -> CommandResult {
+    let SimulateItemsArgs {
+        throughput,
+        output,
+        iterations,
+        until,
+        start_date,
+        calendar_dir,
+        seed,
+        percentiles,
+    } = args;
+
+    let simulation = simulate_items_from_throughput_file(
+        &throughput,
+        iterations,
+        start_date,
+        until,
+        calendar_dir.as_deref(),
+        seed.unwrap_or_else(rand::random),
+        &percentiles,
+    )
+    .map_err(CommandError::SimulateThroughput)?;
+
+    let histogram_path = format!("{output}.png");
+    write_item_count_histogram_png(&histogram_path, &simulation.results)
+        .map_err(CommandError::WriteHistogram)?;
+
+    let yaml =
+        serde_yaml::to_string(&simulation.report).map_err(CommandError::SerializeSimulation)?;
+    std::fs::write(&output, yaml).map_err(CommandError::WriteOutput)?;
+
+    Ok(vec![
+        format_item_forecast_report(&simulation.report),
+        format!("Item forecast until {until} written to {output}"),
+        format!("Simulation histogram written to {histogram_path}"),
+    ])
+}
@@ -1,6 +1,9 @@
 use crate::commands::base_commands::SimulateThroughputArgs;
 use crate::commands::report_format::format_simulation_report;
 use crate::commands::{CommandError, CommandResult};
+use crate::services::plotting::histogram::write_histogram_png;
+use crate::services::project_simulation::scope_growth::ScopeModel;
+use crate::services::project_simulation::throughput_sampler::SamplingOptions;
 use crate::services::project_simulation::throughput_simulation::simulate_from_throughput_file;
 
 pub fn simulate_n_command(args: SimulateThroughputArgs) -> CommandResult {
@@ -11,24 +14,49 @@ pub fn simulate_n_command(args: SimulateThroughputArgs) -> CommandResult {
         number_of_issues,
         start_date,
         calendar_dir,
+        seed,
+        percentiles,
+        window_weeks,
+        half_life_days,
+        split_factor,
+        arrivals,
     } = args;
+    let sampling = SamplingOptions {
+        window_weeks,
+        half_life_days,
+    };
+
+    let scope = match (split_factor, arrivals) {
+        (Some((min, max)), _) => Some(ScopeModel::SplitFactor { min, max }),
+        (None, Some(arrivals)) => Some(
+            ScopeModel::arrival_rate_from_file(&arrivals)
+                .map_err(CommandError::SimulateThroughput)?,
+        ),
+        (None, None) => None,
+    };
 
     let histogram_path = format!("{output}.png");
     let simulation = simulate_from_throughput_file(
         &throughput,
+        sampling,
+        scope,
         iterations,
         number_of_issues,
         start_date,
-        &histogram_path,
         calendar_dir.as_deref(),
+        seed.unwrap_or_else(rand::random),
+        &percentiles,
     )
     .map_err(CommandError::SimulateThroughput)?;
+    write_histogram_png(&histogram_path, &simulation.results)
+        .map_err(CommandError::WriteHistogram)?;
 
-    let yaml = serde_yaml::to_string(&simulation).map_err(CommandError::SerializeSimulation)?;
+    let yaml =
+        serde_yaml::to_string(&simulation.report).map_err(CommandError::SerializeSimulation)?;
     std::fs::write(&output, yaml).map_err(CommandError::WriteOutput)?;
 
     Ok(vec![
-        format_simulation_report(&simulation),
+        format_simulation_report(&simulation.report),
         format!("Simulation result for {number_of_issues} items written to {output}"),
         format!("Simulation histogram written to {histogram_path}"),
     ])
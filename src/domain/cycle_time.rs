@@ -0,0 +1,14 @@
+use chrono::NaiveDate;
+
+/// Time an issue spent from the start of work until it was done.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CycleTime {
+    pub issue_id: String,
+    pub start_date: NaiveDate,
+    pub done_date: NaiveDate,
+    /// Calendar days including the start and the done day.
+    pub days: i64,
+    /// Story points of the issue, used to group cycle times by size.
+    pub story_points: Option<f32>,
+    pub labels: Vec<String>,
+}
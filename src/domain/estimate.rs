@@ -8,6 +8,27 @@ pub struct ThreePointEstimate {
     pub optimistic: Option<f32>,
     pub most_likely: Option<f32>,
     pub pessimistic: Option<f32>,
+    /// Overrides the distribution selected for the whole project.
+    pub distribution: Option<DurationDistribution>,
+}
+
+/// Distribution the duration of a three-point estimate is sampled from.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DurationDistribution {
+    /// Beta distribution of classic PERT.
+    #[default]
+    BetaPert,
+    /// PERT with the weight of the most likely value set by `shape`
+    /// instead of 4. Higher values concentrate samples around it.
+    ModifiedPert {
+        shape: f32,
+    },
+    Triangular,
+    /// Ignores the most likely value.
+    Uniform,
+    /// Fitted with the optimistic and pessimistic values as the 10th and
+    /// 90th percentiles; ignores the most likely value and is unbounded above.
+    LogNormal,
 }
 
 #[derive(Debug, Clone, PartialEq)]
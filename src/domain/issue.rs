@@ -9,6 +9,60 @@ pub struct IssueId {
     pub id: String,
 }
 
+/// A status change of an issue as recorded by the issue tracker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusTransition {
+    pub date: NaiveDate,
+    pub from_status: Option<String>,
+    pub to_status: String,
+    /// Category of `to_status`, if the tracker could map it to one.
+    pub category: Option<IssueStatus>,
+}
+
+/// A date a work package is scheduled around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateConstraint {
+    /// The package cannot start before the date, e.g. when it waits for an
+    /// external delivery.
+    StartNoEarlierThan(NaiveDate),
+    /// The package has to finish on or before the date, e.g. a regulatory
+    /// date. Finishing later violates the constraint.
+    MustFinishOnOrBefore(NaiveDate),
+    /// The milestone is reached on the date, not earlier, and violates the
+    /// constraint if its dependencies finish later.
+    FixedDate(NaiveDate),
+}
+
+impl DateConstraint {
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            DateConstraint::StartNoEarlierThan(date)
+            | DateConstraint::MustFinishOnOrBefore(date)
+            | DateConstraint::FixedDate(date) => *date,
+        }
+    }
+
+    /// The date before which the package cannot start, if any.
+    pub fn earliest_start(&self) -> Option<NaiveDate> {
+        match self {
+            DateConstraint::StartNoEarlierThan(date) | DateConstraint::FixedDate(date) => {
+                Some(*date)
+            }
+            DateConstraint::MustFinishOnOrBefore(_) => None,
+        }
+    }
+
+    /// The date after which finishing violates the constraint, if any.
+    pub fn latest_finish(&self) -> Option<NaiveDate> {
+        match self {
+            DateConstraint::MustFinishOnOrBefore(date) | DateConstraint::FixedDate(date) => {
+                Some(*date)
+            }
+            DateConstraint::StartNoEarlierThan(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Issue {
     pub issue_id: Option<IssueId>,
@@ -21,6 +75,16 @@ pub struct Issue {
     pub created_date: Option<NaiveDate>,
     pub start_date: Option<NaiveDate>,
     pub done_date: Option<NaiveDate>,
+    /// Committed date for a milestone; simulations report the probability of meeting it.
+    pub due_date: Option<NaiveDate>,
+    /// Status changes in chronological order; empty if the data source does not provide them.
+    pub status_history: Vec<StatusTransition>,
+    /// Labels or tags assigned in the issue tracker.
+    pub labels: Vec<String>,
+    /// Work packages of a group share part of their estimation error.
+    pub correlation_group: Option<String>,
+    /// Date the package is scheduled around.
+    pub constraint: Option<DateConstraint>,
 }
 
 impl Issue {
@@ -62,6 +126,27 @@ mod tests {
         assert_eq!(issue.created_date, None);
         assert_eq!(issue.start_date, None);
         assert_eq!(issue.done_date, None);
+        assert_eq!(issue.due_date, None);
+        assert!(issue.status_history.is_empty());
+        assert!(issue.labels.is_empty());
+        assert_eq!(issue.correlation_group, None);
+        assert_eq!(issue.constraint, None);
+    }
+
+    #[test]
+    fn date_constraints_bound_start_and_finish() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+
+        let start = DateConstraint::StartNoEarlierThan(date);
+        let finish = DateConstraint::MustFinishOnOrBefore(date);
+        let fixed = DateConstraint::FixedDate(date);
+
+        assert_eq!(start.earliest_start(), Some(date));
+        assert_eq!(start.latest_finish(), None);
+        assert_eq!(finish.earliest_start(), None);
+        assert_eq!(finish.latest_finish(), Some(date));
+        assert_eq!(fixed.earliest_start(), Some(date));
+        assert_eq!(fixed.latest_finish(), Some(date));
     }
 
     #[test]
@@ -77,6 +162,7 @@ mod tests {
                     optimistic: Some(1.0),
                     most_likely: Some(2.0),
                     pessimistic: Some(3.0),
+                    distribution: None,
                 },
             )),
             ..Issue::new()
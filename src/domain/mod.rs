@@ -1,7 +1,9 @@
 pub(crate) mod calendar;
+pub(crate) mod cycle_time;
 pub(crate) mod estimate;
 pub(crate) mod issue;
 pub(crate) mod issue_status;
 pub(crate) mod project;
+pub(crate) mod risk;
 pub(crate) mod throughput;
 pub(crate) mod validation;
@@ -1,10 +1,13 @@
 use crate::domain::estimate::{Estimate, StoryPointEstimate};
 use crate::domain::issue::Issue;
+use crate::domain::risk::Risk;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Project {
     pub name: String,
     pub work_packages: Vec<Issue>,
+    /// Events that may delay work packages.
+    pub risks: Vec<Risk>,
 }
 
 impl Project {
@@ -0,0 +1,14 @@
+use crate::domain::estimate::ThreePointEstimate;
+
+/// An event that may delay work packages of a project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Risk {
+    pub id: String,
+    pub description: Option<String>,
+    /// Chance that the risk occurs, from 0 to 1.
+    pub probability: f32,
+    /// Delay in days that an occurrence adds to every affected work package.
+    pub impact: ThreePointEstimate,
+    /// IDs of the affected work packages.
+    pub affects: Vec<String>,
+}
@@ -1,7 +1,146 @@
-use chrono::NaiveDate;
+use chrono::{Datelike, Duration, NaiveDate};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Throughput {
     pub date: NaiveDate,
     pub completed_issues: usize,
+    /// Team capacity on that day according to the team calendar, if one was
+    /// used when the throughput was recorded.
+    pub capacity: Option<f32>,
+}
+
+impl Throughput {
+    /// Completed issues scaled to a day at full capacity, or `None` for a day
+    /// without any capacity.
+    pub fn at_full_capacity(&self) -> Option<f32> {
+        match self.capacity {
+            None => Some(self.completed_issues as f32),
+            Some(capacity) if capacity > 0.0 => Some(self.completed_issues as f32 / capacity),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Period a throughput value counts completed issues over. The date of a
+/// value is the first day of its bucket.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ThroughputBucket {
+    #[default]
+    Day,
+    /// Monday to Sunday.
+    Week,
+    /// Consecutive sprints, the first one starting on `start`.
+    Sprint { start: NaiveDate, length_days: u32 },
+}
+
+impl ThroughputBucket {
+    pub fn length_days(&self) -> u32 {
+        match self {
+            ThroughputBucket::Day => 1,
+            ThroughputBucket::Week => 7,
+            ThroughputBucket::Sprint { length_days, .. } => *length_days,
+        }
+    }
+
+    /// First day of the bucket containing `date`.
+    pub fn start_of(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            ThroughputBucket::Day => date,
+            ThroughputBucket::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            ThroughputBucket::Sprint { start, length_days } => {
+                let length = (*length_days).max(1) as i64;
+                let offset = (date - *start).num_days().rem_euclid(length);
+                date - Duration::days(offset)
+            }
+        }
+    }
+
+    /// Completed issues per day at full capacity of a value of this bucket,
+    /// or `None` if it has no capacity. Buckets without a recorded capacity
+    /// are assumed to span the weekdays in them.
+    pub fn rate_at_full_capacity(&self, value: &Throughput) -> Option<f32> {
+        match (self, value.capacity) {
+            (ThroughputBucket::Day, _) | (_, Some(_)) => value.at_full_capacity(),
+            (_, None) => {
+                let weekdays = value
+                    .date
+                    .iter_days()
+                    .take(self.length_days() as usize)
+                    .filter(|date| date.weekday().num_days_from_monday() < 5)
+                    .count();
+                (weekdays > 0).then(|| value.completed_issues as f32 / weekdays as f32)
+            }
+        }
+    }
+}
+
+/// Throughput values together with the bucket they were aggregated by.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThroughputHistory {
+    pub bucket: ThroughputBucket,
+    pub throughput: Vec<Throughput>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    #[test]
+    fn bucket_start_of_aligns_weeks_and_sprints() {
+        let sprint = ThroughputBucket::Sprint {
+            start: date(5),
+            length_days: 14,
+        };
+
+        assert_eq!(ThroughputBucket::Day.start_of(date(8)), date(8));
+        assert_eq!(ThroughputBucket::Week.start_of(date(8)), date(5));
+        assert_eq!(ThroughputBucket::Week.start_of(date(11)), date(5));
+        assert_eq!(sprint.start_of(date(18)), date(5));
+        assert_eq!(sprint.start_of(date(19)), date(19));
+        assert_eq!(
+            sprint.start_of(date(4)),
+            NaiveDate::from_ymd_opt(2025, 12, 22).unwrap()
+        );
+    }
+
+    #[test]
+    fn weekly_rates_fall_back_to_weekdays_without_capacity() {
+        let weeks = [
+            Throughput {
+                date: date(5),
+                completed_issues: 10,
+                capacity: None,
+            },
+            Throughput {
+                date: date(12),
+                completed_issues: 6,
+                capacity: Some(3.0),
+            },
+        ];
+
+        let rates: Vec<Option<f32>> = weeks
+            .iter()
+            .map(|week| ThroughputBucket::Week.rate_at_full_capacity(week))
+            .collect();
+        assert_eq!(rates, vec![Some(2.0), Some(2.0)]);
+    }
+
+    #[test]
+    fn at_full_capacity_scales_by_recorded_capacity() {
+        let throughput = |capacity| Throughput {
+            date: date(5),
+            completed_issues: 2,
+            capacity,
+        };
+
+        assert_eq!(throughput(None).at_full_capacity(), Some(2.0));
+        assert_eq!(throughput(Some(0.5)).at_full_capacity(), Some(4.0));
+        assert_eq!(throughput(Some(0.0)).at_full_capacity(), None);
+    }
 }
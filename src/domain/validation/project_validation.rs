@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::fmt;
 use std::ops::Deref;
 
+use crate::domain::issue::DateConstraint;
 use crate::domain::issue_status::IssueStatus;
 use crate::domain::project::Project;
 use thiserror::Error;
@@ -39,6 +40,16 @@ pub enum ProjectValidationError {
         status: IssueStatus,
         date_type: DateType,
     },
+    #[error("Issue {0} has a due_date but is not a milestone.")]
+    DueDateOnNonMilestone(String),
+    #[error("Issue {0} has a fixed_date constraint but is not a milestone.")]
+    FixedDateOnNonMilestone(String),
+    #[error("Risk {0} needs a probability between 0 and 1.")]
+    InvalidRiskProbability(String),
+    #[error("Risk {0} needs delays with optimistic <= most_likely <= pessimistic.")]
+    InvalidRiskImpact(String),
+    #[error("Risk affects a non-existing work package: {0}")]
+    NonExistingRiskTarget(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -73,6 +84,9 @@ pub fn validate_project(project: &Project) -> Result<(), ValidationErrors> {
     validate_no_duplicate_ids(project, &mut errors);
     validate_dependency_references(project, &all_ids, &mut errors);
     validate_issue_statuses(project, &mut errors);
+    validate_due_dates(project, &mut errors);
+    validate_constraints(project, &mut errors);
+    validate_risks(project, &all_ids, &mut errors);
     if errors.is_empty() {
         Ok(())
     } else {
@@ -80,7 +94,7 @@ pub fn validate_project(project: &Project) -> Result<(), ValidationErrors> {
     }
 }
 
-fn collect_all_issue_ids<'a>(project: &'a Project) -> HashSet<&'a str> {
+fn collect_all_issue_ids(project: &Project) -> HashSet<&str> {
     project
         .work_packages
         .iter()
@@ -125,6 +139,64 @@ fn validate_dependency_references(
     }
 }
 
+fn validate_due_dates(project: &Project, errors: &mut Vec<ProjectValidationError>) {
+    for issue in &project.work_packages {
+        if issue.due_date.is_some() && !issue.is_milestone() {
+            let id = issue
+                .issue_id
+                .as_ref()
+                .map(|value| value.id.clone())
+                .unwrap_or_default();
+            errors.push(ProjectValidationError::DueDateOnNonMilestone(id));
+        }
+    }
+}
+
+fn validate_constraints(project: &Project, errors: &mut Vec<ProjectValidationError>) {
+    for issue in &project.work_packages {
+        if matches!(issue.constraint, Some(DateConstraint::FixedDate(_))) && !issue.is_milestone() {
+            let id = issue
+                .issue_id
+                .as_ref()
+                .map(|value| value.id.clone())
+                .unwrap_or_default();
+            errors.push(ProjectValidationError::FixedDateOnNonMilestone(id));
+        }
+    }
+}
+
+fn validate_risks(
+    project: &Project,
+    all_ids: &HashSet<&str>,
+    errors: &mut Vec<ProjectValidationError>,
+) {
+    for risk in &project.risks {
+        if !(0.0..=1.0).contains(&risk.probability) {
+            errors.push(ProjectValidationError::InvalidRiskProbability(
+                risk.id.clone(),
+            ));
+        }
+        let impact = &risk.impact;
+        let ordered = match (impact.optimistic, impact.most_likely, impact.pessimistic) {
+            (Some(optimistic), Some(most_likely), Some(pessimistic)) => {
+                0.0 <= optimistic && optimistic <= most_likely && most_likely <= pessimistic
+            }
+            _ => false,
+        };
+        if !ordered {
+            errors.push(ProjectValidationError::InvalidRiskImpact(risk.id.clone()));
+        }
+        for target in &risk.affects {
+            if !all_ids.contains(target.as_str()) {
+                errors.push(ProjectValidationError::NonExistingRiskTarget(format!(
+                    "{} -> {}",
+                    risk.id, target
+                )));
+            }
+        }
+    }
+}
+
 fn validate_issue_statuses(project: &Project, errors: &mut Vec<ProjectValidationError>) {
     for issue in &project.work_packages {
         let id = issue
@@ -195,7 +267,9 @@ mod tests {
     use chrono::NaiveDate;
 
     use super::*;
+    use crate::domain::estimate::ThreePointEstimate;
     use crate::domain::issue::{Issue, IssueId};
+    use crate::domain::risk::Risk;
 
     fn make_issue(id: &str) -> Issue {
         let mut issue = Issue::new();
@@ -217,6 +291,7 @@ mod tests {
         let project = Project {
             name: "Demo".to_string(),
             work_packages: vec![todo, issue],
+            risks: Vec::new(),
         };
 
         assert!(validate_project(&project).is_ok());
@@ -229,6 +304,7 @@ mod tests {
         let project = Project {
             name: "Demo".to_string(),
             work_packages: vec![issue1, issue2],
+            risks: Vec::new(),
         };
 
         let errors = validate_project(&project).unwrap_err();
@@ -247,6 +323,7 @@ mod tests {
         let project = Project {
             name: "Demo".to_string(),
             work_packages: vec![issue],
+            risks: Vec::new(),
         };
 
         let errors = validate_project(&project).unwrap_err();
@@ -275,6 +352,7 @@ mod tests {
         let project = Project {
             name: "Demo".to_string(),
             work_packages: vec![todo, todo_2, in_progress, done],
+            risks: Vec::new(),
         };
 
         let errors = validate_project(&project).unwrap_err();
@@ -320,4 +398,92 @@ mod tests {
             )
         }));
     }
+
+    #[test]
+    fn validate_project_reports_due_date_on_non_milestone() {
+        let mut milestone = make_issue("M-1");
+        milestone.estimate = Some(crate::domain::estimate::Estimate::Milestone);
+        milestone.due_date = NaiveDate::from_ymd_opt(2026, 3, 1);
+
+        let mut task = make_issue("TASK-1");
+        task.due_date = NaiveDate::from_ymd_opt(2026, 3, 1);
+
+        let project = Project {
+            name: "Demo".to_string(),
+            work_packages: vec![milestone, task],
+            risks: Vec::new(),
+        };
+
+        let errors = validate_project(&project).unwrap_err();
+        assert_eq!(
+            errors.0,
+            vec![ProjectValidationError::DueDateOnNonMilestone(
+                "TASK-1".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_project_reports_fixed_date_on_non_milestone() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let mut milestone = make_issue("M-1");
+        milestone.estimate = Some(crate::domain::estimate::Estimate::Milestone);
+        milestone.constraint = Some(DateConstraint::FixedDate(date));
+
+        let mut task = make_issue("TASK-1");
+        task.constraint = Some(DateConstraint::FixedDate(date));
+
+        let mut delivery = make_issue("TASK-2");
+        delivery.constraint = Some(DateConstraint::StartNoEarlierThan(date));
+
+        let project = Project {
+            name: "Demo".to_string(),
+            work_packages: vec![milestone, task, delivery],
+            risks: Vec::new(),
+        };
+
+        let errors = validate_project(&project).unwrap_err();
+        assert_eq!(
+            errors.0,
+            vec![ProjectValidationError::FixedDateOnNonMilestone(
+                "TASK-1".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_project_reports_invalid_risks() {
+        let risk = |id: &str, probability: f32, delays: (f32, f32, f32), target: &str| Risk {
+            id: id.to_string(),
+            description: None,
+            probability,
+            impact: ThreePointEstimate {
+                optimistic: Some(delays.0),
+                most_likely: Some(delays.1),
+                pessimistic: Some(delays.2),
+                distribution: None,
+            },
+            affects: vec![target.to_string()],
+        };
+        let project = Project {
+            name: "Demo".to_string(),
+            work_packages: vec![make_issue("ABC-1")],
+            risks: vec![
+                risk("R1", 0.5, (1.0, 2.0, 3.0), "ABC-1"),
+                risk("R2", 1.5, (1.0, 2.0, 3.0), "ABC-1"),
+                risk("R3", 0.5, (1.0, 4.0, 3.0), "ABC-1"),
+                risk("R4", 0.5, (1.0, 2.0, 3.0), "ABC-404"),
+            ],
+        };
+
+        let errors = validate_project(&project).unwrap_err();
+        assert_eq!(
+            errors.0,
+            vec![
+                ProjectValidationError::InvalidRiskProbability("R2".to_string()),
+                ProjectValidationError::InvalidRiskImpact("R3".to_string()),
+                ProjectValidationError::NonExistingRiskTarget("R4 -> ABC-404".to_string()),
+            ]
+        );
+    }
 }
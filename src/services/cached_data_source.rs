@@ -0,0 +1,232 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{Duration, Utc};
+
+use crate::domain::issue::Issue;
+use crate::services::data_source::{DataQuery, DataSource, DataSourceError};
+use crate::services::parsing::issue_cache_yaml::{
+    CachedIssues, deserialize_cached_issues_from_yaml_str, serialize_cached_issues_to_yaml,
+};
+
+/// When the cache is consulted instead of the wrapped data source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheMode {
+    /// Use cached issues younger than the max age, fetch otherwise.
+    Normal,
+    /// Always fetch and update the cache.
+    Refresh,
+    /// Never fetch; cached issues are used regardless of their age.
+    Offline,
+}
+
+/// Decorates a data source with an on-disk cache of the mapped issues, one
+/// file per query.
+pub struct CachedDataSource {
+    /// Missing in offline mode, where no connection is set up at all.
+    inner: Option<Box<dyn DataSource>>,
+    cache_dir: PathBuf,
+    /// Distinguishes equal queries against different data sources.
+    namespace: String,
+    max_age: Duration,
+    mode: CacheMode,
+}
+
+impl CachedDataSource {
+    pub fn new(
+        inner: Option<Box<dyn DataSource>>,
+        cache_dir: impl Into<PathBuf>,
+        namespace: impl Into<String>,
+        max_age: Duration,
+        mode: CacheMode,
+    ) -> Self {
+        Self {
+            inner,
+            cache_dir: cache_dir.into(),
+            namespace: namespace.into(),
+            max_age,
+            mode,
+        }
+    }
+
+    fn cache_file(&self, query: &str) -> PathBuf {
+        let key = fnv1a_hash(&[self.namespace.as_bytes(), b"\0", query.as_bytes()]);
+        self.cache_dir.join(format!("{key:016x}.yaml"))
+    }
+
+    fn read_cache(&self, path: &Path) -> Option<CachedIssues> {
+        let contents = fs::read_to_string(path).ok()?;
+        deserialize_cached_issues_from_yaml_str(&contents).ok()
+    }
+
+    fn write_cache(&self, path: &Path, cached: &CachedIssues) -> Result<(), DataSourceError> {
+        let yaml = serialize_cached_issues_to_yaml(cached)
+            .map_err(|err| DataSourceError::Other(format!("failed to serialize cache: {err}")))?;
+        fs::create_dir_all(&self.cache_dir)
+            .and_then(|_| fs::write(path, yaml))
+            .map_err(|err| {
+                DataSourceError::Other(format!(
+                    "failed to write cache file {}: {err}",
+                    path.display()
+                ))
+            })
+    }
+}
+
+impl DataSource for CachedDataSource {
+    fn get_issues(&self, query: DataQuery) -> Result<Vec<Issue>, DataSourceError> {
+        let DataQuery::StringQuery(query_text) = &query;
+        let path = self.cache_file(query_text);
+
+        if self.mode != CacheMode::Refresh
+            && let Some(cached) = self.read_cache(&path)
+        {
+            let is_fresh = Utc::now() - cached.fetched_at <= self.max_age;
+            if self.mode == CacheMode::Offline || is_fresh {
+                return Ok(cached.issues);
+            }
+        }
+
+        let inner = match (&self.inner, self.mode) {
+            (Some(inner), mode) if mode != CacheMode::Offline => inner,
+            _ => {
+                return Err(DataSourceError::NotCached {
+                    query: query_text.clone(),
+                });
+            }
+        };
+
+        let cached = CachedIssues {
+            query: query_text.clone(),
+            fetched_at: Utc::now(),
+            issues: inner.get_issues(query)?,
+        };
+        self.write_cache(&path, &cached)?;
+        Ok(cached.issues)
+    }
+}
+
+/// 64-bit FNV-1a; unlike the std hashers its output is stable across Rust
+/// releases, so cache file names stay valid.
+fn fnv1a_hash(parts: &[&[u8]]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    parts
+        .iter()
+        .flat_map(|part| part.iter())
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::issue::IssueId;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct CountingDataSource {
+        calls: Rc<Cell<usize>>,
+    }
+
+    impl DataSource for CountingDataSource {
+        fn get_issues(&self, query: DataQuery) -> Result<Vec<Issue>, DataSourceError> {
+            self.calls.set(self.calls.get() + 1);
+            let DataQuery::StringQuery(query) = query;
+            let mut issue = Issue::new();
+            issue.issue_id = Some(IssueId {
+                id: format!("{query}-{}", self.calls.get()),
+            });
+            Ok(vec![issue])
+        }
+    }
+
+    fn cached_source(
+        cache_dir: &Path,
+        mode: CacheMode,
+        max_age: Duration,
+    ) -> (CachedDataSource, Rc<Cell<usize>>) {
+        let calls = Rc::new(Cell::new(0));
+        let inner: Box<dyn DataSource> = Box::new(CountingDataSource {
+            calls: calls.clone(),
+        });
+        let source = CachedDataSource::new(Some(inner), cache_dir, "jira", max_age, mode);
+        (source, calls)
+    }
+
+    fn first_id(issues: Vec<Issue>) -> String {
+        issues[0].issue_id.as_ref().unwrap().id.clone()
+    }
+
+    fn query(text: &str) -> DataQuery {
+        DataQuery::StringQuery(text.to_string())
+    }
+
+    #[test]
+    fn fresh_cache_entries_are_replayed_per_query() {
+        let cache_dir = assert_fs::TempDir::new().unwrap();
+        let (source, calls) =
+            cached_source(cache_dir.path(), CacheMode::Normal, Duration::hours(1));
+
+        assert_eq!(first_id(source.get_issues(query("a")).unwrap()), "a-1");
+        assert_eq!(first_id(source.get_issues(query("a")).unwrap()), "a-1");
+        assert_eq!(first_id(source.get_issues(query("b")).unwrap()), "b-2");
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn expired_entries_and_refresh_fetch_again() {
+        let cache_dir = assert_fs::TempDir::new().unwrap();
+        let (expired, calls) = cached_source(cache_dir.path(), CacheMode::Normal, Duration::zero());
+        expired.get_issues(query("a")).unwrap();
+        expired.get_issues(query("a")).unwrap();
+        assert_eq!(calls.get(), 2);
+
+        let (refresh, calls) =
+            cached_source(cache_dir.path(), CacheMode::Refresh, Duration::hours(1));
+        assert_eq!(first_id(refresh.get_issues(query("a")).unwrap()), "a-1");
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn offline_mode_replays_stale_entries_and_fails_on_misses() {
+        let cache_dir = assert_fs::TempDir::new().unwrap();
+        let (online, _) = cached_source(cache_dir.path(), CacheMode::Normal, Duration::hours(1));
+        online.get_issues(query("a")).unwrap();
+
+        let offline = CachedDataSource::new(
+            None,
+            cache_dir.path(),
+            "jira",
+            Duration::zero(),
+            CacheMode::Offline,
+        );
+
+        assert_eq!(first_id(offline.get_issues(query("a")).unwrap()), "a-1");
+        let error = offline.get_issues(query("b")).unwrap_err();
+        assert!(matches!(error, DataSourceError::NotCached { query } if query == "b"));
+    }
+
+    #[test]
+    fn namespaces_separate_equal_queries() {
+        let a = CachedDataSource::new(
+            None,
+            "cache",
+            "jira:a",
+            Duration::zero(),
+            CacheMode::Offline,
+        );
+        let b = CachedDataSource::new(
+            None,
+            "cache",
+            "jira:b",
+            Duration::zero(),
+            CacheMode::Offline,
+        );
+
+        assert_ne!(a.cache_file("q"), b.cache_file("q"));
+        assert_eq!(a.cache_file("q"), a.cache_file("q"));
+    }
+}
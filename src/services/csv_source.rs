@@ -0,0 +1,378 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use csv::StringRecord;
+use serde::Deserialize;
+
+use crate::domain::estimate::{Estimate, StoryPointEstimate};
+use crate::domain::issue::Issue;
+use crate::domain::issue::IssueId;
+use crate::domain::issue_status::IssueStatus;
+use crate::services::data_source::{DataQuery, DataSource, DataSourceError};
+use crate::services::util::parse_query_pairs;
+
+/// Names of the CSV columns the issue fields are read from. Only the id
+/// column is required.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CsvColumns {
+    pub id: String,
+    pub summary: String,
+    pub status: String,
+    pub start_date: String,
+    pub done_date: String,
+    pub estimate: String,
+    pub dependencies: String,
+}
+
+impl Default for CsvColumns {
+    fn default() -> Self {
+        Self {
+            id: "id".to_string(),
+            summary: "summary".to_string(),
+            status: "status".to_string(),
+            start_date: "start_date".to_string(),
+            done_date: "done_date".to_string(),
+            estimate: "estimate".to_string(),
+            dependencies: "dependencies".to_string(),
+        }
+    }
+}
+
+/// Configuration of a CSV export used as data source.
+///
+/// The queries are optional row filters in URL query syntax, e.g.
+/// `Team=Payments&Type=Story`, matched against the CSV columns.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CsvProjectMetaData {
+    /// CSV file, relative paths are resolved against the config file.
+    pub file_path: String,
+    pub project_name: String,
+    pub throughput_query: String,
+    pub project_query: String,
+    pub delimiter: char,
+    pub date_format: String,
+    /// Separator between the ids in the dependencies column.
+    pub dependency_separator: char,
+    pub columns: CsvColumns,
+}
+
+impl Default for CsvProjectMetaData {
+    fn default() -> Self {
+        Self {
+            file_path: String::new(),
+            project_name: String::new(),
+            throughput_query: String::new(),
+            project_query: String::new(),
+            delimiter: ',',
+            date_format: "%Y-%m-%d".to_string(),
+            dependency_separator: ';',
+            columns: CsvColumns::default(),
+        }
+    }
+}
+
+impl CsvProjectMetaData {
+    pub fn from_yaml_file(filepath: &str) -> Result<Self, DataSourceError> {
+        let contents = fs::read_to_string(filepath)
+            .map_err(|err| DataSourceError::Other(format!("failed to read config: {err}")))?;
+        let mut metadata: CsvProjectMetaData =
+            serde_yaml::from_str(&contents).map_err(|_| DataSourceError::Parse)?;
+        metadata.file_path = resolve_relative_to(filepath, &metadata.file_path)
+            .to_string_lossy()
+            .into_owned();
+        Ok(metadata)
+    }
+}
+
+pub struct CsvConfigParser;
+
+impl CsvConfigParser {
+    pub fn parse(&self, filepath: &str) -> Result<CsvProjectMetaData, DataSourceError> {
+        CsvProjectMetaData::from_yaml_file(filepath)
+    }
+}
+
+/// Column positions of the configured fields in the header row.
+struct ColumnIndices {
+    id: usize,
+    summary: Option<usize>,
+    status: Option<usize>,
+    start_date: Option<usize>,
+    done_date: Option<usize>,
+    estimate: Option<usize>,
+    dependencies: Option<usize>,
+}
+
+pub struct CsvDataSource {
+    csv_project: CsvProjectMetaData,
+}
+
+impl CsvDataSource {
+    pub fn new(csv_project: CsvProjectMetaData) -> Result<Self, DataSourceError> {
+        if csv_project.file_path.is_empty() {
+            return Err(DataSourceError::Other(
+                "csv metadata is missing file_path".to_string(),
+            ));
+        }
+        if !csv_project.delimiter.is_ascii() {
+            return Err(DataSourceError::Other(
+                "csv delimiter must be an ASCII character".to_string(),
+            ));
+        }
+
+        Ok(Self { csv_project })
+    }
+
+    fn read_issues(&self, filter: &str) -> Result<Vec<Issue>, DataSourceError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.csv_project.delimiter as u8)
+            .trim(csv::Trim::All)
+            .from_path(&self.csv_project.file_path)
+            .map_err(|err| match err.kind() {
+                csv::ErrorKind::Io(io) if io.kind() == std::io::ErrorKind::NotFound => {
+                    DataSourceError::NotFound
+                }
+                _ => DataSourceError::Other(format!("failed to read csv: {err}")),
+            })?;
+
+        let headers = reader
+            .headers()
+            .map_err(|_| DataSourceError::Parse)?
+            .clone();
+        let indices = self.column_indices(&headers)?;
+        let filters = parse_query_pairs(filter)
+            .into_iter()
+            .map(|(column, value)| {
+                column_index(&headers, &column)
+                    .map(|index| (index, value))
+                    .ok_or_else(|| DataSourceError::Other(format!("unknown csv column: {column}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut issues = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(|_| DataSourceError::Parse)?;
+            let matches = filters
+                .iter()
+                .all(|(index, value)| record.get(*index) == Some(value.as_str()));
+            if matches {
+                issues.push(self.map_record(&record, &indices)?);
+            }
+        }
+        Ok(issues)
+    }
+
+    fn column_indices(&self, headers: &StringRecord) -> Result<ColumnIndices, DataSourceError> {
+        let columns = &self.csv_project.columns;
+        let id = column_index(headers, &columns.id).ok_or_else(|| {
+            DataSourceError::Other(format!("csv file has no id column '{}'", columns.id))
+        })?;
+
+        Ok(ColumnIndices {
+            id,
+            summary: column_index(headers, &columns.summary),
+            status: column_index(headers, &columns.status),
+            start_date: column_index(headers, &columns.start_date),
+            done_date: column_index(headers, &columns.done_date),
+            estimate: column_index(headers, &columns.estimate),
+            dependencies: column_index(headers, &columns.dependencies),
+        })
+    }
+
+    fn map_record(
+        &self,
+        record: &StringRecord,
+        indices: &ColumnIndices,
+    ) -> Result<Issue, DataSourceError> {
+        let cell = |index: Option<usize>| {
+            index
+                .and_then(|index| record.get(index))
+                .filter(|value| !value.is_empty())
+        };
+        let date = |index: Option<usize>| -> Result<Option<NaiveDate>, DataSourceError> {
+            cell(index)
+                .map(|value| {
+                    NaiveDate::parse_from_str(value, &self.csv_project.date_format)
+                        .map_err(|_| DataSourceError::Parse)
+                })
+                .transpose()
+        };
+
+        let id = cell(Some(indices.id)).ok_or(DataSourceError::Parse)?;
+
+        let mut issue = Issue::new();
+        issue.issue_id = Some(IssueId { id: id.to_string() });
+        issue.summary = cell(indices.summary).map(|value| value.to_string());
+        issue.start_date = date(indices.start_date)?;
+        issue.done_date = date(indices.done_date)?;
+        issue.status = cell(indices.status).and_then(parse_status);
+        issue.estimate = cell(indices.estimate)
+            .map(|value| value.parse::<f32>().map_err(|_| DataSourceError::Parse))
+            .transpose()?
+            .map(|value| {
+                Estimate::StoryPoint(StoryPointEstimate {
+                    estimate: Some(value),
+                })
+            });
+        if let Some(dependencies) = cell(indices.dependencies) {
+            issue.dependencies = Some(
+                dependencies
+                    .split(self.csv_project.dependency_separator)
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(|id| IssueId { id: id.to_string() })
+                    .collect(),
+            );
+        }
+        Ok(issue)
+    }
+}
+
+impl DataSource for CsvDataSource {
+    fn get_issues(&self, query: DataQuery) -> Result<Vec<Issue>, DataSourceError> {
+        match query {
+            DataQuery::StringQuery(filter) => self.read_issues(&filter),
+        }
+    }
+}
+
+fn resolve_relative_to(config_path: &str, file_path: &str) -> PathBuf {
+    let path = Path::new(file_path);
+    if path.is_absolute() || file_path.is_empty() {
+        return path.to_path_buf();
+    }
+    Path::new(config_path)
+        .parent()
+        .map(|dir| dir.join(path))
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+fn column_index(headers: &StringRecord, name: &str) -> Option<usize> {
+    if name.is_empty() {
+        return None;
+    }
+    headers
+        .iter()
+        .position(|header| header.eq_ignore_ascii_case(name))
+}
+
+fn parse_status(value: &str) -> Option<IssueStatus> {
+    match value.to_ascii_lowercase().as_str() {
+        "todo" | "to do" | "open" | "backlog" => Some(IssueStatus::ToDo),
+        "inprogress" | "in progress" | "doing" => Some(IssueStatus::InProgress),
+        "done" | "closed" | "resolved" => Some(IssueStatus::Done),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    fn data_source(
+        file: &assert_fs::NamedTempFile,
+        csv_project: CsvProjectMetaData,
+    ) -> CsvDataSource {
+        CsvDataSource::new(CsvProjectMetaData {
+            file_path: file.path().to_str().unwrap().to_string(),
+            ..csv_project
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn reads_issues_with_default_columns() {
+        let file = assert_fs::NamedTempFile::new("issues.csv").unwrap();
+        file.write_str(
+            "id,summary,status,start_date,done_date,estimate,dependencies\n\
+             A-1,Design,Done,2026-01-05,2026-01-09,3,\n\
+             A-2,\"Build, test\",In Progress,2026-01-12,,5,A-1\n",
+        )
+        .unwrap();
+
+        let issues = data_source(&file, CsvProjectMetaData::default())
+            .get_issues(DataQuery::StringQuery(String::new()))
+            .unwrap();
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].status, Some(IssueStatus::Done));
+        assert_eq!(issues[0].done_date, NaiveDate::from_ymd_opt(2026, 1, 9));
+        assert_eq!(issues[0].dependencies, Some(Vec::new()));
+        assert_eq!(issues[1].summary.as_deref(), Some("Build, test"));
+        assert_eq!(issues[1].story_point_value(), Some(5.0));
+        assert_eq!(
+            issues[1].dependencies,
+            Some(vec![IssueId {
+                id: "A-1".to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn reads_issues_with_mapped_columns_and_filter() {
+        let file = assert_fs::NamedTempFile::new("export.csv").unwrap();
+        file.write_str(
+            "Key;Team;Resolved;Blocked By\n\
+             X-1;Payments;09.01.2026;\n\
+             X-2;Search;10.01.2026;\n\
+             X-3;Payments;;X-1, X-2\n",
+        )
+        .unwrap();
+        let csv_project = CsvProjectMetaData {
+            delimiter: ';',
+            date_format: "%d.%m.%Y".to_string(),
+            dependency_separator: ',',
+            columns: CsvColumns {
+                id: "Key".to_string(),
+                done_date: "Resolved".to_string(),
+                dependencies: "Blocked By".to_string(),
+                ..CsvColumns::default()
+            },
+            ..CsvProjectMetaData::default()
+        };
+
+        let issues = data_source(&file, csv_project)
+            .get_issues(DataQuery::StringQuery("Team=Payments".to_string()))
+            .unwrap();
+
+        let ids: Vec<&str> = issues
+            .iter()
+            .map(|issue| issue.issue_id.as_ref().unwrap().id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["X-1", "X-3"]);
+        assert_eq!(issues[0].done_date, NaiveDate::from_ymd_opt(2026, 1, 9));
+        assert_eq!(issues[1].dependencies.as_ref().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_missing_id_column_and_bad_dates() {
+        let file = assert_fs::NamedTempFile::new("broken.csv").unwrap();
+        file.write_str("summary\nTask\n").unwrap();
+        let error = data_source(&file, CsvProjectMetaData::default())
+            .get_issues(DataQuery::StringQuery(String::new()))
+            .unwrap_err();
+        assert!(matches!(error, DataSourceError::Other(_)));
+
+        file.write_str("id,done_date\nA-1,09/01/2026\n").unwrap();
+        let error = data_source(&file, CsvProjectMetaData::default())
+            .get_issues(DataQuery::StringQuery(String::new()))
+            .unwrap_err();
+        assert!(matches!(error, DataSourceError::Parse));
+    }
+
+    #[test]
+    fn file_path_is_resolved_relative_to_config() {
+        assert_eq!(
+            resolve_relative_to("/data/config.yaml", "issues.csv"),
+            PathBuf::from("/data/issues.csv")
+        );
+        assert_eq!(
+            resolve_relative_to("/data/config.yaml", "/tmp/issues.csv"),
+            PathBuf::from("/tmp/issues.csv")
+        );
+    }
+}
@@ -1,16 +1,38 @@
 use std::collections::BTreeMap;
 
-use crate::domain::throughput::Throughput;
+use crate::domain::calendar::TeamCalendar;
+use crate::domain::cycle_time::CycleTime;
+use crate::domain::throughput::{Throughput, ThroughputBucket};
 use crate::services::data_source::{DataQuery, DataSource, DataSourceError};
-use chrono::{Datelike, NaiveDate};
+use chrono::{Duration, NaiveDate};
 
 pub struct DataConverter {
     data_source: Box<dyn DataSource>,
+    calendar: Option<TeamCalendar>,
+    bucket: ThroughputBucket,
 }
 
 impl DataConverter {
     pub fn new(data_source: Box<dyn DataSource>) -> Self {
-        Self { data_source }
+        Self {
+            data_source,
+            calendar: None,
+            bucket: ThroughputBucket::Day,
+        }
+    }
+
+    /// Aggregates throughput over the working days of `calendar` and records
+    /// the capacity of each day, instead of over all weekdays.
+    pub fn with_calendar(mut self, calendar: TeamCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Sums throughput per `bucket` instead of per day. The capacity of a
+    /// bucket is the sum of the capacities of its working days.
+    pub fn with_bucket(mut self, bucket: ThroughputBucket) -> Self {
+        self.bucket = bucket;
+        self
     }
 
     pub fn get_throughput_data(
@@ -21,32 +43,215 @@ impl DataConverter {
 
         let done_dates: Vec<NaiveDate> =
             issues.iter().filter_map(|issue| issue.done_date).collect();
-        let min_date = *done_dates.iter().min().ok_or(DataSourceError::NotFound)?;
-        let max_date = *done_dates.iter().max().ok_or(DataSourceError::NotFound)?;
+        self.count_per_bucket(&done_dates)
+    }
 
-        let mut date_counts: BTreeMap<NaiveDate, usize> = BTreeMap::new();
-        for date in done_dates {
-            *date_counts.entry(date).or_insert(0) += 1;
-        }
+    /// Like `get_throughput_data`, but counts issues by their created date,
+    /// i.e. the rate at which new issues arrive.
+    pub fn get_arrival_data(
+        &self,
+        data_query: DataQuery,
+    ) -> Result<Vec<Throughput>, DataSourceError> {
+        let issues = self.data_source.get_issues(data_query)?;
+
+        let created_dates: Vec<NaiveDate> = issues
+            .iter()
+            .filter_map(|issue| issue.created_date)
+            .collect();
+        self.count_per_bucket(&created_dates)
+    }
+
+    fn count_per_bucket(&self, dates: &[NaiveDate]) -> Result<Vec<Throughput>, DataSourceError> {
+        let min_date = *dates.iter().min().ok_or(DataSourceError::NotFound)?;
+        let max_date = *dates.iter().max().ok_or(DataSourceError::NotFound)?;
 
-        fn is_weekend(date: NaiveDate) -> bool {
-            matches!(date.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun)
+        Ok(self.aggregate(dates, min_date, max_date))
+    }
+
+    /// First day whose issues are needed to fetch the bucket starting on
+    /// `last_date` again. With a team calendar, issues done on the free days
+    /// right before a day count towards that day, so these days are fetched
+    /// as well.
+    pub fn refetch_start(&self, last_date: NaiveDate) -> NaiveDate {
+        if self.bucket != ThroughputBucket::Day {
+            return self.bucket.start_of(last_date);
+        }
+        let Some(calendar) = self.calendar.as_ref() else {
+            return last_date;
+        };
+        let mut start = last_date;
+        for _ in 0..MAX_FREE_DAYS {
+            match start.pred_opt() {
+                Some(previous) if calendar.get_capacity(previous) <= 0.0 => start = previous,
+                _ => break,
+            }
         }
+        start
+    }
+
+    /// Throughput from the bucket containing `since` up to the latest done
+    /// date, for extending an existing history. Issues done earlier are
+    /// ignored, so the query may return more issues than needed.
+    pub fn get_throughput_data_since(
+        &self,
+        data_query: DataQuery,
+        since: NaiveDate,
+    ) -> Result<Vec<Throughput>, DataSourceError> {
+        let issues = self.data_source.get_issues(data_query)?;
+        let since = self.bucket.start_of(since);
+
+        let done_dates: Vec<NaiveDate> = issues
+            .iter()
+            .filter_map(|issue| issue.done_date)
+            .filter(|&date| date >= since)
+            .collect();
+        let max_date = done_dates.iter().copied().max().unwrap_or(since);
+
+        Ok(self.aggregate(&done_dates, since, max_date))
+    }
 
-        let mut throughput_data = Vec::new();
-        for date in min_date.iter_days().take_while(|&d| d <= max_date) {
-            if is_weekend(date) {
-                continue;
+    /// Appends `update` to `history`. Recorded buckets from the first updated
+    /// one onwards are replaced rather than added to, so refetching the last
+    /// recorded bucket does not count its issues twice. Buckets between both
+    /// are zero-filled.
+    pub fn merge_throughput(
+        &self,
+        history: Vec<Throughput>,
+        update: Vec<Throughput>,
+    ) -> Vec<Throughput> {
+        let Some(first_update) = update.first().map(|throughput| throughput.date) else {
+            return history;
+        };
+
+        let mut merged: Vec<Throughput> = history
+            .into_iter()
+            .filter(|throughput| throughput.date < first_update)
+            .collect();
+        if let Some(last) = merged.last().map(|throughput| throughput.date) {
+            let gap_start = last + Duration::days(self.bucket.length_days() as i64);
+            let gap_end = first_update.pred_opt().unwrap_or(first_update);
+            if gap_start <= gap_end {
+                merged.extend(self.aggregate(&[], gap_start, gap_end));
             }
+        }
+        merged.extend(update);
+        merged
+    }
 
-            throughput_data.push(Throughput {
+    /// Throughput per bucket from `min_date` to `max_date`. A bucket counts
+    /// every issue done in it, also on days without capacity; the calendar
+    /// only determines its capacity.
+    fn aggregate(
+        &self,
+        done_dates: &[NaiveDate],
+        min_date: NaiveDate,
+        max_date: NaiveDate,
+    ) -> Vec<Throughput> {
+        let calendar = self.calendar.as_ref();
+        if self.bucket == ThroughputBucket::Day {
+            return count_per_working_day(done_dates, min_date, max_date, calendar);
+        }
+
+        let mut buckets: BTreeMap<NaiveDate, (usize, f32)> = BTreeMap::new();
+        for day in count_per_working_day(&[], min_date, max_date, calendar) {
+            let (_, capacity) = buckets.entry(self.bucket.start_of(day.date)).or_default();
+            *capacity += day.capacity.unwrap_or(1.0);
+        }
+        for &date in done_dates {
+            let (completed_issues, _) = buckets.entry(self.bucket.start_of(date)).or_default();
+            *completed_issues += 1;
+        }
+        buckets
+            .into_iter()
+            .map(|(date, (completed_issues, capacity))| Throughput {
                 date,
-                completed_issues: *date_counts.get(&date).unwrap_or(&0),
-            });
+                completed_issues,
+                capacity: Some(capacity),
+            })
+            .collect()
+    }
+
+    /// Cycle times of all issues with a start and a done date, ordered by
+    /// done date.
+    pub fn get_cycle_time_data(
+        &self,
+        data_query: DataQuery,
+    ) -> Result<Vec<CycleTime>, DataSourceError> {
+        let issues = self.data_source.get_issues(data_query)?;
+
+        let mut cycle_times: Vec<CycleTime> = issues
+            .iter()
+            .filter_map(|issue| {
+                let start_date = issue.start_date?;
+                let done_date = issue.done_date?;
+                Some(CycleTime {
+                    issue_id: issue
+                        .issue_id
+                        .as_ref()
+                        .map(|id| id.id.clone())
+                        .unwrap_or_default(),
+                    start_date,
+                    done_date,
+                    days: (done_date - start_date).num_days() + 1,
+                    story_points: issue.story_point_value(),
+                    labels: issue.labels.clone(),
+                })
+            })
+            .collect();
+        if cycle_times.is_empty() {
+            return Err(DataSourceError::NotFound);
+        }
+
+        cycle_times.sort_by_key(|cycle_time| cycle_time.done_date);
+        Ok(cycle_times)
+    }
+}
+
+/// Longest run of free days before a day that is fetched again with it.
+const MAX_FREE_DAYS: i64 = 366;
+
+/// Issues done per working day from `min_date` to `max_date`, zero-filled.
+/// Days without capacity are skipped; without a team calendar these are the
+/// weekends, and issues done on them are not counted. With a team calendar,
+/// issues done on a free day count towards the next working day, or towards
+/// the last one if no working day follows up to `max_date`.
+fn count_per_working_day(
+    done_dates: &[NaiveDate],
+    min_date: NaiveDate,
+    max_date: NaiveDate,
+    calendar: Option<&TeamCalendar>,
+) -> Vec<Throughput> {
+    let mut date_counts: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+    for &date in done_dates {
+        *date_counts.entry(date).or_insert(0) += 1;
+    }
+
+    let default_calendar = TeamCalendar::new();
+    let capacity_calendar = calendar.unwrap_or(&default_calendar);
+
+    let mut throughput_data: Vec<Throughput> = Vec::new();
+    let mut carried_issues = 0;
+    for date in min_date.iter_days().take_while(|&date| date <= max_date) {
+        let completed_issues = date_counts.get(&date).copied().unwrap_or(0);
+        let capacity = capacity_calendar.get_capacity(date);
+        if capacity <= 0.0 {
+            if calendar.is_some() {
+                carried_issues += completed_issues;
+            }
+            continue;
         }
 
-        Ok(throughput_data)
+        throughput_data.push(Throughput {
+            date,
+            completed_issues: carried_issues + completed_issues,
+            capacity: calendar.map(|_| capacity),
+        });
+        carried_issues = 0;
     }
+    if let Some(last) = throughput_data.last_mut() {
+        last.completed_issues += carried_issues;
+    }
+    throughput_data
 }
 
 #[cfg(test)]
@@ -54,8 +259,11 @@ mod tests {
     use chrono::NaiveDate;
 
     use super::*;
-    use crate::domain::issue::Issue;
+    use crate::domain::calendar::{Calendar, FreeDateRange};
+    use crate::domain::estimate::{Estimate, StoryPointEstimate};
+    use crate::domain::issue::{Issue, IssueId};
     use crate::services::data_source::{DataQuery, DataSourceError};
+    use chrono::Weekday;
 
     struct MockDataSource {
         issues: Vec<Issue>,
@@ -70,7 +278,7 @@ mod tests {
     #[test]
     fn construct_throughput_from_issue_vector() {
         // Arrange
-        let done_dates = vec![
+        let done_dates = [
             NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(), // Thursday
             NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
             NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(), // Monday
@@ -113,8 +321,481 @@ mod tests {
             .map(|(date, completed_issues)| Throughput {
                 date,
                 completed_issues,
+                capacity: None,
             })
             .collect::<Vec<_>>();
         assert_eq!(result, expected_throughput);
     }
+
+    fn empty_converter() -> DataConverter {
+        DataConverter::new(Box::new(MockDataSource { issues: vec![] }))
+    }
+
+    fn throughput(values: &[(u32, usize)]) -> Vec<Throughput> {
+        values
+            .iter()
+            .map(|&(day, completed_issues)| Throughput {
+                date: NaiveDate::from_ymd_opt(2026, 1, day).unwrap(),
+                completed_issues,
+                capacity: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn throughput_since_ignores_earlier_issues_and_zero_fills() {
+        let done_issues = [2, 6, 8, 8]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = NaiveDate::from_ymd_opt(2026, 1, day);
+                issue
+            })
+            .collect();
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }));
+
+        let result = converter
+            .get_throughput_data_since(
+                DataQuery::StringQuery(String::new()),
+                NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(result, throughput(&[(5, 0), (6, 1), (7, 0), (8, 2)]));
+    }
+
+    #[test]
+    fn arrival_data_counts_issues_by_created_date() {
+        let issues = [(5, Some(6)), (7, None), (7, Some(8))]
+            .iter()
+            .map(|&(created, done)| {
+                let mut issue = Issue::new();
+                issue.created_date = NaiveDate::from_ymd_opt(2026, 1, created);
+                issue.done_date = done.and_then(|day| NaiveDate::from_ymd_opt(2026, 1, day));
+                issue
+            })
+            .collect();
+        let converter = DataConverter::new(Box::new(MockDataSource { issues }));
+
+        let result = converter
+            .get_arrival_data(DataQuery::StringQuery(String::new()))
+            .unwrap();
+
+        assert_eq!(result, throughput(&[(5, 1), (6, 0), (7, 2)]));
+    }
+
+    #[test]
+    fn throughput_since_without_new_issues_keeps_the_start_day() {
+        let converter = DataConverter::new(Box::new(MockDataSource { issues: vec![] }));
+
+        let result = converter
+            .get_throughput_data_since(
+                DataQuery::StringQuery(String::new()),
+                NaiveDate::from_ymd_opt(2026, 1, 7).unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!(result, throughput(&[(7, 0)]));
+    }
+
+    #[test]
+    fn merge_throughput_replaces_refetched_days() {
+        let history = throughput(&[(5, 1), (6, 2), (7, 1)]);
+        let update = throughput(&[(7, 3), (8, 1)]);
+
+        let merged = empty_converter().merge_throughput(history, update);
+
+        assert_eq!(merged, throughput(&[(5, 1), (6, 2), (7, 3), (8, 1)]));
+    }
+
+    #[test]
+    fn merge_throughput_zero_fills_weekdays_between() {
+        let history = throughput(&[(1, 1), (2, 2)]);
+        let update = throughput(&[(7, 4)]);
+
+        let merged = empty_converter().merge_throughput(history, update);
+
+        assert_eq!(
+            merged,
+            throughput(&[(1, 1), (2, 2), (5, 0), (6, 0), (7, 4)])
+        );
+    }
+
+    #[test]
+    fn throughput_with_calendar_skips_free_days_and_records_capacity() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        let done_issues = [5, 6, 9]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = Some(date(day));
+                issue
+            })
+            .collect();
+        let calendar = TeamCalendar {
+            calendars: vec![
+                Calendar {
+                    free_weekdays: vec![Weekday::Sat, Weekday::Sun],
+                    free_date_ranges: vec![FreeDateRange {
+                        start_date: date(7),
+                        end_date: date(7),
+                    }],
+                },
+                Calendar {
+                    free_weekdays: vec![Weekday::Sat, Weekday::Sun],
+                    free_date_ranges: vec![FreeDateRange {
+                        start_date: date(6),
+                        end_date: date(7),
+                    }],
+                },
+            ],
+        };
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }))
+        .with_calendar(calendar);
+
+        let result = converter
+            .get_throughput_data(DataQuery::StringQuery(String::new()))
+            .unwrap();
+
+        let days: Vec<(NaiveDate, usize, Option<f32>)> = result
+            .iter()
+            .map(|throughput| {
+                (
+                    throughput.date,
+                    throughput.completed_issues,
+                    throughput.capacity,
+                )
+            })
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                (date(5), 1, Some(1.0)),
+                (date(6), 1, Some(0.5)),
+                // Wednesday the 7th is a holiday for everyone.
+                (date(8), 0, Some(1.0)),
+                (date(9), 1, Some(1.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn issues_done_on_free_days_count_towards_the_next_working_day() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        // Wednesday the 7th is a holiday, the 10th and 11th a weekend.
+        let done_issues = [6, 7, 10, 11, 12]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = Some(date(day));
+                issue
+            })
+            .collect();
+        let calendar = TeamCalendar {
+            calendars: vec![Calendar {
+                free_weekdays: vec![Weekday::Sat, Weekday::Sun],
+                free_date_ranges: vec![FreeDateRange {
+                    start_date: date(7),
+                    end_date: date(7),
+                }],
+            }],
+        };
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }))
+        .with_calendar(calendar);
+
+        let result = converter
+            .get_throughput_data(DataQuery::StringQuery(String::new()))
+            .unwrap();
+
+        let days: Vec<(NaiveDate, usize)> = result
+            .iter()
+            .map(|throughput| (throughput.date, throughput.completed_issues))
+            .collect();
+        assert_eq!(
+            days,
+            vec![(date(6), 1), (date(8), 1), (date(9), 0), (date(12), 3)]
+        );
+    }
+
+    #[test]
+    fn issues_done_on_free_days_at_the_end_count_towards_the_last_working_day() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        // The 10th and 11th are a weekend.
+        let done_issues = [8, 10, 11]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = Some(date(day));
+                issue
+            })
+            .collect();
+        let calendar = TeamCalendar {
+            calendars: vec![Calendar {
+                free_weekdays: vec![Weekday::Sat, Weekday::Sun],
+                free_date_ranges: vec![],
+            }],
+        };
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }))
+        .with_calendar(calendar);
+
+        let result = converter
+            .get_throughput_data(DataQuery::StringQuery(String::new()))
+            .unwrap();
+
+        let days: Vec<(NaiveDate, usize)> = result
+            .iter()
+            .map(|throughput| (throughput.date, throughput.completed_issues))
+            .collect();
+        assert_eq!(days, vec![(date(8), 1), (date(9), 2)]);
+    }
+
+    #[test]
+    fn issues_done_on_weekends_without_calendar_are_not_counted() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        let done_issues = [8, 10, 11, 12]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = Some(date(day));
+                issue
+            })
+            .collect();
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }));
+
+        let result = converter
+            .get_throughput_data(DataQuery::StringQuery(String::new()))
+            .unwrap();
+
+        assert_eq!(result, throughput(&[(8, 1), (9, 0), (12, 1)]));
+        assert_eq!(converter.refetch_start(date(12)), date(12));
+    }
+
+    #[test]
+    fn refetching_a_day_includes_the_free_days_before_it() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        let done_issues = [9, 10, 12]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = Some(date(day));
+                issue
+            })
+            .collect();
+        let calendar = TeamCalendar {
+            calendars: vec![Calendar {
+                free_weekdays: vec![Weekday::Sat, Weekday::Sun],
+                free_date_ranges: vec![],
+            }],
+        };
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }))
+        .with_calendar(calendar);
+
+        let since = converter.refetch_start(date(12));
+        let result = converter
+            .get_throughput_data_since(DataQuery::StringQuery(String::new()), since)
+            .unwrap();
+
+        assert_eq!(since, date(10));
+        assert_eq!(
+            result
+                .iter()
+                .map(|throughput| (throughput.date, throughput.completed_issues))
+                .collect::<Vec<_>>(),
+            vec![(date(12), 2)]
+        );
+        assert_eq!(
+            empty_converter()
+                .with_bucket(ThroughputBucket::Week)
+                .refetch_start(date(14)),
+            date(12)
+        );
+    }
+
+    #[test]
+    fn weekly_throughput_sums_working_days_and_their_capacity() {
+        let done_issues = [2, 5, 6, 6, 16]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = NaiveDate::from_ymd_opt(2026, 1, day);
+                issue
+            })
+            .collect();
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }))
+        .with_bucket(ThroughputBucket::Week);
+
+        let result = converter
+            .get_throughput_data(DataQuery::StringQuery(String::new()))
+            .unwrap();
+
+        let weeks: Vec<(NaiveDate, usize, Option<f32>)> = result
+            .iter()
+            .map(|week| (week.date, week.completed_issues, week.capacity))
+            .collect();
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert_eq!(
+            weeks,
+            vec![
+                // Only Friday the 2nd lies in the range of the first week.
+                (date(2025, 12, 29), 1, Some(1.0)),
+                (date(2026, 1, 5), 3, Some(5.0)),
+                (date(2026, 1, 12), 1, Some(5.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn weekly_throughput_counts_issues_done_on_weekends_and_holidays() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        // Tuesday the 6th is a holiday, the 10th and 11th a weekend.
+        let done_issues = [6, 9, 10, 11, 12]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = Some(date(day));
+                issue
+            })
+            .collect();
+        let calendar = TeamCalendar {
+            calendars: vec![Calendar {
+                free_weekdays: vec![Weekday::Sat, Weekday::Sun],
+                free_date_ranges: vec![FreeDateRange {
+                    start_date: date(6),
+                    end_date: date(6),
+                }],
+            }],
+        };
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }))
+        .with_calendar(calendar)
+        .with_bucket(ThroughputBucket::Week);
+
+        let result = converter
+            .get_throughput_data(DataQuery::StringQuery(String::new()))
+            .unwrap();
+
+        let weeks: Vec<(NaiveDate, usize, Option<f32>)> = result
+            .iter()
+            .map(|week| (week.date, week.completed_issues, week.capacity))
+            .collect();
+        assert_eq!(
+            weeks,
+            vec![(date(5), 4, Some(3.0)), (date(12), 1, Some(1.0))]
+        );
+    }
+
+    #[test]
+    fn sprint_updates_refetch_the_whole_last_sprint() {
+        let done_issues = [20, 22]
+            .iter()
+            .map(|&day| {
+                let mut issue = Issue::new();
+                issue.done_date = NaiveDate::from_ymd_opt(2026, 1, day);
+                issue
+            })
+            .collect();
+        let sprint = ThroughputBucket::Sprint {
+            start: NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+            length_days: 14,
+        };
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: done_issues,
+        }))
+        .with_bucket(sprint);
+
+        let update = converter
+            .get_throughput_data_since(
+                DataQuery::StringQuery(String::new()),
+                NaiveDate::from_ymd_opt(2026, 1, 21).unwrap(),
+            )
+            .unwrap();
+        let history = throughput(&[(5, 8), (19, 1)]);
+        let merged = converter.merge_throughput(history, update);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(
+            merged[1].date,
+            NaiveDate::from_ymd_opt(2026, 1, 19).unwrap()
+        );
+        assert_eq!(merged[1].completed_issues, 2);
+        assert_eq!(merged[1].capacity, Some(4.0));
+    }
+
+    #[test]
+    fn cycle_times_of_started_and_done_issues_ordered_by_done_date() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        let issue = |id: &str, start: Option<NaiveDate>, done: Option<NaiveDate>| {
+            let mut issue = Issue::new();
+            issue.issue_id = Some(IssueId { id: id.to_string() });
+            issue.start_date = start;
+            issue.done_date = done;
+            issue
+        };
+        let mut late = issue("LATE", Some(date(5)), Some(date(9)));
+        late.estimate = Some(Estimate::StoryPoint(StoryPointEstimate {
+            estimate: Some(3.0),
+        }));
+        late.labels = vec!["backend".to_string()];
+        let data_source = Box::new(MockDataSource {
+            issues: vec![
+                late,
+                issue("SAME-DAY", Some(date(6)), Some(date(6))),
+                issue("NOT-STARTED", None, Some(date(7))),
+                issue("OPEN", Some(date(7)), None),
+            ],
+        });
+
+        let converter = DataConverter::new(data_source);
+        let result = converter
+            .get_cycle_time_data(DataQuery::StringQuery("dummy string".to_string()))
+            .unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                CycleTime {
+                    issue_id: "SAME-DAY".to_string(),
+                    start_date: date(6),
+                    done_date: date(6),
+                    days: 1,
+                    story_points: None,
+                    labels: vec![],
+                },
+                CycleTime {
+                    issue_id: "LATE".to_string(),
+                    start_date: date(5),
+                    done_date: date(9),
+                    days: 5,
+                    story_points: Some(3.0),
+                    labels: vec!["backend".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn cycle_times_without_complete_issues_are_not_found() {
+        let converter = DataConverter::new(Box::new(MockDataSource {
+            issues: vec![Issue::new()],
+        }));
+
+        let result = converter.get_cycle_time_data(DataQuery::StringQuery(String::new()));
+
+        assert!(matches!(result, Err(DataSourceError::NotFound)));
+    }
 }
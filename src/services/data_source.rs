@@ -11,6 +11,17 @@ pub enum DataSourceError {
     Parse,
     #[error("unauthorized")]
     Unauthorized,
+    #[error("request to {url} failed: {message}")]
+    Request { url: String, message: String },
+    #[error("HTTP {status} from {url}: {body}")]
+    Http {
+        status: u16,
+        url: String,
+        /// Start of the response body, see `truncate_body`.
+        body: String,
+    },
+    #[error("no cached data for query '{query}' while offline")]
+    NotCached { query: String },
     #[error("{0}")]
     Other(String),
 }
@@ -0,0 +1,483 @@
+use std::env;
+use std::fs;
+
+use chrono::NaiveDate;
+use reqwest::StatusCode;
+use reqwest::blocking::Client;
+use reqwest::header::{ACCEPT, USER_AGENT};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::domain::estimate::{Estimate, StoryPointEstimate};
+use crate::domain::issue::Issue;
+use crate::domain::issue::IssueId;
+use crate::domain::issue_status::IssueStatus;
+use crate::services::data_source::{DataQuery, DataSource, DataSourceError};
+use crate::services::util::{next_page_link, parse_query_pairs, truncate_body};
+
+const DEFAULT_BASE_URL: &str = "https://api.github.com";
+const PAGE_SIZE: &str = "100";
+
+/// Configuration of a GitHub repository used as data source.
+///
+/// The queries are issue list filters in URL query syntax, e.g.
+/// `state=closed&labels=team-a`.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct GitHubProjectMetaData {
+    pub base_url: String,
+    pub owner: String,
+    pub repository: String,
+    pub throughput_query: String,
+    pub project_query: String,
+    /// Label carrying the story points, with `{}` in place of the number,
+    /// e.g. `sp: {}`.
+    pub story_points_label_pattern: String,
+    /// Label marking open issues as in progress. The first time it was
+    /// added is the start date of an issue.
+    pub in_progress_label: String,
+}
+
+impl GitHubProjectMetaData {
+    pub fn from_yaml_file(filepath: &str) -> Result<Self, DataSourceError> {
+        let contents = fs::read_to_string(filepath)
+            .map_err(|err| DataSourceError::Other(format!("failed to read config: {err}")))?;
+        let metadata: GitHubProjectMetaData =
+            serde_yaml::from_str(&contents).map_err(|_| DataSourceError::Parse)?;
+        Ok(metadata)
+    }
+
+    /// The throughput query restricted to items updated on or after `date`,
+    /// which includes all items closed since then.
+    pub fn throughput_query_since(&self, date: NaiveDate) -> String {
+        let separator = if self.throughput_query.is_empty() {
+            ""
+        } else {
+            "&"
+        };
+        format!("{}{separator}since={date}T00:00:00Z", self.throughput_query)
+    }
+}
+
+pub struct GitHubConfigParser;
+
+impl GitHubConfigParser {
+    pub fn parse(&self, filepath: &str) -> Result<GitHubProjectMetaData, DataSourceError> {
+        GitHubProjectMetaData::from_yaml_file(filepath)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GitHubAuthData {
+    pub token: String,
+}
+
+impl GitHubAuthData {
+    pub fn from_env() -> Result<Self, DataSourceError> {
+        env::var("GITHUB_TOKEN")
+            .map(|token| Self { token })
+            .map_err(|_| DataSourceError::Unauthorized)
+    }
+}
+
+pub struct GitHubApiClient {
+    github_project: GitHubProjectMetaData,
+    auth: GitHubAuthData,
+    client: Client,
+    /// Whether start dates are read from the issue events, see `get_start_date`.
+    start_dates: bool,
+}
+
+impl GitHubApiClient {
+    pub fn new(
+        mut github_project: GitHubProjectMetaData,
+        auth: GitHubAuthData,
+    ) -> Result<Self, DataSourceError> {
+        if github_project.owner.is_empty() || github_project.repository.is_empty() {
+            return Err(DataSourceError::Other(
+                "github project metadata is missing owner or repository".to_string(),
+            ));
+        }
+        if github_project.base_url.is_empty() {
+            github_project.base_url = DEFAULT_BASE_URL.to_string();
+        }
+
+        Ok(Self {
+            github_project,
+            auth,
+            client: Client::new(),
+            start_dates: false,
+        })
+    }
+
+    /// Reads the events of every started issue for its start date, which
+    /// takes one request per issue and is not needed for throughput.
+    pub fn with_start_dates(mut self, start_dates: bool) -> Self {
+        self.start_dates = start_dates;
+        self
+    }
+
+    fn repository_url(&self) -> String {
+        format!(
+            "{}/repos/{}/{}",
+            self.github_project.base_url, self.github_project.owner, self.github_project.repository
+        )
+    }
+
+    /// Fetches one page and returns it together with the URL of the next
+    /// page, taken from the `Link` response header.
+    fn fetch_page(
+        &self,
+        url: &str,
+        params: &[(String, String)],
+    ) -> Result<(Value, Option<String>), DataSourceError> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .bearer_auth(&self.auth.token)
+            .header(ACCEPT, "application/vnd.github+json")
+            .header(USER_AGENT, env!("CARGO_PKG_NAME"))
+            .send()
+            .map_err(|err| DataSourceError::Request {
+                url: url.to_string(),
+                message: err.to_string(),
+            })?;
+
+        let status = response.status();
+        if status == StatusCode::UNAUTHORIZED {
+            return Err(DataSourceError::Unauthorized);
+        }
+        if status == StatusCode::NOT_FOUND {
+            return Err(DataSourceError::NotFound);
+        }
+        if !status.is_success() {
+            let url = response.url().to_string();
+            let body = response.text().unwrap_or_default();
+            return Err(DataSourceError::Http {
+                status: status.as_u16(),
+                url,
+                body: truncate_body(&body),
+            });
+        }
+
+        let next_page = next_page_link(response.headers());
+        let payload = response
+            .json::<Value>()
+            .map_err(|_| DataSourceError::Parse)?;
+        Ok((payload, next_page))
+    }
+
+    fn fetch_all(
+        &self,
+        url: &str,
+        mut params: Vec<(String, String)>,
+    ) -> Result<Vec<Value>, DataSourceError> {
+        params.push(("per_page".to_string(), PAGE_SIZE.to_string()));
+        let mut url = url.to_string();
+        let mut items = Vec::new();
+        loop {
+            let (payload, next_page) = self.fetch_page(&url, &params)?;
+            let page = payload.as_array().ok_or(DataSourceError::Parse)?;
+            items.extend(page.iter().cloned());
+
+            match next_page {
+                Some(next_url) if next_url != url => {
+                    // The next page URL already contains all query parameters.
+                    url = next_url;
+                    params.clear();
+                }
+                _ => break,
+            }
+        }
+        Ok(items)
+    }
+
+    fn get_issues_by_filter(&self, filter: &str) -> Result<Vec<Issue>, DataSourceError> {
+        let url = format!("{}/issues", self.repository_url());
+        let issues = self.fetch_all(&url, parse_query_pairs(filter))?;
+
+        let mut mapped = Vec::new();
+        for issue in &issues {
+            // The issues endpoint lists pull requests as well.
+            if issue.get("pull_request").is_some() {
+                continue;
+            }
+            if let Some(issue_obj) = issue.as_object() {
+                mapped.push(self.map_issue(issue_obj)?);
+            }
+        }
+        Ok(mapped)
+    }
+
+    /// Date the in-progress label was first added to an issue.
+    fn get_start_date(&self, number: u64) -> Result<Option<NaiveDate>, DataSourceError> {
+        let url = format!("{}/issues/{number}/events", self.repository_url());
+        let events = self.fetch_all(&url, Vec::new())?;
+        Ok(first_labeled_date(
+            &events,
+            &self.github_project.in_progress_label,
+        ))
+    }
+
+    fn map_issue(&self, issue: &serde_json::Map<String, Value>) -> Result<Issue, DataSourceError> {
+        let number = issue
+            .get("number")
+            .and_then(|value| value.as_u64())
+            .ok_or(DataSourceError::Parse)?;
+        let labels = get_label_names(issue);
+
+        let mut mapped = Issue::new();
+        mapped.issue_id = Some(IssueId {
+            id: format!("#{number}"),
+        });
+        mapped.summary = get_string(issue, "title");
+        mapped.description = get_string(issue, "body");
+        mapped.status = map_status(
+            get_string(issue, "state").as_deref(),
+            labels
+                .iter()
+                .any(|label| label == &self.github_project.in_progress_label),
+        );
+        mapped.created_date = parse_date_opt(get_string(issue, "created_at").as_deref());
+        mapped.done_date = parse_date_opt(get_string(issue, "closed_at").as_deref());
+        // Issues that were never labeled have no events worth reading.
+        let started = mapped
+            .status
+            .as_ref()
+            .is_some_and(|status| *status != IssueStatus::ToDo);
+        if self.start_dates && started && !self.github_project.in_progress_label.is_empty() {
+            mapped.start_date = self.get_start_date(number)?;
+        }
+        mapped.estimate = labels
+            .iter()
+            .find_map(|label| {
+                parse_story_points(label, &self.github_project.story_points_label_pattern)
+            })
+            .map(|value| {
+                Estimate::StoryPoint(StoryPointEstimate {
+                    estimate: Some(value),
+                })
+            });
+        mapped.labels = labels;
+
+        let blockers = mapped
+            .description
+            .as_deref()
+            .map(parse_blocked_by)
+            .unwrap_or_default();
+        if !blockers.is_empty() {
+            mapped.dependencies = Some(blockers);
+        }
+        Ok(mapped)
+    }
+}
+
+impl DataSource for GitHubApiClient {
+    fn get_issues(&self, query: DataQuery) -> Result<Vec<Issue>, DataSourceError> {
+        match query {
+            DataQuery::StringQuery(filter) => self.get_issues_by_filter(&filter),
+        }
+    }
+}
+
+fn get_string(fields: &serde_json::Map<String, Value>, key: &str) -> Option<String> {
+    fields
+        .get(key)
+        .and_then(|value| value.as_str())
+        .map(|text| text.to_string())
+}
+
+fn get_label_names(issue: &serde_json::Map<String, Value>) -> Vec<String> {
+    issue
+        .get("labels")
+        .and_then(|value| value.as_array())
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| match label {
+                    Value::String(name) => Some(name.clone()),
+                    Value::Object(obj) => obj
+                        .get("name")
+                        .and_then(|name| name.as_str())
+                        .map(|name| name.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn map_status(state: Option<&str>, has_in_progress_label: bool) -> Option<IssueStatus> {
+    match state {
+        Some("closed") => Some(IssueStatus::Done),
+        Some("open") if has_in_progress_label => Some(IssueStatus::InProgress),
+        Some("open") => Some(IssueStatus::ToDo),
+        _ => None,
+    }
+}
+
+/// Date of the first `labeled` event that added `label`.
+fn first_labeled_date(events: &[Value], label: &str) -> Option<NaiveDate> {
+    events
+        .iter()
+        .filter(|event| event.get("event").and_then(|value| value.as_str()) == Some("labeled"))
+        .filter(|event| {
+            event
+                .get("label")
+                .and_then(|value| value.get("name"))
+                .and_then(|value| value.as_str())
+                == Some(label)
+        })
+        .filter_map(|event| {
+            parse_date_opt(event.get("created_at").and_then(|value| value.as_str()))
+        })
+        .min()
+}
+
+/// Reads the number out of a label like `sp: 5` for the pattern `sp: {}`.
+fn parse_story_points(label: &str, pattern: &str) -> Option<f32> {
+    let (prefix, suffix) = pattern.split_once("{}")?;
+    label
+        .strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .trim()
+        .parse::<f32>()
+        .ok()
+}
+
+/// Collects the issues referenced as "blocked by #12" or
+/// "Blocked by #12, #13 and #14" in an issue body.
+fn parse_blocked_by(body: &str) -> Vec<IssueId> {
+    const MARKER: &str = "blocked by";
+
+    let lowercase = body.to_ascii_lowercase();
+    let mut blockers: Vec<IssueId> = Vec::new();
+    for (position, _) in lowercase.match_indices(MARKER) {
+        let mut rest = lowercase[position + MARKER.len()..].trim_start_matches(':');
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+            if let Some(after_and) = rest.strip_prefix("and ") {
+                rest = after_and;
+                continue;
+            }
+            let Some(reference) = rest.strip_prefix('#') else {
+                break;
+            };
+            let digits: String = reference
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            if digits.is_empty() {
+                break;
+            }
+            let id = format!("#{digits}");
+            if !blockers.iter().any(|blocker| blocker.id == id) {
+                blockers.push(IssueId { id });
+            }
+            rest = &reference[digits.len()..];
+        }
+    }
+    blockers
+}
+
+fn parse_date_opt(value: Option<&str>) -> Option<NaiveDate> {
+    let text = value?;
+    let date = text
+        .split_once('T')
+        .map_or(text, |(date_part, _)| date_part);
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(blockers: Vec<IssueId>) -> Vec<String> {
+        blockers.into_iter().map(|blocker| blocker.id).collect()
+    }
+
+    #[test]
+    fn parse_story_points_uses_label_pattern() {
+        assert_eq!(parse_story_points("sp: 5", "sp: {}"), Some(5.0));
+        assert_eq!(parse_story_points("2.5 points", "{} points"), Some(2.5));
+        assert_eq!(parse_story_points("bug", "sp: {}"), None);
+        assert_eq!(parse_story_points("sp: 5", ""), None);
+    }
+
+    #[test]
+    fn parse_blocked_by_collects_all_references() {
+        let body = "Blocked by #12.\nAlso blocked by: #3, #4 and #5\nSee #99";
+
+        assert_eq!(ids(parse_blocked_by(body)), vec!["#12", "#3", "#4", "#5"]);
+    }
+
+    #[test]
+    fn parse_blocked_by_ignores_duplicates_and_plain_text() {
+        assert_eq!(
+            ids(parse_blocked_by("blocked by #7, blocked by #7")),
+            vec!["#7"]
+        );
+        assert!(parse_blocked_by("blocked by the review").is_empty());
+    }
+
+    #[test]
+    fn map_status_uses_state_and_in_progress_label() {
+        assert_eq!(map_status(Some("closed"), true), Some(IssueStatus::Done));
+        assert_eq!(
+            map_status(Some("open"), true),
+            Some(IssueStatus::InProgress)
+        );
+        assert_eq!(map_status(Some("open"), false), Some(IssueStatus::ToDo));
+        assert_eq!(map_status(None, false), None);
+    }
+
+    #[test]
+    fn first_labeled_date_uses_the_first_time_the_label_was_added() {
+        let events = serde_json::json!([
+            { "event": "labeled", "label": { "name": "bug" }, "created_at": "2026-01-02T08:00:00Z" },
+            { "event": "labeled", "label": { "name": "doing" }, "created_at": "2026-01-05T08:00:00Z" },
+            { "event": "unlabeled", "label": { "name": "doing" }, "created_at": "2026-01-06T08:00:00Z" },
+            { "event": "labeled", "label": { "name": "doing" }, "created_at": "2026-01-08T08:00:00Z" }
+        ]);
+        let events = events.as_array().unwrap();
+
+        assert_eq!(
+            first_labeled_date(events, "doing"),
+            NaiveDate::from_ymd_opt(2026, 1, 5)
+        );
+        assert_eq!(first_labeled_date(events, "review"), None);
+    }
+
+    #[test]
+    fn throughput_query_since_appends_since_parameter() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 3).unwrap();
+        let mut metadata = GitHubProjectMetaData {
+            throughput_query: "state=closed".to_string(),
+            ..GitHubProjectMetaData::default()
+        };
+
+        assert_eq!(
+            metadata.throughput_query_since(date),
+            "state=closed&since=2026-02-03T00:00:00Z"
+        );
+        metadata.throughput_query.clear();
+        assert_eq!(
+            metadata.throughput_query_since(date),
+            "since=2026-02-03T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn new_rejects_missing_repository() {
+        let metadata = GitHubProjectMetaData {
+            owner: "octo".to_string(),
+            ..GitHubProjectMetaData::default()
+        };
+        let auth = GitHubAuthData {
+            token: "token".to_string(),
+        };
+
+        assert!(GitHubApiClient::new(metadata, auth).is_err());
+    }
+}
@@ -0,0 +1,571 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+
+use chrono::NaiveDate;
+use reqwest::StatusCode;
+use reqwest::blocking::Client;
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::domain::estimate::{Estimate, StoryPointEstimate};
+use crate::domain::issue::Issue;
+use crate::domain::issue::IssueId;
+use crate::domain::issue_status::IssueStatus;
+use crate::services::data_source::{DataQuery, DataSource, DataSourceError};
+use crate::services::util::{next_page_link, parse_query_pairs};
+
+const DEFAULT_BASE_URL: &str = "https://gitlab.com/api/v4";
+const PAGE_SIZE: &str = "100";
+
+/// Configuration of a GitLab project used as data source.
+///
+/// Queries are list filters in URL query syntax. They read issues unless
+/// they start with `merge_requests?`, e.g.
+/// `merge_requests?state=merged&target_branch=main` for throughput.
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(default)]
+pub struct GitLabProjectMetaData {
+    pub base_url: String,
+    /// Numeric project id or full path like `group/project`.
+    pub project: String,
+    pub throughput_query: String,
+    pub project_query: String,
+    /// Labels starting with this prefix name the subgraph, e.g. `epic::`.
+    /// Without a matching label the milestone title is used.
+    pub subgraph_label_prefix: String,
+    /// Label marking open issues as in progress.
+    pub in_progress_label: String,
+}
+
+impl GitLabProjectMetaData {
+    pub fn from_yaml_file(filepath: &str) -> Result<Self, DataSourceError> {
+        let contents = fs::read_to_string(filepath)
+            .map_err(|err| DataSourceError::Other(format!("failed to read config: {err}")))?;
+        let metadata: GitLabProjectMetaData =
+            serde_yaml::from_str(&contents).map_err(|_| DataSourceError::Parse)?;
+        Ok(metadata)
+    }
+
+    /// The throughput query restricted to items updated on or after `date`,
+    /// which includes all items closed since then.
+    pub fn throughput_query_since(&self, date: NaiveDate) -> String {
+        let separator = if self.throughput_query.is_empty() {
+            ""
+        } else {
+            "&"
+        };
+        format!(
+            "{}{separator}updated_after={date}T00:00:00Z",
+            self.throughput_query
+        )
+    }
+}
+
+pub struct GitLabConfigParser;
+
+impl GitLabConfigParser {
+    pub fn parse(&self, filepath: &str) -> Result<GitLabProjectMetaData, DataSourceError> {
+        GitLabProjectMetaData::from_yaml_file(filepath)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GitLabAuthData {
+    pub token: String,
+}
+
+impl GitLabAuthData {
+    pub fn from_env() -> Result<Self, DataSourceError> {
+        env::var("GITLAB_TOKEN")
+            .map(|token| Self { token })
+            .map_err(|_| DataSourceError::Unauthorized)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Resource {
+    Issues,
+    MergeRequests,
+}
+
+pub struct GitLabApiClient {
+    gitlab_project: GitLabProjectMetaData,
+    auth: GitLabAuthData,
+    client: Client,
+    /// Whether issue links are read as dependencies, see `add_link_dependencies`.
+    issue_links: bool,
+}
+
+impl GitLabApiClient {
+    pub fn new(
+        mut gitlab_project: GitLabProjectMetaData,
+        auth: GitLabAuthData,
+    ) -> Result<Self, DataSourceError> {
+        if gitlab_project.project.is_empty() {
+            return Err(DataSourceError::Other(
+                "gitlab project metadata is missing project".to_string(),
+            ));
+        }
+        if gitlab_project.base_url.is_empty() {
+            gitlab_project.base_url = DEFAULT_BASE_URL.to_string();
+        }
+
+        Ok(Self {
+            gitlab_project,
+            auth,
+            client: Client::new(),
+            issue_links: false,
+        })
+    }
+
+    /// Reads the links of every fetched issue, which takes one request per
+    /// issue and is only worth it for project data.
+    pub fn with_issue_links(mut self, issue_links: bool) -> Self {
+        self.issue_links = issue_links;
+        self
+    }
+
+    fn project_url(&self) -> String {
+        format!(
+            "{}/projects/{}",
+            self.gitlab_project.base_url,
+            self.gitlab_project.project.replace('/', "%2F")
+        )
+    }
+
+    /// Fetches one page and returns it together with the URL of the next
+    /// page. Keyset pagination announces that page in the `Link` header,
+    /// offset pagination in `x-next-page`.
+    fn fetch_page(
+        &self,
+        url: &str,
+        params: &[(String, String)],
+    ) -> Result<(Value, Option<String>), DataSourceError> {
+        let response = self
+            .client
+            .get(url)
+            .query(params)
+            .header("PRIVATE-TOKEN", &self.auth.token)
+            .send()
+            .map_err(|_| DataSourceError::Connection)?;
+
+        let status = response.status();
+        if status == StatusCode::UNAUTHORIZED {
+            return Err(DataSourceError::Unauthorized);
+        }
+        if status == StatusCode::NOT_FOUND {
+            return Err(DataSourceError::NotFound);
+        }
+        if !status.is_success() {
+            return Err(DataSourceError::Connection);
+        }
+
+        let next_page = next_page_link(response.headers())
+            .or_else(|| next_offset_page(response.headers(), url, params));
+        let payload = response
+            .json::<Value>()
+            .map_err(|_| DataSourceError::Parse)?;
+        Ok((payload, next_page))
+    }
+
+    fn fetch_all(
+        &self,
+        url: &str,
+        mut params: Vec<(String, String)>,
+    ) -> Result<Vec<Value>, DataSourceError> {
+        let mut url = url.to_string();
+        let mut items = Vec::new();
+        loop {
+            let (payload, next_page) = self.fetch_page(&url, &params)?;
+            let page = payload.as_array().ok_or(DataSourceError::Parse)?;
+            items.extend(page.iter().cloned());
+
+            match next_page {
+                Some(next_url) if next_url != url => {
+                    // The next page URL already contains all query parameters.
+                    url = next_url;
+                    params.clear();
+                }
+                _ => break,
+            }
+        }
+        Ok(items)
+    }
+
+    fn get_issues_by_query(&self, query: &str) -> Result<Vec<Issue>, DataSourceError> {
+        let (resource, filter) = parse_resource_query(query);
+        let mut params = parse_query_pairs(filter);
+        params.extend([
+            ("pagination".to_string(), "keyset".to_string()),
+            ("order_by".to_string(), "id".to_string()),
+            ("sort".to_string(), "asc".to_string()),
+            ("per_page".to_string(), PAGE_SIZE.to_string()),
+        ]);
+
+        let path = match resource {
+            Resource::Issues => "issues",
+            Resource::MergeRequests => "merge_requests",
+        };
+        let url = format!("{}/{}", self.project_url(), path);
+        let items = self.fetch_all(&url, params)?;
+
+        let mut mapped = Vec::with_capacity(items.len());
+        for item in &items {
+            let item_obj = item.as_object().ok_or(DataSourceError::Parse)?;
+            mapped.push(match resource {
+                Resource::Issues => self.map_issue(item_obj)?,
+                Resource::MergeRequests => self.map_merge_request(item_obj)?,
+            });
+        }
+
+        if self.issue_links && resource == Resource::Issues {
+            self.add_link_dependencies(&mut mapped)?;
+        }
+        Ok(mapped)
+    }
+
+    fn map_issue(&self, issue: &serde_json::Map<String, Value>) -> Result<Issue, DataSourceError> {
+        let iid = get_iid(issue)?;
+        let labels = get_labels(issue);
+
+        let mut mapped = Issue::new();
+        mapped.issue_id = Some(IssueId {
+            id: format!("#{iid}"),
+        });
+        mapped.summary = get_string(issue, "title");
+        mapped.description = get_string(issue, "description");
+        mapped.status = map_issue_status(
+            get_string(issue, "state").as_deref(),
+            labels
+                .iter()
+                .any(|label| label == &self.gitlab_project.in_progress_label),
+        );
+        mapped.created_date = parse_date_opt(get_string(issue, "created_at").as_deref());
+        mapped.done_date = parse_date_opt(get_string(issue, "closed_at").as_deref());
+        mapped.estimate = issue
+            .get("weight")
+            .and_then(|value| value.as_f64())
+            .map(|weight| {
+                Estimate::StoryPoint(StoryPointEstimate {
+                    estimate: Some(weight as f32),
+                })
+            });
+        mapped.subgraph = self.subgraph(issue, &labels);
+        mapped.labels = labels;
+        Ok(mapped)
+    }
+
+    fn map_merge_request(
+        &self,
+        merge_request: &serde_json::Map<String, Value>,
+    ) -> Result<Issue, DataSourceError> {
+        let iid = get_iid(merge_request)?;
+        let labels = get_labels(merge_request);
+
+        let mut mapped = Issue::new();
+        mapped.issue_id = Some(IssueId {
+            id: format!("!{iid}"),
+        });
+        mapped.summary = get_string(merge_request, "title");
+        mapped.description = get_string(merge_request, "description");
+        mapped.status = match get_string(merge_request, "state").as_deref() {
+            Some("merged") => Some(IssueStatus::Done),
+            Some("opened") => Some(IssueStatus::InProgress),
+            _ => None,
+        };
+        mapped.created_date = parse_date_opt(get_string(merge_request, "created_at").as_deref());
+        mapped.done_date = parse_date_opt(get_string(merge_request, "merged_at").as_deref());
+        mapped.subgraph = self.subgraph(merge_request, &labels);
+        mapped.labels = labels;
+        Ok(mapped)
+    }
+
+    fn subgraph(&self, item: &serde_json::Map<String, Value>, labels: &[String]) -> Option<String> {
+        let prefix = &self.gitlab_project.subgraph_label_prefix;
+        let from_label = if prefix.is_empty() {
+            None
+        } else {
+            labels
+                .iter()
+                .find_map(|label| label.strip_prefix(prefix.as_str()))
+                .map(|name| name.to_string())
+        };
+        from_label.or_else(|| {
+            item.get("milestone")
+                .and_then(|milestone| milestone.get("title"))
+                .and_then(|title| title.as_str())
+                .map(|title| title.to_string())
+        })
+    }
+
+    /// Reads the issue links of every issue. "is blocked by" links become
+    /// dependencies of the issue itself, "blocks" links dependencies of the
+    /// linked issue, if that issue is part of the result.
+    fn add_link_dependencies(&self, issues: &mut [Issue]) -> Result<(), DataSourceError> {
+        let mut blockers: HashMap<String, Vec<String>> = HashMap::new();
+        for issue in issues.iter() {
+            let Some(id) = issue.issue_id.as_ref().map(|issue_id| issue_id.id.clone()) else {
+                continue;
+            };
+            let url = format!(
+                "{}/issues/{}/links",
+                self.project_url(),
+                id.trim_start_matches('#')
+            );
+            for link in self.fetch_all(&url, Vec::new())? {
+                let Some(linked_id) = linked_issue_reference(&link) else {
+                    continue;
+                };
+                match link.get("link_type").and_then(|value| value.as_str()) {
+                    Some("is_blocked_by") => add_blocker(&mut blockers, &id, linked_id),
+                    Some("blocks") => add_blocker(&mut blockers, &linked_id, id.clone()),
+                    _ => {}
+                }
+            }
+        }
+
+        for issue in issues.iter_mut() {
+            let Some(id) = issue.issue_id.as_ref().map(|issue_id| issue_id.id.clone()) else {
+                continue;
+            };
+            if let Some(ids) = blockers.remove(&id) {
+                let dependencies = issue.dependencies.get_or_insert_with(Vec::new);
+                dependencies.extend(ids.into_iter().map(|id| IssueId { id }));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl DataSource for GitLabApiClient {
+    fn get_issues(&self, query: DataQuery) -> Result<Vec<Issue>, DataSourceError> {
+        match query {
+            DataQuery::StringQuery(query) => self.get_issues_by_query(&query),
+        }
+    }
+}
+
+fn parse_resource_query(query: &str) -> (Resource, &str) {
+    match query.split_once('?') {
+        Some(("merge_requests", filter)) => (Resource::MergeRequests, filter),
+        Some(("issues", filter)) => (Resource::Issues, filter),
+        _ => (Resource::Issues, query),
+    }
+}
+
+/// Builds the URL of the next page for offset pagination, which GitLab
+/// falls back to for endpoints without keyset support.
+fn next_offset_page(headers: &HeaderMap, url: &str, params: &[(String, String)]) -> Option<String> {
+    let next_page = headers.get("x-next-page")?.to_str().ok()?.trim();
+    if next_page.is_empty() {
+        return None;
+    }
+
+    // Later pages are requested by URL alone, so keep the query it carries.
+    let (base, url_query) = url.split_once('?').unwrap_or((url, ""));
+    let mut query: Vec<String> = parse_query_pairs(url_query)
+        .into_iter()
+        .chain(params.iter().cloned())
+        .filter(|(key, _)| key != "page")
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect();
+    query.push(format!("page={next_page}"));
+    Some(format!("{base}?{}", query.join("&")))
+}
+
+fn add_blocker(blockers: &mut HashMap<String, Vec<String>>, issue_id: &str, blocker_id: String) {
+    let entry = blockers.entry(issue_id.to_string()).or_default();
+    if !entry.contains(&blocker_id) {
+        entry.push(blocker_id);
+    }
+}
+
+fn linked_issue_reference(link: &Value) -> Option<String> {
+    link.get("references")
+        .and_then(|references| references.get("relative"))
+        .and_then(|value| value.as_str())
+        .map(|reference| reference.to_string())
+        .or_else(|| {
+            link.get("iid")
+                .and_then(|value| value.as_u64())
+                .map(|iid| format!("#{iid}"))
+        })
+}
+
+fn get_iid(item: &serde_json::Map<String, Value>) -> Result<u64, DataSourceError> {
+    item.get("iid")
+        .and_then(|value| value.as_u64())
+        .ok_or(DataSourceError::Parse)
+}
+
+fn get_string(fields: &serde_json::Map<String, Value>, key: &str) -> Option<String> {
+    fields
+        .get(key)
+        .and_then(|value| value.as_str())
+        .map(|text| text.to_string())
+}
+
+fn get_labels(item: &serde_json::Map<String, Value>) -> Vec<String> {
+    item.get("labels")
+        .and_then(|value| value.as_array())
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| label.as_str())
+                .map(|label| label.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn map_issue_status(state: Option<&str>, has_in_progress_label: bool) -> Option<IssueStatus> {
+    match state {
+        Some("closed") => Some(IssueStatus::Done),
+        Some("opened") if has_in_progress_label => Some(IssueStatus::InProgress),
+        Some("opened") => Some(IssueStatus::ToDo),
+        _ => None,
+    }
+}
+
+fn parse_date_opt(value: Option<&str>) -> Option<NaiveDate> {
+    let text = value?;
+    let date = text
+        .split_once('T')
+        .map_or(text, |(date_part, _)| date_part);
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn client(subgraph_label_prefix: &str) -> GitLabApiClient {
+        GitLabApiClient::new(
+            GitLabProjectMetaData {
+                project: "group/demo".to_string(),
+                subgraph_label_prefix: subgraph_label_prefix.to_string(),
+                in_progress_label: "doing".to_string(),
+                ..GitLabProjectMetaData::default()
+            },
+            GitLabAuthData {
+                token: "token".to_string(),
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_resource_query_defaults_to_issues() {
+        assert_eq!(
+            parse_resource_query("state=closed"),
+            (Resource::Issues, "state=closed")
+        );
+        assert_eq!(
+            parse_resource_query("merge_requests?state=merged"),
+            (Resource::MergeRequests, "state=merged")
+        );
+        assert_eq!(
+            parse_resource_query("issues?labels=a"),
+            (Resource::Issues, "labels=a")
+        );
+    }
+
+    #[test]
+    fn project_url_encodes_project_path() {
+        assert_eq!(
+            client("").project_url(),
+            "https://gitlab.com/api/v4/projects/group%2Fdemo"
+        );
+    }
+
+    #[test]
+    fn map_issue_reads_weight_state_and_subgraph_label() {
+        let issue = json!({
+            "iid": 4,
+            "title": "Build",
+            "state": "opened",
+            "weight": 3,
+            "created_at": "2026-01-05T10:00:00.000Z",
+            "labels": ["doing", "epic::Checkout"],
+            "milestone": { "title": "Release 1" }
+        });
+
+        let mapped = client("epic::")
+            .map_issue(issue.as_object().unwrap())
+            .unwrap();
+
+        assert_eq!(mapped.issue_id.as_ref().unwrap().id, "#4");
+        assert_eq!(mapped.status, Some(IssueStatus::InProgress));
+        assert_eq!(mapped.story_point_value(), Some(3.0));
+        assert_eq!(mapped.subgraph.as_deref(), Some("Checkout"));
+        assert_eq!(mapped.created_date, NaiveDate::from_ymd_opt(2026, 1, 5));
+    }
+
+    #[test]
+    fn subgraph_falls_back_to_milestone() {
+        let issue = json!({
+            "iid": 5,
+            "state": "closed",
+            "closed_at": "2026-01-09T10:00:00.000Z",
+            "labels": ["bug"],
+            "milestone": { "title": "Release 1" }
+        });
+
+        let mapped = client("epic::")
+            .map_issue(issue.as_object().unwrap())
+            .unwrap();
+
+        assert_eq!(mapped.subgraph.as_deref(), Some("Release 1"));
+        assert_eq!(mapped.status, Some(IssueStatus::Done));
+        assert_eq!(mapped.done_date, NaiveDate::from_ymd_opt(2026, 1, 9));
+    }
+
+    #[test]
+    fn map_merge_request_uses_merged_date() {
+        let merge_request = json!({
+            "iid": 12,
+            "title": "Add login",
+            "state": "merged",
+            "merged_at": "2026-01-20T08:00:00.000Z",
+            "labels": []
+        });
+
+        let mapped = client("")
+            .map_merge_request(merge_request.as_object().unwrap())
+            .unwrap();
+
+        assert_eq!(mapped.issue_id.unwrap().id, "!12");
+        assert_eq!(mapped.status, Some(IssueStatus::Done));
+        assert_eq!(mapped.done_date, NaiveDate::from_ymd_opt(2026, 1, 20));
+    }
+
+    #[test]
+    fn next_offset_page_replaces_page_parameter() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-next-page", "3".parse().unwrap());
+        let params = vec![
+            ("state".to_string(), "closed".to_string()),
+            ("page".to_string(), "2".to_string()),
+        ];
+
+        assert_eq!(
+            next_offset_page(&headers, "http://host/issues", &params),
+            Some("http://host/issues?state=closed&page=3".to_string())
+        );
+
+        headers.insert("x-next-page", "4".parse().unwrap());
+        assert_eq!(
+            next_offset_page(&headers, "http://host/issues?state=closed&page=3", &[]),
+            Some("http://host/issues?state=closed&page=4".to_string())
+        );
+
+        headers.insert("x-next-page", "".parse().unwrap());
+        assert_eq!(
+            next_offset_page(&headers, "http://host/issues", &params),
+            None
+        );
+    }
+}
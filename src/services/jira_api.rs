@@ -1,18 +1,46 @@
 use std::collections::HashMap;
 use std::env;
 use std::fs;
+use std::thread;
+use std::time::Duration;
 
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate};
 use reqwest::StatusCode;
-use reqwest::blocking::Client;
+use reqwest::blocking::{Client, Response};
+use reqwest::header::HeaderMap;
 use serde::Deserialize;
 use serde_json::Value;
 
 use crate::domain::estimate::{Estimate, StoryPointEstimate};
 use crate::domain::issue::Issue;
 use crate::domain::issue::IssueId;
+use crate::domain::issue::StatusTransition;
 use crate::domain::issue_status::IssueStatus;
 use crate::services::data_source::{DataQuery, DataSource, DataSourceError};
+use crate::services::util::{retry_delay, truncate_body};
+
+/// How requests authenticate against Jira.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JiraAuthMode {
+    /// Username and API token (Jira Cloud).
+    #[default]
+    Basic,
+    /// Personal access token sent as bearer token (Jira Server / Data Center).
+    Bearer,
+}
+
+/// REST API variant the Jira instance offers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JiraApiFlavor {
+    /// `/search/jql` with `nextPageToken` paging.
+    #[default]
+    Cloud,
+    /// Version 2 `/search` with `startAt` paging.
+    #[serde(alias = "data_center")]
+    Server,
+}
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
@@ -25,6 +53,26 @@ pub struct JiraProjectMetaData {
     pub start_date_field_id: String,
     pub actual_start_date_field_id: String,
     pub actual_end_date_field_id: String,
+    /// Names of the issue link types that express a dependency. The issue on
+    /// the inward side of such a link ("is blocked by") becomes a dependency.
+    /// Without link types, issues depend on their predecessor instead.
+    pub dependency_link_types: Vec<String>,
+    /// Expand the changelog to read the status history. Start and done dates
+    /// missing in the date fields are then taken from the status transitions.
+    pub use_changelog: bool,
+    /// Issue type of the parents that become subgraphs.
+    pub epic_issue_type: String,
+    /// Legacy "Epic Link" field, used instead of the parent if it is set.
+    pub epic_link_field_id: String,
+    pub auth_mode: JiraAuthMode,
+    pub api_flavor: JiraApiFlavor,
+    /// Retries of a request that was rate limited (429) or failed with a
+    /// server error (5xx), a timeout or a connection error.
+    pub max_retries: u32,
+    /// Wait before the first retry, doubled for every further retry. A
+    /// `Retry-After` header sent by the server takes precedence.
+    pub retry_backoff_ms: u64,
+    pub timeout_secs: u64,
 }
 
 impl Default for JiraProjectMetaData {
@@ -38,6 +86,15 @@ impl Default for JiraProjectMetaData {
             start_date_field_id: String::new(),
             actual_start_date_field_id: String::new(),
             actual_end_date_field_id: String::new(),
+            dependency_link_types: vec!["Blocks".to_string()],
+            use_changelog: false,
+            epic_issue_type: "Epic".to_string(),
+            epic_link_field_id: String::new(),
+            auth_mode: JiraAuthMode::default(),
+            api_flavor: JiraApiFlavor::default(),
+            max_retries: 3,
+            retry_backoff_ms: 1000,
+            timeout_secs: 30,
         }
     }
 }
@@ -50,6 +107,40 @@ impl JiraProjectMetaData {
             serde_yaml::from_str(&contents).map_err(|_| DataSourceError::Parse)?;
         Ok(metadata)
     }
+
+    /// Whether the dependencies of issues are read from their links.
+    pub fn reads_dependency_links(&self) -> bool {
+        !self.dependency_link_types.is_empty()
+    }
+
+    /// The throughput query restricted to issues done on or after `date`.
+    ///
+    /// Without an end date field, or with done dates read from the changelog,
+    /// issues updated since `date` are queried instead.
+    pub fn throughput_query_since(&self, date: NaiveDate) -> String {
+        let field_id = &self.actual_end_date_field_id;
+        let field = if field_id.is_empty() || self.use_changelog {
+            "updated".to_string()
+        } else if let Some(number) = field_id.strip_prefix("customfield_") {
+            format!("cf[{number}]")
+        } else {
+            field_id.clone()
+        };
+        let condition = format!("{field} >= \"{date}\"");
+
+        let lowercase = self.throughput_query.to_lowercase();
+        let (filter, order) = match lowercase.find("order by") {
+            Some(index) => self.throughput_query.split_at(index),
+            None => (self.throughput_query.as_str(), ""),
+        };
+        let filter = filter.trim();
+        match (filter.is_empty(), order.is_empty()) {
+            (true, true) => condition,
+            (true, false) => format!("{condition} {order}"),
+            (false, true) => format!("({filter}) AND {condition}"),
+            (false, false) => format!("({filter}) AND {condition} {order}"),
+        }
+    }
 }
 
 pub struct JiraConfigParser;
@@ -61,21 +152,26 @@ impl JiraConfigParser {
 }
 
 #[derive(Debug, Clone)]
-pub struct AuthData {
-    pub username: String,
-    pub api_token: String,
+pub enum AuthData {
+    Basic { username: String, api_token: String },
+    Bearer { token: String },
 }
 
 impl AuthData {
-    pub fn from_env() -> Result<Self, DataSourceError> {
-        let username = env::var("JIRA_USERNAME").ok();
-        let api_token = env::var("JIRA_API_TOKEN").ok();
-        match (username, api_token) {
-            (Some(username), Some(api_token)) => Ok(Self {
-                username,
-                api_token,
-            }),
-            _ => Err(DataSourceError::Unauthorized),
+    /// Reads `JIRA_USERNAME` and `JIRA_API_TOKEN` for basic auth, or only
+    /// `JIRA_API_TOKEN` as personal access token for bearer auth.
+    pub fn from_env(mode: JiraAuthMode) -> Result<Self, DataSourceError> {
+        let api_token = env::var("JIRA_API_TOKEN").map_err(|_| DataSourceError::Unauthorized)?;
+        match mode {
+            JiraAuthMode::Basic => {
+                let username =
+                    env::var("JIRA_USERNAME").map_err(|_| DataSourceError::Unauthorized)?;
+                Ok(Self::Basic {
+                    username,
+                    api_token,
+                })
+            }
+            JiraAuthMode::Bearer => Ok(Self::Bearer { token: api_token }),
         }
     }
 }
@@ -94,55 +190,109 @@ impl JiraApiClient {
             ));
         }
 
+        let client = Client::builder()
+            .timeout(Duration::from_secs(jira_project.timeout_secs))
+            .build()
+            .map_err(|err| DataSourceError::Other(format!("failed to create client: {err}")))?;
+
         Ok(Self {
             jira_project,
             auth,
-            client: Client::new(),
+            client,
         })
     }
 
+    /// Sends a GET request, retrying rate limited and transient failures.
     fn fetch_json(
         &self,
         url: &str,
         params: &HashMap<&str, String>,
     ) -> Result<Value, DataSourceError> {
-        let response = self
-            .client
-            .get(url)
-            .query(params)
-            .basic_auth(
-                self.auth.username.clone(),
-                Some(self.auth.api_token.clone()),
-            )
-            .send()
-            .map_err(|_| DataSourceError::Connection)?;
-
-        let status = response.status();
-        if status == StatusCode::UNAUTHORIZED {
-            return Err(DataSourceError::Unauthorized);
-        }
-        if status == StatusCode::NOT_FOUND {
-            return Err(DataSourceError::NotFound);
-        }
-        if !status.is_success() {
-            return Err(DataSourceError::Connection);
+        let base_delay = Duration::from_millis(self.jira_project.retry_backoff_ms);
+        let mut attempt = 0;
+        loop {
+            let can_retry = attempt < self.jira_project.max_retries;
+            let response = match self.send(url, params) {
+                Ok(response) => response,
+                Err(err) if can_retry && (err.is_timeout() || err.is_connect()) => {
+                    thread::sleep(retry_delay(&HeaderMap::new(), attempt, base_delay));
+                    attempt += 1;
+                    continue;
+                }
+                Err(err) => {
+                    return Err(DataSourceError::Request {
+                        url: url.to_string(),
+                        message: err.to_string(),
+                    });
+                }
+            };
+
+            let status = response.status();
+            let is_transient = status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error();
+            if is_transient && can_retry {
+                thread::sleep(retry_delay(response.headers(), attempt, base_delay));
+                attempt += 1;
+                continue;
+            }
+            if status == StatusCode::UNAUTHORIZED {
+                return Err(DataSourceError::Unauthorized);
+            }
+            if status == StatusCode::NOT_FOUND {
+                return Err(DataSourceError::NotFound);
+            }
+            if !status.is_success() {
+                let url = response.url().to_string();
+                let body = response.text().unwrap_or_default();
+                return Err(DataSourceError::Http {
+                    status: status.as_u16(),
+                    url,
+                    body: truncate_body(&body),
+                });
+            }
+
+            return response.json::<Value>().map_err(|_| DataSourceError::Parse);
         }
+    }
 
-        response.json::<Value>().map_err(|_| DataSourceError::Parse)
+    fn send(&self, url: &str, params: &HashMap<&str, String>) -> reqwest::Result<Response> {
+        let request = self.client.get(url).query(params);
+        let request = match &self.auth {
+            AuthData::Basic {
+                username,
+                api_token,
+            } => request.basic_auth(username, Some(api_token)),
+            AuthData::Bearer { token } => request.bearer_auth(token),
+        };
+        request.send()
     }
 
     fn get_issues_by_jql(&self, jql: &str) -> Result<Vec<Issue>, DataSourceError> {
-        let url = format!("{}/search/jql", self.jira_project.base_url);
-        let fields = format!(
-            "summary,description,statusCategory,created,{},{},{}",
+        let search_path = match self.jira_project.api_flavor {
+            JiraApiFlavor::Cloud => "search/jql",
+            JiraApiFlavor::Server => "search",
+        };
+        let url = format!("{}/{search_path}", self.jira_project.base_url);
+        let mut fields = format!(
+            "summary,description,statusCategory,created,labels,issuelinks,parent,{},{},{}",
             self.jira_project.actual_start_date_field_id,
             self.jira_project.actual_end_date_field_id,
             self.jira_project.estimation_field_id
         );
+        if !self.jira_project.epic_link_field_id.is_empty() {
+            fields.push(',');
+            fields.push_str(&self.jira_project.epic_link_field_id);
+        }
         let mut params = HashMap::new();
         params.insert("jql", jql.to_string());
         params.insert("fields", fields);
 
+        let status_categories = if self.jira_project.use_changelog {
+            params.insert("expand", "changelog".to_string());
+            self.get_status_categories()?
+        } else {
+            HashMap::new()
+        };
+
         let mut mapped = Vec::new();
         let mut last_page_token: Option<String> = None;
 
@@ -156,7 +306,7 @@ impl JiraApiClient {
 
             for issue in issues {
                 if let Some(issue_obj) = issue.as_object() {
-                    let mapped_issue = self.map_issue(issue_obj)?;
+                    let mapped_issue = self.map_issue(issue_obj, &status_categories)?;
                     mapped.push(mapped_issue);
                 }
             }
@@ -189,7 +339,7 @@ impl JiraApiClient {
             if let (Some(start_at), Some(max_results), Some(total)) = (start_at, max_results, total)
             {
                 let next_start_at = start_at.saturating_add(max_results);
-                if next_start_at >= total {
+                if max_results == 0 || next_start_at >= total {
                     break;
                 }
                 params.remove("nextPageToken");
@@ -203,7 +353,110 @@ impl JiraApiClient {
         Ok(mapped)
     }
 
-    fn map_issue(&self, issue: &serde_json::Map<String, Value>) -> Result<Issue, DataSourceError> {
+    /// Maps the lowercase name of every status to its status category.
+    fn get_status_categories(&self) -> Result<HashMap<String, IssueStatus>, DataSourceError> {
+        let url = format!("{}/status", self.jira_project.base_url);
+        let payload = self.fetch_json(&url, &HashMap::new())?;
+        let statuses = payload.as_array().ok_or(DataSourceError::Parse)?;
+
+        let mut categories = HashMap::new();
+        for status in statuses {
+            let name = status.get("name").and_then(|value| value.as_str());
+            let category = status
+                .get("statusCategory")
+                .and_then(|value| value.get("key"))
+                .and_then(|value| value.as_str())
+                .and_then(map_status_category_key);
+            if let (Some(name), Some(category)) = (name, category) {
+                categories.insert(name.to_ascii_lowercase(), category);
+            }
+        }
+        Ok(categories)
+    }
+
+    /// Pages through the whole changelog of an issue, if the search only
+    /// embedded the first `maxResults` histories of it.
+    fn get_truncated_changelog(
+        &self,
+        key: &str,
+        issue: &serde_json::Map<String, Value>,
+    ) -> Result<Option<Vec<Value>>, DataSourceError> {
+        let Some(changelog) = issue.get("changelog") else {
+            return Ok(None);
+        };
+        let total = changelog.get("total").and_then(|value| value.as_u64());
+        let max_results = changelog.get("maxResults").and_then(|value| value.as_u64());
+        let total = match (total, max_results) {
+            (Some(total), Some(max_results)) if total > max_results => total,
+            _ => return Ok(None),
+        };
+
+        let mut histories = Vec::new();
+        loop {
+            let (page, is_last) = self.get_changelog_page(key, histories.len())?;
+            let is_empty = page.is_empty();
+            histories.extend(page);
+            if is_last || is_empty || histories.len() as u64 >= total {
+                break;
+            }
+        }
+        Ok(Some(histories))
+    }
+
+    /// The histories of an issue from `start_at` on, and whether they are the
+    /// last. Jira Server has no changelog resource, so there the changelog
+    /// expanded into the issue is paged instead.
+    fn get_changelog_page(
+        &self,
+        key: &str,
+        start_at: usize,
+    ) -> Result<(Vec<Value>, bool), DataSourceError> {
+        let base_url = &self.jira_project.base_url;
+        let (page, histories_key) = match self.jira_project.api_flavor {
+            JiraApiFlavor::Cloud => {
+                let url = format!("{base_url}/issue/{key}/changelog");
+                let params = HashMap::from([("startAt", start_at.to_string())]);
+                (self.fetch_json(&url, &params)?, "values")
+            }
+            JiraApiFlavor::Server => {
+                let url = format!("{base_url}/issue/{key}");
+                let params = HashMap::from([
+                    ("fields", "status".to_string()),
+                    ("expand", "changelog".to_string()),
+                    ("startAt", start_at.to_string()),
+                ]);
+                let mut issue = self.fetch_json(&url, &params)?;
+                let changelog = issue
+                    .get_mut("changelog")
+                    .map(Value::take)
+                    .ok_or(DataSourceError::Parse)?;
+                (changelog, "histories")
+            }
+        };
+
+        // An instance that ignores `startAt` returns the first histories
+        // again, which must not be added twice.
+        let page_start = page.get("startAt").and_then(|value| value.as_u64());
+        if page_start.is_some_and(|page_start| page_start != start_at as u64) {
+            return Ok((Vec::new(), true));
+        }
+        let histories = page
+            .get(histories_key)
+            .and_then(|value| value.as_array())
+            .cloned()
+            .ok_or(DataSourceError::Parse)?;
+        let is_last = page
+            .get("isLast")
+            .and_then(|value| value.as_bool())
+            .unwrap_or(false);
+        Ok((histories, is_last))
+    }
+
+    fn map_issue(
+        &self,
+        issue: &serde_json::Map<String, Value>,
+        status_categories: &HashMap<String, IssueStatus>,
+    ) -> Result<Issue, DataSourceError> {
         let key = issue
             .get("key")
             .and_then(|value| value.as_str())
@@ -233,6 +486,29 @@ impl JiraApiClient {
         mapped.done_date = parse_date_opt(
             get_field_string(fields, &self.jira_project.actual_end_date_field_id).as_deref(),
         );
+        let paged_histories = self.get_truncated_changelog(key, issue)?;
+        let histories = paged_histories
+            .as_deref()
+            .unwrap_or_else(|| changelog_histories(issue));
+        mapped.status_history = get_status_history(histories, status_categories);
+        if mapped.start_date.is_none() {
+            mapped.start_date = history_start_date(&mapped.status_history);
+        }
+        if mapped.done_date.is_none() {
+            mapped.done_date = history_done_date(&mapped.status_history);
+        }
+
+        mapped.labels = get_field_labels(fields);
+        mapped.subgraph = get_field_string(fields, &self.jira_project.epic_link_field_id)
+            .or_else(|| get_field_epic_parent(fields, &self.jira_project.epic_issue_type));
+
+        // Without link types, issues keep the implicit dependency on their
+        // predecessor. Otherwise the links are the whole dependency graph.
+        if self.jira_project.reads_dependency_links() {
+            let dependencies =
+                get_field_dependencies(fields, &self.jira_project.dependency_link_types);
+            mapped.dependencies = (!dependencies.is_empty()).then_some(dependencies);
+        }
         Ok(mapped)
     }
 }
@@ -253,6 +529,19 @@ fn get_field_string(fields: &serde_json::Map<String, Value>, key: &str) -> Optio
     })
 }
 
+fn get_field_labels(fields: &serde_json::Map<String, Value>) -> Vec<String> {
+    fields
+        .get("labels")
+        .and_then(|value| value.as_array())
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| label.as_str().map(|label| label.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn get_field_f32(fields: &serde_json::Map<String, Value>, key: &str) -> Option<f32> {
     fields.get(key).and_then(|value| match value {
         Value::Number(number) => number.as_f64().map(|value| value as f32),
@@ -273,6 +562,162 @@ fn get_field_description(fields: &serde_json::Map<String, Value>, key: &str) ->
     })
 }
 
+/// Collects the keys of the inward issues of all links whose type is one of
+/// `link_types`, e.g. the blockers of a "Blocks" link.
+fn get_field_dependencies(
+    fields: &serde_json::Map<String, Value>,
+    link_types: &[String],
+) -> Vec<IssueId> {
+    let Some(links) = fields.get("issuelinks").and_then(|value| value.as_array()) else {
+        return Vec::new();
+    };
+
+    let mut dependencies: Vec<IssueId> = Vec::new();
+    for link in links {
+        let type_name = link
+            .get("type")
+            .and_then(|value| value.get("name"))
+            .and_then(|value| value.as_str());
+        let is_dependency = type_name.is_some_and(|name| {
+            link_types
+                .iter()
+                .any(|link_type| link_type.eq_ignore_ascii_case(name))
+        });
+        if !is_dependency {
+            continue;
+        }
+
+        let key = link
+            .get("inwardIssue")
+            .and_then(|value| value.get("key"))
+            .and_then(|value| value.as_str());
+        if let Some(key) = key
+            && !dependencies.iter().any(|dependency| dependency.id == key)
+        {
+            dependencies.push(IssueId {
+                id: key.to_string(),
+            });
+        }
+    }
+    dependencies
+}
+
+/// Key of the parent issue if it is of the epic issue type.
+fn get_field_epic_parent(
+    fields: &serde_json::Map<String, Value>,
+    epic_issue_type: &str,
+) -> Option<String> {
+    let parent = fields.get("parent")?;
+    let issue_type = parent
+        .get("fields")
+        .and_then(|value| value.get("issuetype"))
+        .and_then(|value| value.get("name"))
+        .and_then(|value| value.as_str())?;
+    if !issue_type.eq_ignore_ascii_case(epic_issue_type) {
+        return None;
+    }
+    parent
+        .get("key")
+        .and_then(|value| value.as_str())
+        .map(|key| key.to_string())
+}
+
+/// JQL selecting the direct children of an issue, e.g. the epics of an
+/// initiative or the stories of an epic.
+pub fn child_issues_query(parent_key: &str) -> String {
+    format!("parent = {parent_key} ORDER BY Rank ASC")
+}
+
+/// Histories embedded in the expanded changelog of a search result.
+fn changelog_histories(issue: &serde_json::Map<String, Value>) -> &[Value] {
+    issue
+        .get("changelog")
+        .and_then(|value| value.get("histories"))
+        .and_then(|value| value.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Reads the status transitions from changelog histories, oldest first.
+fn get_status_history(
+    histories: &[Value],
+    status_categories: &HashMap<String, IssueStatus>,
+) -> Vec<StatusTransition> {
+    let mut timed_transitions = Vec::new();
+    for history in histories {
+        let Some(created) = history
+            .get("created")
+            .and_then(|value| value.as_str())
+            .and_then(|text| DateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.3f%z").ok())
+        else {
+            continue;
+        };
+        // The day as seen by whoever made the change.
+        let date = created.date_naive();
+        let items = history
+            .get("items")
+            .and_then(|value| value.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for item in items {
+            if item.get("field").and_then(|value| value.as_str()) != Some("status") {
+                continue;
+            }
+            let Some(to_status) = item.get("toString").and_then(|value| value.as_str()) else {
+                continue;
+            };
+            timed_transitions.push((
+                created,
+                StatusTransition {
+                    date,
+                    from_status: item
+                        .get("fromString")
+                        .and_then(|value| value.as_str())
+                        .map(|text| text.to_string()),
+                    to_status: to_status.to_string(),
+                    category: status_categories
+                        .get(&to_status.to_ascii_lowercase())
+                        .cloned(),
+                },
+            ));
+        }
+    }
+
+    // Sorted by instant, as changes made in different time zones carry
+    // different UTC offsets.
+    timed_transitions.sort_by_key(|(created, _)| *created);
+    timed_transitions
+        .into_iter()
+        .map(|(_, transition)| transition)
+        .collect()
+}
+
+/// Date of the first transition into the "in progress" category.
+fn history_start_date(history: &[StatusTransition]) -> Option<NaiveDate> {
+    history
+        .iter()
+        .find(|transition| transition.category == Some(IssueStatus::InProgress))
+        .map(|transition| transition.date)
+}
+
+/// Date of the last transition into the "done" category, unless the issue
+/// was reopened afterwards.
+fn history_done_date(history: &[StatusTransition]) -> Option<NaiveDate> {
+    history
+        .last()
+        .filter(|transition| transition.category == Some(IssueStatus::Done))
+        .map(|transition| transition.date)
+}
+
+fn map_status_category_key(key: &str) -> Option<IssueStatus> {
+    match key {
+        "new" => Some(IssueStatus::ToDo),
+        "indeterminate" => Some(IssueStatus::InProgress),
+        "done" => Some(IssueStatus::Done),
+        _ => None,
+    }
+}
+
 fn get_field_status_category(fields: &serde_json::Map<String, Value>) -> Option<IssueStatus> {
     let status_name = fields
         .get("statusCategory")
@@ -322,3 +767,225 @@ fn adf_to_text(value: &Value) -> String {
 
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Datelike;
+
+    fn link(type_name: &str, side: &str, key: &str) -> Value {
+        serde_json::json!({
+            "type": { "name": type_name, "inward": "is blocked by", "outward": "blocks" },
+            side: { "key": key }
+        })
+    }
+
+    fn ids(dependencies: Vec<IssueId>) -> Vec<String> {
+        dependencies
+            .into_iter()
+            .map(|dependency| dependency.id)
+            .collect()
+    }
+
+    #[test]
+    fn get_field_dependencies_uses_inward_side_of_configured_link_types() {
+        let fields = serde_json::json!({
+            "issuelinks": [
+                link("Blocks", "inwardIssue", "ABC-1"),
+                link("Blocks", "outwardIssue", "ABC-2"),
+                link("Relates", "inwardIssue", "ABC-3"),
+                link("blocks", "inwardIssue", "ABC-4"),
+                link("Blocks", "inwardIssue", "ABC-1")
+            ]
+        });
+        let link_types = vec!["Blocks".to_string()];
+
+        let dependencies = get_field_dependencies(fields.as_object().unwrap(), &link_types);
+
+        assert_eq!(ids(dependencies), vec!["ABC-1", "ABC-4"]);
+    }
+
+    #[test]
+    fn throughput_query_since_adds_condition_before_ordering() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 3).unwrap();
+        let mut metadata = JiraProjectMetaData {
+            throughput_query: "project = ABC AND status = Done ORDER BY resolved".to_string(),
+            actual_end_date_field_id: "customfield_10050".to_string(),
+            ..JiraProjectMetaData::default()
+        };
+
+        assert_eq!(
+            metadata.throughput_query_since(date),
+            "(project = ABC AND status = Done) AND cf[10050] >= \"2026-02-03\" ORDER BY resolved"
+        );
+
+        metadata.use_changelog = true;
+        metadata.throughput_query = "project = ABC".to_string();
+        assert_eq!(
+            metadata.throughput_query_since(date),
+            "(project = ABC) AND updated >= \"2026-02-03\""
+        );
+    }
+
+    fn transition(day: u32, to_status: &str, category: IssueStatus) -> StatusTransition {
+        StatusTransition {
+            date: NaiveDate::from_ymd_opt(2026, 1, day).unwrap(),
+            from_status: None,
+            to_status: to_status.to_string(),
+            category: Some(category),
+        }
+    }
+
+    #[test]
+    fn get_status_history_orders_status_items_and_maps_categories() {
+        let issue = serde_json::json!({
+            "changelog": {
+                "histories": [
+                    {
+                        "created": "2026-01-09T09:00:00.000+0100",
+                        "items": [
+                            { "field": "status", "fromString": "Review", "toString": "Closed" }
+                        ]
+                    },
+                    {
+                        "created": "2026-01-05T09:00:00.000+0100",
+                        "items": [
+                            { "field": "assignee", "fromString": null, "toString": "Ann" },
+                            { "field": "status", "fromString": "Open", "toString": "Review" }
+                        ]
+                    }
+                ]
+            }
+        });
+        let categories = HashMap::from([
+            ("review".to_string(), IssueStatus::InProgress),
+            ("closed".to_string(), IssueStatus::Done),
+        ]);
+
+        let history =
+            get_status_history(changelog_histories(issue.as_object().unwrap()), &categories);
+
+        assert_eq!(
+            history,
+            vec![
+                StatusTransition {
+                    from_status: Some("Open".to_string()),
+                    ..transition(5, "Review", IssueStatus::InProgress)
+                },
+                StatusTransition {
+                    from_status: Some("Review".to_string()),
+                    ..transition(9, "Closed", IssueStatus::Done)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn get_status_history_orders_by_instant_across_utc_offsets() {
+        let issue = serde_json::json!({
+            "changelog": {
+                "histories": [
+                    {
+                        "created": "2026-01-05T23:30:00.000-0500",
+                        "items": [
+                            { "field": "status", "fromString": "Review", "toString": "Closed" }
+                        ]
+                    },
+                    {
+                        "created": "2026-01-06T02:00:00.000+0100",
+                        "items": [
+                            { "field": "status", "fromString": "Open", "toString": "Review" }
+                        ]
+                    }
+                ]
+            }
+        });
+
+        let history = get_status_history(
+            changelog_histories(issue.as_object().unwrap()),
+            &HashMap::new(),
+        );
+
+        let statuses: Vec<_> = history
+            .iter()
+            .map(|transition| (transition.date.day(), transition.to_status.as_str()))
+            .collect();
+        assert_eq!(statuses, vec![(6, "Review"), (5, "Closed")]);
+    }
+
+    #[test]
+    fn history_dates_use_first_start_and_last_done_transition() {
+        let history = vec![
+            transition(5, "In Progress", IssueStatus::InProgress),
+            transition(6, "Done", IssueStatus::Done),
+            transition(7, "In Progress", IssueStatus::InProgress),
+            transition(9, "Done", IssueStatus::Done),
+        ];
+
+        assert_eq!(
+            history_start_date(&history),
+            NaiveDate::from_ymd_opt(2026, 1, 5)
+        );
+        assert_eq!(
+            history_done_date(&history),
+            NaiveDate::from_ymd_opt(2026, 1, 9)
+        );
+        assert_eq!(history_done_date(&history[..3]), None);
+    }
+
+    #[test]
+    fn get_field_epic_parent_requires_epic_issue_type() {
+        let parent = |issue_type: &str| {
+            serde_json::json!({
+                "parent": {
+                    "key": "ABC-10",
+                    "fields": { "issuetype": { "name": issue_type } }
+                }
+            })
+        };
+
+        let epic = parent("Epic");
+        let story = parent("Story");
+
+        assert_eq!(
+            get_field_epic_parent(epic.as_object().unwrap(), "epic"),
+            Some("ABC-10".to_string())
+        );
+        assert_eq!(
+            get_field_epic_parent(story.as_object().unwrap(), "Epic"),
+            None
+        );
+    }
+
+    #[test]
+    fn metadata_defaults_to_cloud_with_basic_auth() {
+        let metadata: JiraProjectMetaData = serde_yaml::from_str("project_key: ABC").unwrap();
+        assert_eq!(metadata.auth_mode, JiraAuthMode::Basic);
+        assert_eq!(metadata.api_flavor, JiraApiFlavor::Cloud);
+
+        let metadata: JiraProjectMetaData =
+            serde_yaml::from_str("auth_mode: bearer\napi_flavor: data_center").unwrap();
+        assert_eq!(metadata.auth_mode, JiraAuthMode::Bearer);
+        assert_eq!(metadata.api_flavor, JiraApiFlavor::Server);
+    }
+
+    #[test]
+    fn get_field_dependencies_without_links_is_empty() {
+        let fields = serde_json::json!({ "summary": "No links" });
+        let link_types = JiraProjectMetaData::default().dependency_link_types;
+
+        assert!(get_field_dependencies(fields.as_object().unwrap(), &link_types).is_empty());
+    }
+
+    #[test]
+    fn get_field_labels_reads_label_names() {
+        let fields = serde_json::json!({ "labels": ["backend", "api"] });
+        let without_labels = serde_json::json!({ "summary": "No labels" });
+
+        assert_eq!(
+            get_field_labels(fields.as_object().unwrap()),
+            vec!["backend".to_string(), "api".to_string()]
+        );
+        assert!(get_field_labels(without_labels.as_object().unwrap()).is_empty());
+    }
+}
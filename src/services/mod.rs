@@ -1,8 +1,13 @@
+pub(crate) mod cached_data_source;
+pub(crate) mod csv_source;
 pub(crate) mod data_converter;
 pub(crate) mod data_source;
+pub(crate) mod github_api;
+pub(crate) mod gitlab_api;
 pub(crate) mod jira_api;
 pub(crate) mod parsing;
 pub(crate) mod plotting;
 pub(crate) mod project_factory;
+pub(crate) mod project_hierarchy;
 pub(crate) mod project_simulation;
 pub(crate) mod util;
@@ -0,0 +1,139 @@
+use crate::domain::cycle_time::CycleTime;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+use thiserror::Error;
+
+#[derive(Serialize)]
+struct CycleTimeRecord {
+    id: String,
+    start_date: String,
+    done_date: String,
+    cycle_time_days: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    story_points: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CycleTimeRecordInput {
+    id: String,
+    start_date: String,
+    done_date: String,
+    cycle_time_days: i64,
+    #[serde(default)]
+    story_points: Option<f32>,
+    #[serde(default)]
+    labels: Vec<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum CycleTimeYamlError {
+    #[error("failed to read cycle time file: {0}")]
+    Read(#[from] io::Error),
+    #[error("failed to parse yaml: {0}")]
+    Parse(#[from] serde_yaml::Error),
+    #[error("invalid date format: {0}")]
+    InvalidDate(String),
+}
+
+pub fn serialize_cycle_times_to_yaml<W: Write>(
+    writer: &mut W,
+    data: &[CycleTime],
+) -> io::Result<()> {
+    let records: Vec<CycleTimeRecord> = data
+        .iter()
+        .map(|c| CycleTimeRecord {
+            id: c.issue_id.clone(),
+            start_date: c.start_date.format("%Y-%m-%d").to_string(),
+            done_date: c.done_date.format("%Y-%m-%d").to_string(),
+            cycle_time_days: c.days,
+            story_points: c.story_points,
+            labels: c.labels.clone(),
+        })
+        .collect();
+
+    let yaml = serde_yaml::to_string(&records).map_err(io::Error::other)?;
+    writer.write_all(yaml.as_bytes())
+}
+
+pub fn load_cycle_times_from_yaml_file(path: &str) -> Result<Vec<CycleTime>, CycleTimeYamlError> {
+    let contents = std::fs::read_to_string(path)?;
+    deserialize_cycle_times_from_yaml_str(&contents)
+}
+
+pub fn deserialize_cycle_times_from_yaml_str(
+    input: &str,
+) -> Result<Vec<CycleTime>, CycleTimeYamlError> {
+    let records: Vec<CycleTimeRecordInput> = serde_yaml::from_str(input)?;
+    records
+        .into_iter()
+        .map(|record| {
+            Ok(CycleTime {
+                issue_id: record.id,
+                start_date: parse_date(&record.start_date)?,
+                done_date: parse_date(&record.done_date)?,
+                days: record.cycle_time_days,
+                story_points: record.story_points,
+                labels: record.labels,
+            })
+        })
+        .collect()
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, CycleTimeYamlError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| CycleTimeYamlError::InvalidDate(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_cycle_times_to_yaml() {
+        let data = vec![CycleTime {
+            issue_id: "ABC-1".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 2, 9).unwrap(),
+            done_date: NaiveDate::from_ymd_opt(2026, 2, 12).unwrap(),
+            days: 4,
+            story_points: None,
+            labels: vec![],
+        }];
+        let mut buf = Vec::new();
+        serialize_cycle_times_to_yaml(&mut buf, &data).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert_eq!(
+            output,
+            "- id: ABC-1\n  start_date: 2026-02-09\n  done_date: 2026-02-12\n  cycle_time_days: 4\n"
+        );
+    }
+
+    #[test]
+    fn cycle_times_survive_a_yaml_round_trip() {
+        let data = vec![CycleTime {
+            issue_id: "ABC-2".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 2, 9).unwrap(),
+            done_date: NaiveDate::from_ymd_opt(2026, 2, 10).unwrap(),
+            days: 2,
+            story_points: Some(3.0),
+            labels: vec!["backend".to_string()],
+        }];
+        let mut buf = Vec::new();
+        serialize_cycle_times_to_yaml(&mut buf, &data).unwrap();
+
+        let restored = deserialize_cycle_times_from_yaml_str(&String::from_utf8(buf).unwrap());
+
+        assert_eq!(restored.unwrap(), data);
+    }
+
+    #[test]
+    fn invalid_dates_are_rejected() {
+        let yaml = "- id: ABC-1\n  start_date: 09.02.2026\n  done_date: 2026-02-12\n  cycle_time_days: 4\n";
+
+        let error = deserialize_cycle_times_from_yaml_str(yaml).unwrap_err();
+
+        assert!(matches!(error, CycleTimeYamlError::InvalidDate(date) if date == "09.02.2026"));
+    }
+}
@@ -0,0 +1,268 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::estimate::{
+    Estimate, ReferenceEstimate, StoryPointEstimate, ThreePointEstimate,
+};
+use crate::domain::issue::{Issue, IssueId, StatusTransition};
+use crate::domain::issue_status::IssueStatus;
+
+/// Issues fetched for one query, as stored in the data source cache.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedIssues {
+    pub query: String,
+    pub fetched_at: DateTime<Utc>,
+    pub issues: Vec<Issue>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheRecord {
+    query: String,
+    fetched_at: DateTime<Utc>,
+    issues: Vec<IssueRecord>,
+}
+
+/// Unlike the project YAML, the cache keeps every field of an issue so that a
+/// cached fetch is indistinguishable from a live one.
+#[derive(Serialize, Deserialize)]
+struct IssueRecord {
+    id: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    estimate: Option<EstimateRecord>,
+    dependencies: Option<Vec<String>>,
+    subgraph: Option<String>,
+    status: Option<StatusRecord>,
+    created_date: Option<NaiveDate>,
+    start_date: Option<NaiveDate>,
+    done_date: Option<NaiveDate>,
+    due_date: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    status_history: Vec<TransitionRecord>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum EstimateRecord {
+    StoryPoints {
+        value: Option<f32>,
+    },
+    ThreePoint {
+        optimistic: Option<f32>,
+        most_likely: Option<f32>,
+        pessimistic: Option<f32>,
+    },
+    Reference {
+        report_file_path: String,
+    },
+    Milestone,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum StatusRecord {
+    ToDo,
+    InProgress,
+    Done,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TransitionRecord {
+    date: NaiveDate,
+    from_status: Option<String>,
+    to_status: String,
+    category: Option<StatusRecord>,
+}
+
+pub fn serialize_cached_issues_to_yaml(cached: &CachedIssues) -> Result<String, serde_yaml::Error> {
+    let record = CacheRecord {
+        query: cached.query.clone(),
+        fetched_at: cached.fetched_at,
+        issues: cached.issues.iter().map(issue_to_record).collect(),
+    };
+    serde_yaml::to_string(&record)
+}
+
+pub fn deserialize_cached_issues_from_yaml_str(
+    input: &str,
+) -> Result<CachedIssues, serde_yaml::Error> {
+    let record: CacheRecord = serde_yaml::from_str(input)?;
+    Ok(CachedIssues {
+        query: record.query,
+        fetched_at: record.fetched_at,
+        issues: record.issues.into_iter().map(issue_from_record).collect(),
+    })
+}
+
+fn issue_to_record(issue: &Issue) -> IssueRecord {
+    IssueRecord {
+        id: issue.issue_id.as_ref().map(|id| id.id.clone()),
+        summary: issue.summary.clone(),
+        description: issue.description.clone(),
+        estimate: issue.estimate.as_ref().map(estimate_to_record),
+        dependencies: issue
+            .dependencies
+            .as_ref()
+            .map(|values| values.iter().map(|id| id.id.clone()).collect()),
+        subgraph: issue.subgraph.clone(),
+        status: issue.status.as_ref().map(status_to_record),
+        created_date: issue.created_date,
+        start_date: issue.start_date,
+        done_date: issue.done_date,
+        due_date: issue.due_date,
+        status_history: issue
+            .status_history
+            .iter()
+            .map(|transition| TransitionRecord {
+                date: transition.date,
+                from_status: transition.from_status.clone(),
+                to_status: transition.to_status.clone(),
+                category: transition.category.as_ref().map(status_to_record),
+            })
+            .collect(),
+        labels: issue.labels.clone(),
+    }
+}
+
+fn issue_from_record(record: IssueRecord) -> Issue {
+    Issue {
+        issue_id: record.id.map(|id| IssueId { id }),
+        summary: record.summary,
+        description: record.description,
+        estimate: record.estimate.map(estimate_from_record),
+        dependencies: record
+            .dependencies
+            .map(|values| values.into_iter().map(|id| IssueId { id }).collect()),
+        subgraph: record.subgraph,
+        status: record.status.map(status_from_record),
+        created_date: record.created_date,
+        start_date: record.start_date,
+        done_date: record.done_date,
+        due_date: record.due_date,
+        status_history: record
+            .status_history
+            .into_iter()
+            .map(|transition| StatusTransition {
+                date: transition.date,
+                from_status: transition.from_status,
+                to_status: transition.to_status,
+                category: transition.category.map(status_from_record),
+            })
+            .collect(),
+        labels: record.labels,
+        // Only set in project files, never by an issue tracker.
+        correlation_group: None,
+        constraint: None,
+    }
+}
+
+fn estimate_to_record(estimate: &Estimate) -> EstimateRecord {
+    match estimate {
+        Estimate::StoryPoint(StoryPointEstimate { estimate }) => {
+            EstimateRecord::StoryPoints { value: *estimate }
+        }
+        Estimate::ThreePoint(ThreePointEstimate {
+            optimistic,
+            most_likely,
+            pessimistic,
+            ..
+        }) => EstimateRecord::ThreePoint {
+            optimistic: *optimistic,
+            most_likely: *most_likely,
+            pessimistic: *pessimistic,
+        },
+        Estimate::Reference(ReferenceEstimate {
+            report_file_path, ..
+        }) => EstimateRecord::Reference {
+            report_file_path: report_file_path.clone(),
+        },
+        Estimate::Milestone => EstimateRecord::Milestone,
+    }
+}
+
+fn estimate_from_record(record: EstimateRecord) -> Estimate {
+    match record {
+        EstimateRecord::StoryPoints { value } => {
+            Estimate::StoryPoint(StoryPointEstimate { estimate: value })
+        }
+        EstimateRecord::ThreePoint {
+            optimistic,
+            most_likely,
+            pessimistic,
+        } => Estimate::ThreePoint(ThreePointEstimate {
+            optimistic,
+            most_likely,
+            pessimistic,
+            distribution: None,
+        }),
+        EstimateRecord::Reference { report_file_path } => Estimate::Reference(ReferenceEstimate {
+            report_file_path,
+            cached_estimate: None,
+        }),
+        EstimateRecord::Milestone => Estimate::Milestone,
+    }
+}
+
+fn status_to_record(status: &IssueStatus) -> StatusRecord {
+    match status {
+        IssueStatus::ToDo => StatusRecord::ToDo,
+        IssueStatus::InProgress => StatusRecord::InProgress,
+        IssueStatus::Done => StatusRecord::Done,
+    }
+}
+
+fn status_from_record(record: StatusRecord) -> IssueStatus {
+    match record {
+        StatusRecord::ToDo => IssueStatus::ToDo,
+        StatusRecord::InProgress => IssueStatus::InProgress,
+        StatusRecord::Done => IssueStatus::Done,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_issues_survive_a_yaml_round_trip() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let issue = Issue {
+            issue_id: Some(IssueId {
+                id: "ABC-1".to_string(),
+            }),
+            summary: Some("Build".to_string()),
+            estimate: Some(Estimate::StoryPoint(StoryPointEstimate {
+                estimate: Some(3.0),
+            })),
+            dependencies: Some(vec![IssueId {
+                id: "ABC-0".to_string(),
+            }]),
+            subgraph: Some("EPIC-1".to_string()),
+            status: Some(IssueStatus::Done),
+            start_date: Some(date(2)),
+            done_date: Some(date(4)),
+            status_history: vec![StatusTransition {
+                date: date(2),
+                from_status: Some("Open".to_string()),
+                to_status: "In Progress".to_string(),
+                category: Some(IssueStatus::InProgress),
+            }],
+            labels: vec!["backend".to_string()],
+            ..Issue::new()
+        };
+        let cached = CachedIssues {
+            query: "project = ABC".to_string(),
+            fetched_at: DateTime::parse_from_rfc3339("2026-03-05T10:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            issues: vec![issue, Issue::new()],
+        };
+
+        let yaml = serialize_cached_issues_to_yaml(&cached).unwrap();
+        let restored = deserialize_cached_issues_from_yaml_str(&yaml).unwrap();
+
+        assert_eq!(restored, cached);
+    }
+}
@@ -1,3 +1,5 @@
+pub(crate) mod cycle_time_yaml;
+pub(crate) mod issue_cache_yaml;
 pub(crate) mod project_yaml;
 pub(crate) mod simulation_report_yaml;
 pub(crate) mod team_calendar_yaml;
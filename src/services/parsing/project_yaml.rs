@@ -5,15 +5,17 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::domain::estimate::{
-    Estimate, ReferenceEstimate, StoryPointEstimate, ThreePointEstimate,
+    DurationDistribution, Estimate, ReferenceEstimate, StoryPointEstimate, ThreePointEstimate,
 };
-use crate::domain::issue::{Issue, IssueId};
+use crate::domain::issue::{DateConstraint, Issue, IssueId};
 use crate::domain::issue_status::IssueStatus;
 use crate::domain::project::Project;
+use crate::domain::risk::Risk;
 use crate::domain::validation::project_validation::{ValidationErrors, validate_project};
 use crate::services::parsing::simulation_report_yaml::{
     ReportParseError, load_simulation_report_from_file,
 };
+use crate::services::project_simulation::beta_pert_sampler::PERT_SHAPE;
 
 #[derive(Error, Debug)]
 pub enum ProjectYamlError {
@@ -29,6 +31,10 @@ pub enum ProjectYamlError {
     InvalidStatus(String),
     #[error("missing previous issue for implicit dependency")]
     MissingPreviousDependency,
+    #[error("shape is only used by the modified_pert distribution (issue {0})")]
+    ShapeWithoutModifiedPert(String),
+    #[error("risk {0} has an incomplete impact")]
+    IncompleteRiskImpact(String),
     #[error("failed to load reference estimate from report file '{path}': {source}")]
     ReferenceEstimateLoad {
         path: String,
@@ -43,6 +49,8 @@ pub enum ProjectYamlError {
 struct ProjectRecord {
     name: String,
     work_packages: Vec<IssueRecord>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    risks: Vec<RiskRecord>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -55,8 +63,23 @@ struct IssueRecord {
     created_date: Option<String>,
     start_date: Option<String>,
     done_date: Option<String>,
+    due_date: Option<String>,
     dependencies: Option<Vec<String>>,
     subgraph: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    correlation_group: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    constraint: Option<ConstraintRecord>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ConstraintRecord {
+    StartNoEarlierThan { date: String },
+    MustFinishOnOrBefore { date: String },
+    FixedDate { date: String },
 }
 
 #[derive(Serialize, Deserialize)]
@@ -69,6 +92,11 @@ enum EstimateRecord {
         optimistic: f32,
         most_likely: f32,
         pessimistic: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        distribution: Option<DistributionRecord>,
+        /// Shape of the modified PERT distribution, 4 if omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        shape: Option<f32>,
     },
     Reference {
         report_file_path: String,
@@ -76,6 +104,38 @@ enum EstimateRecord {
     Milestone,
 }
 
+#[derive(Serialize, Deserialize)]
+struct RiskRecord {
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    probability: f32,
+    /// Delay in days.
+    impact: ImpactRecord,
+    affects: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ImpactRecord {
+    optimistic: f32,
+    most_likely: f32,
+    pessimistic: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    distribution: Option<DistributionRecord>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shape: Option<f32>,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum DistributionRecord {
+    BetaPert,
+    ModifiedPert,
+    Triangular,
+    Uniform,
+    LogNormal,
+}
+
 pub fn load_project_from_yaml_file(
     path: &str,
     project_start_date: &Option<NaiveDate>,
@@ -108,7 +168,15 @@ pub fn deserialize_project_from_yaml_str(
         issue.created_date = parse_date_opt(issue_record.created_date.as_deref())?;
         issue.start_date = parse_date_opt(issue_record.start_date.as_deref())?;
         issue.done_date = parse_date_opt(issue_record.done_date.as_deref())?;
+        issue.due_date = parse_date_opt(issue_record.due_date.as_deref())?;
         issue.subgraph = issue_record.subgraph;
+        issue.labels = issue_record.labels;
+        issue.correlation_group = issue_record.correlation_group;
+        issue.constraint = issue_record
+            .constraint
+            .as_ref()
+            .map(constraint_from_record)
+            .transpose()?;
         issue.dependencies = match issue_record.dependencies {
             None => None,
             Some(values) if values.is_empty() => {
@@ -123,9 +191,16 @@ pub fn deserialize_project_from_yaml_str(
         work_packages.push(issue);
     }
 
+    let risks = record
+        .risks
+        .into_iter()
+        .map(risk_from_record)
+        .collect::<Result<Vec<_>, _>>()?;
+
     let project = Project {
         name: record.name,
         work_packages,
+        risks,
     };
 
     validate_project(&project)?;
@@ -137,10 +212,15 @@ pub fn serialize_project_to_yaml<W: Write>(writer: &mut W, project: &Project) ->
     let record = ProjectRecord {
         name: project.name.clone(),
         work_packages: project.work_packages.iter().map(issue_to_record).collect(),
+        risks: project
+            .risks
+            .iter()
+            .map(risk_to_record)
+            .collect::<Result<_, _>>()
+            .map_err(io::Error::other)?,
     };
 
-    let yaml =
-        serde_yaml::to_string(&record).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    let yaml = serde_yaml::to_string(&record).map_err(io::Error::other)?;
     writer.write_all(yaml.as_bytes())
 }
 
@@ -164,14 +244,91 @@ fn issue_to_record(issue: &Issue) -> IssueRecord {
         done_date: issue
             .done_date
             .map(|date| date.format("%Y-%m-%d").to_string()),
+        due_date: issue
+            .due_date
+            .map(|date| date.format("%Y-%m-%d").to_string()),
         dependencies: issue
             .dependencies
             .as_ref()
             .map(|values| values.iter().map(|id| id.id.clone()).collect()),
         subgraph: issue.subgraph.clone(),
+        labels: issue.labels.clone(),
+        correlation_group: issue.correlation_group.clone(),
+        constraint: issue.constraint.map(constraint_to_record),
     }
 }
 
+fn constraint_from_record(record: &ConstraintRecord) -> Result<DateConstraint, ProjectYamlError> {
+    let parse = |text: &str| {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .map_err(|_| ProjectYamlError::InvalidDate(text.to_string()))
+    };
+    Ok(match record {
+        ConstraintRecord::StartNoEarlierThan { date } => {
+            DateConstraint::StartNoEarlierThan(parse(date)?)
+        }
+        ConstraintRecord::MustFinishOnOrBefore { date } => {
+            DateConstraint::MustFinishOnOrBefore(parse(date)?)
+        }
+        ConstraintRecord::FixedDate { date } => DateConstraint::FixedDate(parse(date)?),
+    })
+}
+
+fn constraint_to_record(constraint: DateConstraint) -> ConstraintRecord {
+    let date = constraint.date().format("%Y-%m-%d").to_string();
+    match constraint {
+        DateConstraint::StartNoEarlierThan(_) => ConstraintRecord::StartNoEarlierThan { date },
+        DateConstraint::MustFinishOnOrBefore(_) => ConstraintRecord::MustFinishOnOrBefore { date },
+        DateConstraint::FixedDate(_) => ConstraintRecord::FixedDate { date },
+    }
+}
+
+fn risk_from_record(record: RiskRecord) -> Result<Risk, ProjectYamlError> {
+    let ImpactRecord {
+        optimistic,
+        most_likely,
+        pessimistic,
+        distribution,
+        shape,
+    } = record.impact;
+    Ok(Risk {
+        impact: ThreePointEstimate {
+            optimistic: Some(optimistic),
+            most_likely: Some(most_likely),
+            pessimistic: Some(pessimistic),
+            distribution: distribution_from_record(distribution, shape, &record.id)?,
+        },
+        id: record.id,
+        description: record.description,
+        probability: record.probability,
+        affects: record.affects,
+    })
+}
+
+fn risk_to_record(risk: &Risk) -> Result<RiskRecord, ProjectYamlError> {
+    let (Some(optimistic), Some(most_likely), Some(pessimistic)) = (
+        risk.impact.optimistic,
+        risk.impact.most_likely,
+        risk.impact.pessimistic,
+    ) else {
+        return Err(ProjectYamlError::IncompleteRiskImpact(risk.id.clone()));
+    };
+    let (distribution, shape) = distribution_to_record(risk.impact.distribution);
+    Ok(RiskRecord {
+        id: risk.id.clone(),
+        description: risk.description.clone(),
+        probability: risk.probability,
+        impact: ImpactRecord {
+            optimistic,
+            most_likely,
+            pessimistic,
+            distribution,
+            shape,
+        },
+        affects: risk.affects.clone(),
+    })
+}
+
 fn estimate_from_record(
     record: &IssueRecord,
     project_start_date: &Option<NaiveDate>,
@@ -192,16 +349,19 @@ fn estimate_from_record(
             optimistic,
             most_likely,
             pessimistic,
+            distribution,
+            shape,
         } => Ok(Some(Estimate::ThreePoint(ThreePointEstimate {
             optimistic: Some(*optimistic),
             most_likely: Some(*most_likely),
             pessimistic: Some(*pessimistic),
+            distribution: distribution_from_record(*distribution, *shape, &record.id)?,
         }))),
         EstimateRecord::Reference { report_file_path } => {
             let start_date = parse_date_opt(record.start_date.as_deref())?;
             let cached_estimate = Some(
                 three_point_estimate_from_report_file(
-                    &report_file_path,
+                    report_file_path,
                     &start_date,
                     project_start_date,
                 )
@@ -241,9 +401,10 @@ fn three_point_estimate_from_report_file(
     };
 
     Ok(ThreePointEstimate {
-        optimistic: Some(past_days + report.p0.days),
-        most_likely: Some(past_days + report.p50.days),
-        pessimistic: Some(past_days + report.p100.days),
+        optimistic: Some(past_days + report.percentiles.require(0.0)?.days),
+        most_likely: Some(past_days + report.percentiles.require(50.0)?.days),
+        pessimistic: Some(past_days + report.percentiles.require(100.0)?.days),
+        distribution: None,
     })
 }
 
@@ -256,12 +417,16 @@ fn estimate_to_record(estimate: Option<&Estimate>) -> Option<EstimateRecord> {
             optimistic,
             most_likely,
             pessimistic,
+            distribution,
         }) => match (optimistic, most_likely, pessimistic) {
             (Some(optimistic), Some(most_likely), Some(pessimistic)) => {
+                let (distribution, shape) = distribution_to_record(*distribution);
                 Some(EstimateRecord::ThreePoint {
                     optimistic: *optimistic,
                     most_likely: *most_likely,
                     pessimistic: *pessimistic,
+                    distribution,
+                    shape,
                 })
             }
             _ => None,
@@ -276,6 +441,47 @@ fn estimate_to_record(estimate: Option<&Estimate>) -> Option<EstimateRecord> {
     }
 }
 
+fn distribution_from_record(
+    distribution: Option<DistributionRecord>,
+    shape: Option<f32>,
+    issue_id: &str,
+) -> Result<Option<DurationDistribution>, ProjectYamlError> {
+    let distribution = match distribution {
+        Some(DistributionRecord::ModifiedPert) => {
+            return Ok(Some(DurationDistribution::ModifiedPert {
+                // Without a shape, `modified_pert` is classic PERT.
+                shape: shape.unwrap_or(PERT_SHAPE),
+            }));
+        }
+        Some(DistributionRecord::BetaPert) => Some(DurationDistribution::BetaPert),
+        Some(DistributionRecord::Triangular) => Some(DurationDistribution::Triangular),
+        Some(DistributionRecord::Uniform) => Some(DurationDistribution::Uniform),
+        Some(DistributionRecord::LogNormal) => Some(DurationDistribution::LogNormal),
+        None => None,
+    };
+    if shape.is_some() {
+        return Err(ProjectYamlError::ShapeWithoutModifiedPert(
+            issue_id.to_string(),
+        ));
+    }
+    Ok(distribution)
+}
+
+fn distribution_to_record(
+    distribution: Option<DurationDistribution>,
+) -> (Option<DistributionRecord>, Option<f32>) {
+    match distribution {
+        Some(DurationDistribution::ModifiedPert { shape }) => {
+            (Some(DistributionRecord::ModifiedPert), Some(shape))
+        }
+        Some(DurationDistribution::BetaPert) => (Some(DistributionRecord::BetaPert), None),
+        Some(DurationDistribution::Triangular) => (Some(DistributionRecord::Triangular), None),
+        Some(DurationDistribution::Uniform) => (Some(DistributionRecord::Uniform), None),
+        Some(DurationDistribution::LogNormal) => (Some(DistributionRecord::LogNormal), None),
+        None => (None, None),
+    }
+}
+
 fn parse_date_opt(value: Option<&str>) -> Result<Option<NaiveDate>, ProjectYamlError> {
     let text = match value {
         Some(text) => text,
@@ -335,6 +541,7 @@ mod tests {
         let project = Project {
             name: "TEST".to_string(),
             work_packages: vec![issue],
+            risks: Vec::new(),
         };
 
         let mut buffer = Vec::new();
@@ -403,11 +610,280 @@ work_packages:
             Some(Estimate::ThreePoint(ThreePointEstimate {
                 optimistic: Some(2.0),
                 most_likely: Some(3.0),
-                pessimistic: Some(8.0)
+                pessimistic: Some(8.0),
+                distribution: None,
             }))
         ));
     }
 
+    #[test]
+    fn three_point_estimate_distribution_round_trips() {
+        let yaml = r#"
+name: Demo
+work_packages:
+  - id: ABC-2
+    estimate:
+      type: three_point
+      optimistic: 2
+      most_likely: 3
+      pessimistic: 8
+      distribution: modified_pert
+      shape: 6
+  - id: ABC-3
+    estimate:
+      type: three_point
+      optimistic: 2
+      most_likely: 3
+      pessimistic: 8
+      distribution: log_normal
+"#;
+
+        let project = deserialize_project_from_yaml_str(yaml, &None).unwrap();
+        let distributions: Vec<Option<DurationDistribution>> = project
+            .work_packages
+            .iter()
+            .map(|issue| match &issue.estimate {
+                Some(Estimate::ThreePoint(estimate)) => estimate.distribution,
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            distributions,
+            vec![
+                Some(DurationDistribution::ModifiedPert { shape: 6.0 }),
+                Some(DurationDistribution::LogNormal),
+            ]
+        );
+
+        let mut buffer = Vec::new();
+        serialize_project_to_yaml(&mut buffer, &project).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("distribution: modified_pert"));
+        assert!(output.contains("shape: 6"));
+        assert!(output.contains("distribution: log_normal"));
+    }
+
+    #[test]
+    fn correlation_group_round_trips() {
+        let yaml = r#"
+name: Demo
+work_packages:
+  - id: ABC-1
+    correlation_group: backend
+    estimate:
+      type: milestone
+  - id: ABC-2
+    estimate:
+      type: milestone
+"#;
+
+        let project = deserialize_project_from_yaml_str(yaml, &None).unwrap();
+        assert_eq!(
+            project.work_packages[0].correlation_group.as_deref(),
+            Some("backend")
+        );
+        assert_eq!(project.work_packages[1].correlation_group, None);
+
+        let mut buffer = Vec::new();
+        serialize_project_to_yaml(&mut buffer, &project).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert_eq!(output.matches("correlation_group: backend").count(), 1);
+    }
+
+    #[test]
+    fn date_constraints_round_trip() {
+        let yaml = r#"
+name: Demo
+work_packages:
+  - id: ABC-1
+    estimate:
+      type: three_point
+      optimistic: 1
+      most_likely: 2
+      pessimistic: 3
+    constraint:
+      type: start_no_earlier_than
+      date: 2026-02-02
+  - id: ABC-2
+    estimate:
+      type: three_point
+      optimistic: 1
+      most_likely: 2
+      pessimistic: 3
+    constraint:
+      type: must_finish_on_or_before
+      date: 2026-03-01
+  - id: M1
+    estimate:
+      type: milestone
+    constraint:
+      type: fixed_date
+      date: 2026-04-01
+"#;
+
+        let project = deserialize_project_from_yaml_str(yaml, &None).unwrap();
+        let constraints: Vec<Option<DateConstraint>> = project
+            .work_packages
+            .iter()
+            .map(|issue| issue.constraint)
+            .collect();
+        assert_eq!(
+            constraints,
+            vec![
+                Some(DateConstraint::StartNoEarlierThan(
+                    NaiveDate::from_ymd_opt(2026, 2, 2).unwrap()
+                )),
+                Some(DateConstraint::MustFinishOnOrBefore(
+                    NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+                )),
+                Some(DateConstraint::FixedDate(
+                    NaiveDate::from_ymd_opt(2026, 4, 1).unwrap()
+                )),
+            ]
+        );
+
+        let mut buffer = Vec::new();
+        serialize_project_to_yaml(&mut buffer, &project).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        let reparsed = deserialize_project_from_yaml_str(&output, &None).unwrap();
+        assert_eq!(reparsed.work_packages, project.work_packages);
+    }
+
+    #[test]
+    fn risks_round_trip() {
+        let yaml = r#"
+name: Demo
+work_packages:
+  - id: ABC-1
+    estimate:
+      type: milestone
+risks:
+  - id: R1
+    description: Supplier delivers late
+    probability: 0.3
+    impact:
+      optimistic: 2
+      most_likely: 5
+      pessimistic: 15
+      distribution: triangular
+    affects: [ABC-1]
+"#;
+
+        let project = deserialize_project_from_yaml_str(yaml, &None).unwrap();
+        assert_eq!(
+            project.risks,
+            vec![Risk {
+                id: "R1".to_string(),
+                description: Some("Supplier delivers late".to_string()),
+                probability: 0.3,
+                impact: ThreePointEstimate {
+                    optimistic: Some(2.0),
+                    most_likely: Some(5.0),
+                    pessimistic: Some(15.0),
+                    distribution: Some(DurationDistribution::Triangular),
+                },
+                affects: vec!["ABC-1".to_string()],
+            }]
+        );
+
+        let mut buffer = Vec::new();
+        serialize_project_to_yaml(&mut buffer, &project).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        let reparsed = deserialize_project_from_yaml_str(&output, &None).unwrap();
+        assert_eq!(reparsed.risks, project.risks);
+    }
+
+    #[test]
+    fn serialize_project_rejects_risks_with_incomplete_impact() {
+        let mut project = deserialize_project_from_yaml_str(
+            "name: Demo\nwork_packages:\n  - id: ABC-1\n    estimate:\n      type: milestone\n",
+            &None,
+        )
+        .unwrap();
+        project.risks.push(Risk {
+            id: "R1".to_string(),
+            description: None,
+            probability: 0.5,
+            impact: ThreePointEstimate {
+                optimistic: Some(1.0),
+                most_likely: None,
+                pessimistic: Some(3.0),
+                distribution: None,
+            },
+            affects: vec!["ABC-1".to_string()],
+        });
+
+        let error = serialize_project_to_yaml(&mut Vec::new(), &project).unwrap_err();
+
+        assert_eq!(error.to_string(), "risk R1 has an incomplete impact");
+    }
+
+    #[test]
+    fn deserialize_project_rejects_risks_of_unknown_work_packages() {
+        let yaml = r#"
+name: Demo
+work_packages:
+  - id: ABC-1
+    estimate:
+      type: milestone
+risks:
+  - id: R1
+    probability: 0.3
+    impact:
+      optimistic: 1
+      most_likely: 2
+      pessimistic: 3
+    affects: [ABC-404]
+"#;
+
+        let error = deserialize_project_from_yaml_str(yaml, &None).unwrap_err();
+
+        assert!(error.to_string().contains("R1 -> ABC-404"));
+    }
+
+    #[test]
+    fn deserialize_project_rejects_shape_without_modified_pert() {
+        let yaml = r#"
+name: Demo
+work_packages:
+  - id: ABC-2
+    estimate:
+      type: three_point
+      optimistic: 2
+      most_likely: 3
+      pessimistic: 8
+      distribution: triangular
+      shape: 6
+"#;
+
+        let error = deserialize_project_from_yaml_str(yaml, &None).unwrap_err();
+        assert!(matches!(
+            error,
+            ProjectYamlError::ShapeWithoutModifiedPert(id) if id == "ABC-2"
+        ));
+    }
+
+    #[test]
+    fn deserialize_project_with_milestone_due_date() {
+        let yaml = r#"
+name: Demo
+work_packages:
+  - id: M-1
+    estimate:
+      type: milestone
+    due_date: 2026-04-30
+"#;
+
+        let project = deserialize_project_from_yaml_str(yaml, &None).unwrap();
+        let issue = &project.work_packages[0];
+        assert_eq!(issue.due_date, NaiveDate::from_ymd_opt(2026, 4, 30));
+
+        let mut buffer = Vec::new();
+        serialize_project_to_yaml(&mut buffer, &project).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("due_date: 2026-04-30"));
+    }
+
     #[test]
     fn deserialize_project_rejects_invalid_date() {
         let yaml = r#"
@@ -558,6 +1034,7 @@ work_packages:
         let project = Project {
             name: "TEST".to_string(),
             work_packages: vec![issue_none, issue_empty, issue_values],
+            risks: Vec::new(),
         };
 
         let mut buffer = Vec::new();
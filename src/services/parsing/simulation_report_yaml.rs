@@ -1,3 +1,4 @@
+use crate::services::project_simulation::report_percentiles::MissingPercentile;
 use crate::services::project_simulation::simulation_types::SimulationReport;
 use std::io;
 use thiserror::Error;
@@ -8,6 +9,8 @@ pub enum ReportParseError {
     Io(#[from] io::Error),
     #[error("failed to parse report yaml: {0}")]
     Parse(#[from] serde_yaml::Error),
+    #[error("{0}")]
+    MissingPercentile(#[from] MissingPercentile),
 }
 
 pub fn load_simulation_report_from_file(path: &str) -> Result<SimulationReport, ReportParseError> {
@@ -1,4 +1,5 @@
-use crate::domain::throughput::Throughput;
+use crate::domain::throughput::{Throughput, ThroughputBucket, ThroughputHistory};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::io::{self, Write};
 use thiserror::Error;
@@ -7,12 +8,46 @@ use thiserror::Error;
 struct ThroughputRecord {
     date: String,
     completed_issues: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    capacity: Option<f32>,
 }
 
 #[derive(Deserialize)]
 struct ThroughputRecordInput {
     date: String,
     completed_issues: usize,
+    #[serde(default)]
+    capacity: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum BucketRecord {
+    Day,
+    Week,
+    Sprint,
+}
+
+/// Daily throughput is a plain list; other buckets wrap the list in a map
+/// naming the bucket.
+#[derive(Serialize)]
+struct BucketedThroughputRecord {
+    bucket: BucketRecord,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sprint_start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sprint_length_days: Option<u32>,
+    throughput: Vec<ThroughputRecord>,
+}
+
+#[derive(Deserialize)]
+struct BucketedThroughputRecordInput {
+    bucket: BucketRecord,
+    #[serde(default)]
+    sprint_start: Option<String>,
+    #[serde(default)]
+    sprint_length_days: Option<u32>,
+    throughput: Vec<ThroughputRecordInput>,
 }
 
 #[derive(Error, Debug)]
@@ -21,39 +56,87 @@ pub enum ThroughputYamlError {
     Parse(#[from] serde_yaml::Error),
     #[error("invalid date format: {0}")]
     InvalidDate(String),
+    #[error("sprint buckets need a sprint_start and a positive sprint_length_days")]
+    InvalidSprint,
 }
 
 pub fn serialize_throughput_to_yaml<W: Write>(
     writer: &mut W,
-    data: &[Throughput],
+    data: &ThroughputHistory,
 ) -> io::Result<()> {
     let records: Vec<ThroughputRecord> = data
+        .throughput
         .iter()
         .map(|t| ThroughputRecord {
             date: t.date.format("%Y-%m-%d").to_string(),
             completed_issues: t.completed_issues,
+            capacity: t.capacity,
         })
         .collect();
 
-    let yaml =
-        serde_yaml::to_string(&records).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    let yaml = match data.bucket {
+        ThroughputBucket::Day => serde_yaml::to_string(&records),
+        ThroughputBucket::Week => serde_yaml::to_string(&BucketedThroughputRecord {
+            bucket: BucketRecord::Week,
+            sprint_start: None,
+            sprint_length_days: None,
+            throughput: records,
+        }),
+        ThroughputBucket::Sprint { start, length_days } => {
+            serde_yaml::to_string(&BucketedThroughputRecord {
+                bucket: BucketRecord::Sprint,
+                sprint_start: Some(start.format("%Y-%m-%d").to_string()),
+                sprint_length_days: Some(length_days),
+                throughput: records,
+            })
+        }
+    }
+    .map_err(io::Error::other)?;
     writer.write_all(yaml.as_bytes())
 }
 
 pub fn deserialize_throughput_from_yaml_str(
     input: &str,
-) -> Result<Vec<Throughput>, ThroughputYamlError> {
-    let records: Vec<ThroughputRecordInput> = serde_yaml::from_str(input)?;
+) -> Result<ThroughputHistory, ThroughputYamlError> {
+    let value: serde_yaml::Value = serde_yaml::from_str(input)?;
+    let (bucket, records) = match value {
+        serde_yaml::Value::Sequence(_) => (ThroughputBucket::Day, serde_yaml::from_value(value)?),
+        _ => {
+            let file: BucketedThroughputRecordInput = serde_yaml::from_value(value)?;
+            let bucket = match file.bucket {
+                BucketRecord::Day => ThroughputBucket::Day,
+                BucketRecord::Week => ThroughputBucket::Week,
+                BucketRecord::Sprint => match (file.sprint_start, file.sprint_length_days) {
+                    (Some(start), Some(length_days)) if length_days > 0 => {
+                        ThroughputBucket::Sprint {
+                            start: parse_date(&start)?,
+                            length_days,
+                        }
+                    }
+                    _ => return Err(ThroughputYamlError::InvalidSprint),
+                },
+            };
+            (bucket, file.throughput)
+        }
+    };
+
     let mut result = Vec::with_capacity(records.len());
     for record in records {
-        let date = chrono::NaiveDate::parse_from_str(&record.date, "%Y-%m-%d")
-            .map_err(|_| ThroughputYamlError::InvalidDate(record.date.clone()))?;
         result.push(Throughput {
-            date,
+            date: parse_date(&record.date)?,
             completed_issues: record.completed_issues,
+            capacity: record.capacity,
         });
     }
-    Ok(result)
+    Ok(ThroughputHistory {
+        bucket,
+        throughput: result,
+    })
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate, ThroughputYamlError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| ThroughputYamlError::InvalidDate(value.to_string()))
 }
 
 #[cfg(test)]
@@ -67,19 +150,30 @@ mod tests {
             Throughput {
                 date: NaiveDate::from_ymd_opt(2026, 2, 9).unwrap(),
                 completed_issues: 5,
+                capacity: None,
             },
             Throughput {
                 date: NaiveDate::from_ymd_opt(2026, 2, 10).unwrap(),
                 completed_issues: 3,
+                capacity: Some(0.5),
             },
         ];
         let mut buf = Vec::new();
-        serialize_throughput_to_yaml(&mut buf, &data).unwrap();
+        serialize_throughput_to_yaml(
+            &mut buf,
+            &ThroughputHistory {
+                bucket: ThroughputBucket::Day,
+                throughput: data,
+            },
+        )
+        .unwrap();
         let output = String::from_utf8(buf).unwrap();
         assert!(output.contains("2026-02-09"));
         assert!(output.contains("completed_issues: 5"));
         assert!(output.contains("2026-02-10"));
         assert!(output.contains("completed_issues: 3"));
+        assert!(output.contains("capacity: 0.5"));
+        assert_eq!(output.matches("capacity").count(), 1);
     }
 
     #[test]
@@ -88,8 +182,11 @@ mod tests {
   completed_issues: 5
 - date: 2026-02-10
   completed_issues: 3
+  capacity: 0.5
 "#;
-        let result = deserialize_throughput_from_yaml_str(yaml).unwrap();
+        let history = deserialize_throughput_from_yaml_str(yaml).unwrap();
+        assert_eq!(history.bucket, ThroughputBucket::Day);
+        let result = history.throughput;
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].date, NaiveDate::from_ymd_opt(2026, 2, 9).unwrap());
         assert_eq!(result[0].completed_issues, 5);
@@ -98,5 +195,41 @@ mod tests {
             NaiveDate::from_ymd_opt(2026, 2, 10).unwrap()
         );
         assert_eq!(result[1].completed_issues, 3);
+        assert_eq!(result[0].capacity, None);
+        assert_eq!(result[1].capacity, Some(0.5));
+    }
+
+    #[test]
+    fn sprint_throughput_survives_a_yaml_round_trip() {
+        let history = ThroughputHistory {
+            bucket: ThroughputBucket::Sprint {
+                start: NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+                length_days: 14,
+            },
+            throughput: vec![Throughput {
+                date: NaiveDate::from_ymd_opt(2026, 1, 19).unwrap(),
+                completed_issues: 12,
+                capacity: Some(9.5),
+            }],
+        };
+
+        let mut buf = Vec::new();
+        serialize_throughput_to_yaml(&mut buf, &history).unwrap();
+        let yaml = String::from_utf8(buf).unwrap();
+
+        assert!(yaml.starts_with("bucket: sprint\nsprint_start: 2026-01-05\n"));
+        assert_eq!(
+            deserialize_throughput_from_yaml_str(&yaml).unwrap(),
+            history
+        );
+    }
+
+    #[test]
+    fn sprint_bucket_without_length_is_rejected() {
+        let yaml = "bucket: sprint\nsprint_start: 2026-01-05\nthroughput: []\n";
+
+        let error = deserialize_throughput_from_yaml_str(yaml).unwrap_err();
+
+        assert!(matches!(error, ThroughputYamlError::InvalidSprint));
     }
 }
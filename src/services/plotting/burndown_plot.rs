@@ -15,6 +15,7 @@ use crate::services::parsing::team_calendar_yaml::{
     TeamCalendarYamlError, load_team_calendar_if_provided,
 };
 use crate::services::plotting::burndown_plot_rendering::render_burndown_plot_png;
+use crate::services::project_simulation::report_percentiles::MissingPercentile;
 use crate::services::project_simulation::simulation_types::{
     SimulationReport, WorkPackageSimulation,
 };
@@ -37,6 +38,11 @@ pub enum BurndownPlotError {
     ParseCalendar(#[from] TeamCalendarYamlError),
     #[error("simulation report has no entry for issue '{id}'")]
     MissingSimulationForIssue { id: String },
+    #[error("issue '{id}': {source}")]
+    MissingPercentile {
+        id: String,
+        source: MissingPercentile,
+    },
     #[error("issue '{id}' has unsupported estimate type for burndown")]
     UnsupportedEstimateType { id: String },
     #[error("failed to render burndown plot: {0}")]
@@ -120,11 +126,21 @@ fn build_burndown_data(
         let simulation = simulation_by_id
             .get(id.as_str())
             .ok_or_else(|| BurndownPlotError::MissingSimulationForIssue { id: id.clone() })?;
+        let end_date = |percentile: f64| {
+            simulation
+                .percentiles
+                .require(percentile)
+                .map(|value| value.end_date)
+                .map_err(|source| BurndownPlotError::MissingPercentile {
+                    id: id.clone(),
+                    source,
+                })
+        };
         forecast_issues.push(ForecastIssue {
             points,
-            p15: simulation.percentiles.p15.end_date,
-            p50: simulation.percentiles.p50.end_date,
-            p85: simulation.percentiles.p85.end_date,
+            p15: end_date(15.0)?,
+            p50: end_date(50.0)?,
+            p85: end_date(85.0)?,
         });
     }
 
@@ -32,7 +32,7 @@ pub(super) fn render_burndown_plot_png(
         .configure_mesh()
         .x_desc("Date")
         .y_desc("Remaining story points")
-        .x_labels((x_max as usize).min(10).max(1))
+        .x_labels((x_max as usize).clamp(1, 10))
         .x_label_formatter(&|x| {
             (data.start_date + Duration::days(*x as i64))
                 .format("%Y-%m-%d")
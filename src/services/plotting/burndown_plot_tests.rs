@@ -3,9 +3,7 @@ use super::*;
 use crate::domain::calendar::{Calendar, FreeDateRange, TeamCalendar};
 use crate::domain::estimate::ThreePointEstimate;
 use crate::domain::issue::IssueId;
-use crate::services::project_simulation::simulation_types::{
-    SimulationPercentile, WorkPackagePercentiles,
-};
+use crate::services::project_simulation::simulation_types::SimulationPercentile;
 
 use assert_fs::prelude::*;
 use chrono::NaiveDate;
@@ -31,13 +29,17 @@ fn simulation_for(
     WorkPackageSimulation {
         id: id.to_string(),
         is_milestone: false,
-        percentiles: WorkPackagePercentiles {
-            p0: percentile(p15),
-            p15: percentile(p15),
-            p50: percentile(p50),
-            p85: percentile(p85),
-            p100: percentile(p85),
-        },
+        percentiles: [
+            (0.0, percentile(p15)),
+            (15.0, percentile(p15)),
+            (50.0, percentile(p50)),
+            (85.0, percentile(p85)),
+            (100.0, percentile(p85)),
+        ]
+        .into_iter()
+        .collect(),
+        criticality_index: 0.0,
+        duration_correlation: 0.0,
     }
 }
 
@@ -47,13 +49,24 @@ fn base_report(work_packages: Option<Vec<WorkPackageSimulation>>) -> SimulationR
         start_date: on_date(2026, 3, 1),
         velocity: Some(1.0),
         iterations: 10,
+        seed: None,
         simulated_items: 2,
-        p0: percentile(on_date(2026, 3, 2)),
-        p15: percentile(on_date(2026, 3, 2)),
-        p50: percentile(on_date(2026, 3, 3)),
-        p85: percentile(on_date(2026, 3, 4)),
-        p100: percentile(on_date(2026, 3, 5)),
+        percentiles: [
+            (0.0, percentile(on_date(2026, 3, 2))),
+            (15.0, percentile(on_date(2026, 3, 2))),
+            (50.0, percentile(on_date(2026, 3, 3))),
+            (85.0, percentile(on_date(2026, 3, 4))),
+            (100.0, percentile(on_date(2026, 3, 5))),
+        ]
+        .into_iter()
+        .collect(),
         work_packages,
+        deadlines: None,
+        sample_window: None,
+        scope_growth: None,
+        correlation: None,
+        risks: None,
+        constraints: None,
     }
 }
 
@@ -77,6 +90,7 @@ fn build_data_converts_none_estimate_to_one_story_point() {
     let project = Project {
         name: "Demo".to_string(),
         work_packages: vec![done, todo],
+        risks: Vec::new(),
     };
     let report = base_report(Some(vec![simulation_for(
         "TODO-1",
@@ -104,11 +118,13 @@ fn build_data_rejects_non_story_point_estimates() {
         optimistic: Some(1.0),
         most_likely: Some(2.0),
         pessimistic: Some(3.0),
+        distribution: None,
     }));
 
     let project = Project {
         name: "Demo".to_string(),
         work_packages: vec![done],
+        risks: Vec::new(),
     };
     let report = base_report(Some(vec![]));
 
@@ -142,6 +158,7 @@ fn build_data_requires_done_date_for_done_issues() {
     let project = Project {
         name: "Demo".to_string(),
         work_packages: vec![done, todo],
+        risks: Vec::new(),
     };
     let report = base_report(Some(vec![simulation_for(
         "TODO-1",
@@ -168,6 +185,7 @@ fn build_data_requires_at_least_one_done_issue() {
     let project = Project {
         name: "Demo".to_string(),
         work_packages: vec![todo],
+        risks: Vec::new(),
     };
     let report = base_report(Some(vec![simulation_for(
         "TODO-1",
@@ -204,6 +222,7 @@ fn build_data_requires_simulation_for_not_done_issue() {
     let project = Project {
         name: "Demo".to_string(),
         work_packages: vec![done, todo],
+        risks: Vec::new(),
     };
     let report = base_report(Some(vec![]));
 
@@ -238,6 +257,7 @@ fn build_data_collects_low_capacity_ranges_from_calendar_ignoring_free_weekdays(
     let project = Project {
         name: "Demo".to_string(),
         work_packages: vec![done, todo],
+        risks: Vec::new(),
     };
     let report = base_report(Some(vec![simulation_for(
         "TODO-1",
@@ -137,6 +137,7 @@ mod tests {
             latest_finish: earliest_finish,
             free_float: 0.0,
             total_float,
+            constraint_violated: false,
         }
     }
 
@@ -155,6 +156,7 @@ mod tests {
         let project = Project {
             name: "TestProject".to_string(),
             work_packages: vec![build_issue("WP1", "Design")],
+            risks: Vec::new(),
         };
         let nodes = vec![build_result_node(
             "WP1",
@@ -176,6 +178,7 @@ mod tests {
         let project = Project {
             name: "Test".to_string(),
             work_packages: vec![build_issue("T", "Zero Duration Task")],
+            risks: Vec::new(),
         };
         let nodes = vec![build_result_node(
             "T",
@@ -196,6 +199,7 @@ mod tests {
         let project = Project {
             name: "Test".to_string(),
             work_packages: vec![milestone_issue],
+            risks: Vec::new(),
         };
 
         let mut result_node = build_result_node("M", on_date(2026, 1, 5), on_date(2026, 1, 5), 0.0);
@@ -213,6 +217,7 @@ mod tests {
         let project = Project {
             name: "Test".to_string(),
             work_packages: vec![build_issue("A", "Task A"), build_issue("B", "Task B")],
+            risks: Vec::new(),
         };
         let nodes = vec![
             build_result_node("A", on_date(2026, 1, 1), on_date(2026, 1, 4), 0.0),
@@ -236,6 +241,7 @@ mod tests {
         let project = Project {
             name: "Test".to_string(),
             work_packages: vec![issue],
+            risks: Vec::new(),
         };
         let nodes = vec![build_result_node(
             "X1",
@@ -259,6 +265,7 @@ mod tests {
                 build_issue("C", "Gamma"),
                 build_issue("FIN", "Finish"),
             ],
+            risks: Vec::new(),
         };
         let nodes = vec![
             build_result_node("A", on_date(2026, 1, 1), on_date(2026, 1, 6), 0.0),
@@ -8,10 +8,21 @@ pub enum HistogramError {
 }
 
 pub fn write_histogram_png(output_path: &str, results: &[f32]) -> Result<(), HistogramError> {
-    render_histogram_png(output_path, results)
+    render_histogram_png(output_path, results, "Duration in days")
 }
 
-fn render_histogram_png(output_path: &str, results: &[f32]) -> Result<(), HistogramError> {
+pub fn write_item_count_histogram_png(
+    output_path: &str,
+    results: &[f32],
+) -> Result<(), HistogramError> {
+    render_histogram_png(output_path, results, "Finished items")
+}
+
+fn render_histogram_png(
+    output_path: &str,
+    results: &[f32],
+    x_desc: &str,
+) -> Result<(), HistogramError> {
     if results.is_empty() {
         return Ok(());
     }
@@ -40,7 +51,7 @@ fn render_histogram_png(output_path: &str, results: &[f32]) -> Result<(), Histog
 
     let min_bucket = (*counts.keys().next().unwrap_or(&0)) - 1;
     let max_bucket = (*counts.keys().next_back().unwrap_or(&0)) + 1;
-    let max_x = if max_value - min_value < f32::EPSILON {
+    let max_x = if (max_value - min_value).abs() < f32::EPSILON {
         min_bucket + 1
     } else {
         max_bucket
@@ -56,7 +67,7 @@ fn render_histogram_png(output_path: &str, results: &[f32]) -> Result<(), Histog
     chart
         .configure_mesh()
         .disable_mesh()
-        .x_desc("Duration in days")
+        .x_desc(x_desc)
         .y_desc("Frequency")
         .label_style(("sans-serif", 18))
         .axis_desc_style(("sans-serif", 22))
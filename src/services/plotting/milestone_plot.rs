@@ -21,13 +21,7 @@ pub fn write_milestone_plot_png(
     simulation: &SimulationOutput,
 ) -> Result<(), MilestonePlotError> {
     let mut milestones = collect_milestones(&simulation.report)?;
-    milestones.sort_by(|a, b| {
-        a.percentiles
-            .p50
-            .end_date
-            .partial_cmp(&b.percentiles.p50.end_date)
-            .unwrap()
-    });
+    milestones.sort_by_key(|item| item.percentiles.get(50.0).map(|p50| p50.end_date));
     render_milestone_plot_png(output_path, &milestones)
 }
 
@@ -74,7 +68,7 @@ fn render_milestone_plot_png(
 ) -> Result<(), MilestonePlotError> {
     let max_days = milestones
         .iter()
-        .map(|item| item.percentiles.p100.days)
+        .flat_map(|item| item.percentiles.iter().map(|(_, value)| value.days))
         .fold(0.0_f32, f32::max);
     let x_max = if max_days <= 0.0 {
         1.0
@@ -112,13 +106,11 @@ fn render_milestone_plot_png(
     for (idx, item) in milestones.iter().enumerate() {
         let y_coord = idx as f32 + 0.5;
 
-        let points = vec![
-            (item.percentiles.p0.days, y_coord),
-            (item.percentiles.p15.days, y_coord),
-            (item.percentiles.p50.days, y_coord),
-            (item.percentiles.p85.days, y_coord),
-            (item.percentiles.p100.days, y_coord),
-        ];
+        let points: Vec<(f32, f32)> = item
+            .percentiles
+            .iter()
+            .map(|(_, value)| (value.days, y_coord))
+            .collect();
 
         series.insert(item.id.clone(), points);
     }
@@ -147,7 +139,7 @@ fn render_milestone_plot_png(
 mod tests {
     use super::*;
     use crate::services::project_simulation::simulation_types::{
-        SimulationPercentile, SimulationReport, WorkPackagePercentiles,
+        SimulationPercentile, SimulationReport,
     };
     use assert_fs::prelude::*;
     use chrono::NaiveDate;
@@ -167,13 +159,24 @@ mod tests {
             start_date,
             velocity: Some(3.0),
             iterations: 100,
+            seed: None,
             simulated_items: 4,
-            p0: percentile(4.0, 2026, 3, 5),
-            p15: percentile(6.0, 2026, 3, 7),
-            p50: percentile(10.0, 2026, 3, 11),
-            p85: percentile(13.0, 2026, 3, 14),
-            p100: percentile(17.0, 2026, 3, 18),
+            percentiles: [
+                (0.0, percentile(4.0, 2026, 3, 5)),
+                (15.0, percentile(6.0, 2026, 3, 7)),
+                (50.0, percentile(10.0, 2026, 3, 11)),
+                (85.0, percentile(13.0, 2026, 3, 14)),
+                (100.0, percentile(17.0, 2026, 3, 18)),
+            ]
+            .into_iter()
+            .collect(),
             work_packages,
+            deadlines: None,
+            sample_window: None,
+            scope_growth: None,
+            correlation: None,
+            risks: None,
+            constraints: None,
         };
 
         SimulationOutput {
@@ -194,13 +197,17 @@ mod tests {
         WorkPackageSimulation {
             id: id.to_string(),
             is_milestone,
-            percentiles: WorkPackagePercentiles {
-                p0: percentile(p0, 2026, 3, 1),
-                p15: percentile(p15, 2026, 3, 2),
-                p50: percentile(p50, 2026, 3, 3),
-                p85: percentile(p85, 2026, 3, 4),
-                p100: percentile(p100, 2026, 3, 5),
-            },
+            percentiles: [
+                (0.0, percentile(p0, 2026, 3, 1)),
+                (15.0, percentile(p15, 2026, 3, 2)),
+                (50.0, percentile(p50, 2026, 3, 3)),
+                (85.0, percentile(p85, 2026, 3, 4)),
+                (100.0, percentile(p100, 2026, 3, 5)),
+            ]
+            .into_iter()
+            .collect(),
+            criticality_index: 0.0,
+            duration_correlation: 0.0,
         }
     }
 
@@ -4,5 +4,7 @@ pub(crate) mod estimate_gantt;
 pub(crate) mod histogram;
 pub(crate) mod milestone_plot;
 pub(crate) mod project_flow_diagram;
+pub(crate) mod risk_list;
 pub(crate) mod simulation_gantt;
 pub(crate) mod throughput_plot;
+pub(crate) mod tornado_chart;
@@ -0,0 +1,118 @@
+use std::io;
+
+use thiserror::Error;
+
+use crate::services::parsing::simulation_report_yaml::{
+    ReportParseError, load_simulation_report_from_file,
+};
+use crate::services::project_simulation::simulation_types::{RiskForecast, SimulationReport};
+
+#[derive(Error, Debug)]
+pub enum RiskListError {
+    #[error("failed to load simulation report: {0}")]
+    ReportLoad(#[from] ReportParseError),
+    #[error("failed to write output: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// Loads a simulation report YAML and writes its risks as a Markdown table,
+/// ready to be pasted into a project readme.
+pub fn write_risk_list_markdown(report_path: &str, output_path: &str) -> Result<(), RiskListError> {
+    let report = load_simulation_report_from_file(report_path)?;
+    std::fs::write(output_path, generate_risk_list_markdown(&report))?;
+    Ok(())
+}
+
+/// Lists the risks of a simulation report, the ones adding the most days to
+/// P85 first.
+pub fn generate_risk_list_markdown(report: &SimulationReport) -> String {
+    let mut risks: Vec<&RiskForecast> = report.risks.iter().flatten().collect();
+    risks.sort_by(|a, b| b.p85_contribution_days.total_cmp(&a.p85_contribution_days));
+
+    let mut lines = vec!["# List of risks".to_string(), String::new()];
+    if risks.is_empty() {
+        lines.push("The project has no risks.".to_string());
+    } else {
+        lines.push(
+            "| Risk | Description | Probability | Occurred | Added to P85 | Affects |".to_string(),
+        );
+        lines.push(
+            "|------|-------------|-------------|----------|--------------|---------|".to_string(),
+        );
+        for risk in risks {
+            lines.push(format!(
+                "| {id} | {description} | {probability:.0}% | {occurred:.1}% | {days:.1} days | {affects} |",
+                id = risk.id,
+                description = risk.description.as_deref().unwrap_or(""),
+                probability = risk.probability * 100.0,
+                occurred = risk.occurrence_rate * 100.0,
+                days = risk.p85_contribution_days,
+                affects = risk.affects.join(", "),
+            ));
+        }
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::project_simulation::report_percentiles::Percentiles;
+    use chrono::NaiveDate;
+
+    fn risk(id: &str, description: Option<&str>, days: f32) -> RiskForecast {
+        RiskForecast {
+            id: id.to_string(),
+            description: description.map(str::to_string),
+            probability: 0.3,
+            occurrence_rate: 0.295,
+            affects: vec!["WP1".to_string(), "WP2".to_string()],
+            p85_contribution_days: days,
+        }
+    }
+
+    fn report(risks: Option<Vec<RiskForecast>>) -> SimulationReport {
+        SimulationReport {
+            data_source: "project.yaml".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            velocity: None,
+            iterations: 1000,
+            seed: None,
+            simulated_items: 2,
+            percentiles: Percentiles::default(),
+            work_packages: None,
+            deadlines: None,
+            sample_window: None,
+            scope_growth: None,
+            correlation: None,
+            risks,
+            constraints: None,
+        }
+    }
+
+    #[test]
+    fn risks_are_listed_by_their_p85_contribution() {
+        let markdown = generate_risk_list_markdown(&report(Some(vec![
+            risk("R1", None, 0.5),
+            risk("R2", Some("Supplier delivers late"), 4.0),
+        ])));
+
+        assert_eq!(
+            markdown,
+            "# List of risks\n\
+             \n\
+             | Risk | Description | Probability | Occurred | Added to P85 | Affects |\n\
+             |------|-------------|-------------|----------|--------------|---------|\n\
+             | R2 | Supplier delivers late | 30% | 29.5% | 4.0 days | WP1, WP2 |\n\
+             | R1 |  | 30% | 29.5% | 0.5 days | WP1, WP2 |\n"
+        );
+    }
+
+    #[test]
+    fn reports_without_risks_say_so() {
+        let markdown = generate_risk_list_markdown(&report(None));
+
+        assert!(markdown.contains("The project has no risks."));
+    }
+}
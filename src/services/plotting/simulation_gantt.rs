@@ -11,6 +11,7 @@ use crate::services::parsing::project_yaml::{ProjectYamlError, load_project_from
 use crate::services::parsing::simulation_report_yaml::{
     ReportParseError, load_simulation_report_from_file,
 };
+use crate::services::project_simulation::report_percentiles::MissingPercentile;
 use crate::services::project_simulation::simulation_types::{
     SimulationReport, WorkPackageSimulation,
 };
@@ -23,6 +24,11 @@ pub enum SimulationGanttError {
     ReportLoad(#[from] ReportParseError),
     #[error("failed to write output: {0}")]
     Io(#[from] io::Error),
+    #[error("work package '{issue_id}': {source}")]
+    MissingPercentile {
+        issue_id: String,
+        source: MissingPercentile,
+    },
     #[error(
         "work package '{issue_id}' has status '{status:?}' but no start_date; set start_date as YYYY-MM-DD"
     )]
@@ -92,7 +98,7 @@ pub fn generate_simulation_gantt_markdown(
         let summary = summary_by_id.get(id).copied().unwrap_or(id);
         let label = format!("{} {}", id, summary);
         let start = compute_start_date(wp_sim, project, &wp_sim_by_id, report.start_date)?;
-        let end = wp_sim.percentiles.p85.end_date;
+        let end = p85_end_date(wp_sim)?;
         let status = get_issue_by_simulation(wp_sim, project)
             .and_then(|issue| issue.status.as_ref());
         
@@ -128,18 +134,18 @@ fn compute_start_date(
 ) -> Result<NaiveDate, SimulationGanttError> {
     let issue = get_issue_by_simulation(wp_sim, project);
 
-    if let Some(issue) = issue {
-        if let Some(status) = issue.status.as_ref() {
-            if matches!(status, IssueStatus::Done | IssueStatus::InProgress) {
-                let start_date = issue.start_date.ok_or_else(|| {
-                    SimulationGanttError::MissingStartDateForStatus {
-                        issue_id: wp_sim.id.clone(),
-                        status: status.clone(),
-                    }
+    if let Some(issue) = issue
+        && let Some(status) = issue.status.as_ref()
+        && matches!(status, IssueStatus::Done | IssueStatus::InProgress)
+    {
+        let start_date =
+            issue
+                .start_date
+                .ok_or_else(|| SimulationGanttError::MissingStartDateForStatus {
+                    issue_id: wp_sim.id.clone(),
+                    status: status.clone(),
                 })?;
-                return Ok(start_date);
-            }
-        }
+        return Ok(start_date);
     }
 
     let deps = issue.and_then(|wp| wp.dependencies.as_deref());
@@ -148,12 +154,25 @@ fn compute_start_date(
         return Ok(default_date);
     };
 
-    Ok(deps
+    let mut start_date = None;
+    for dep_wp in deps
         .iter()
         .filter_map(|dep_id| wp_sim_by_id.get(dep_id.id.as_str()))
-        .map(|dep_wp| dep_wp.percentiles.p85.end_date)
-        .max()
-        .unwrap_or(default_date))
+    {
+        start_date = start_date.max(Some(p85_end_date(dep_wp)?));
+    }
+    Ok(start_date.unwrap_or(default_date))
+}
+
+fn p85_end_date(wp_sim: &WorkPackageSimulation) -> Result<NaiveDate, SimulationGanttError> {
+    wp_sim
+        .percentiles
+        .require(85.0)
+        .map(|p85| p85.end_date)
+        .map_err(|source| SimulationGanttError::MissingPercentile {
+            issue_id: wp_sim.id.clone(),
+            source,
+        })
 }
 
 fn get_issue_by_simulation<'a>(
@@ -171,8 +190,9 @@ mod tests {
     use super::*;
     use crate::domain::issue::{Issue, IssueId};
     use crate::domain::issue_status::IssueStatus;
+    use crate::services::project_simulation::report_percentiles::Percentiles;
     use crate::services::project_simulation::simulation_types::{
-        SimulationPercentile, WorkPackagePercentiles, WorkPackageSimulation,
+        SimulationPercentile, WorkPackageSimulation,
     };
     use chrono::NaiveDate;
 
@@ -187,14 +207,16 @@ mod tests {
         }
     }
 
-    fn wp_percentiles(end: &str) -> WorkPackagePercentiles {
-        WorkPackagePercentiles {
-            p0: percentile(end),
-            p15: percentile(end),
-            p50: percentile(end),
-            p85: percentile(end),
-            p100: percentile(end),
-        }
+    fn wp_percentiles(end: &str) -> Percentiles {
+        [
+            (0.0, percentile(end)),
+            (15.0, percentile(end)),
+            (50.0, percentile(end)),
+            (85.0, percentile(end)),
+            (100.0, percentile(end)),
+        ]
+        .into_iter()
+        .collect()
     }
 
     fn build_report(start: &str, work_packages: Vec<WorkPackageSimulation>) -> SimulationReport {
@@ -203,13 +225,24 @@ mod tests {
             start_date: date(start),
             velocity: None,
             iterations: 100,
+            seed: None,
             simulated_items: work_packages.len(),
-            p0: percentile(start),
-            p15: percentile(start),
-            p50: percentile(start),
-            p85: percentile(start),
-            p100: percentile(start),
+            percentiles: [
+                (0.0, percentile(start)),
+                (15.0, percentile(start)),
+                (50.0, percentile(start)),
+                (85.0, percentile(start)),
+                (100.0, percentile(start)),
+            ]
+            .into_iter()
+            .collect(),
             work_packages: Some(work_packages),
+            deadlines: None,
+            sample_window: None,
+            scope_growth: None,
+            correlation: None,
+            risks: None,
+            constraints: None,
         }
     }
 
@@ -229,6 +262,7 @@ mod tests {
         Project {
             name: name.to_string(),
             work_packages: issues,
+            risks: Vec::new(),
         }
     }
 
@@ -253,6 +287,8 @@ mod tests {
                 id: "WP1".to_string(),
                 is_milestone: false,
                 percentiles: wp_percentiles("2026-01-10"),
+                criticality_index: 0.0,
+                duration_correlation: 0.0,
             }],
         );
         let project = build_project("Demo", vec![build_issue("WP1", "Design", None)]);
@@ -272,11 +308,15 @@ mod tests {
                     id: "WP1".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-10"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
                 WorkPackageSimulation {
                     id: "WP2".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-20"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
             ],
         );
@@ -303,16 +343,22 @@ mod tests {
                     id: "WP1".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-05"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
                 WorkPackageSimulation {
                     id: "WP2".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-15"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
                 WorkPackageSimulation {
                     id: "WP3".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-25"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
             ],
         );
@@ -341,11 +387,15 @@ mod tests {
                     id: "WP1".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-10"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
                 WorkPackageSimulation {
                     id: "MS1".to_string(),
                     is_milestone: true,
                     percentiles: wp_percentiles("2026-01-10"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
             ],
         );
@@ -371,6 +421,8 @@ mod tests {
                 id: "WP1".to_string(),
                 is_milestone: false,
                 percentiles: wp_percentiles("2026-01-10"),
+                criticality_index: 0.0,
+                duration_correlation: 0.0,
             }],
         );
         let project = build_project("MyProject", vec![build_issue("WP1", "My Summary", None)]);
@@ -389,6 +441,8 @@ mod tests {
                 id: "WP2".to_string(),
                 is_milestone: false,
                 percentiles: wp_percentiles("2026-01-20"),
+                criticality_index: 0.0,
+                duration_correlation: 0.0,
             }],
         );
         let project = build_project(
@@ -413,6 +467,8 @@ mod tests {
                 id: "WP1".to_string(),
                 is_milestone: false,
                 percentiles: wp_percentiles("2026-01-10"),
+                criticality_index: 0.0,
+                duration_correlation: 0.0,
             }],
         );
 
@@ -447,16 +503,22 @@ mod tests {
                     id: "WP1".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-20"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
                 WorkPackageSimulation {
                     id: "WP2".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-25"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
                 WorkPackageSimulation {
                     id: "WP3".to_string(),
                     is_milestone: false,
                     percentiles: wp_percentiles("2026-01-30"),
+                    criticality_index: 0.0,
+                    duration_correlation: 0.0,
                 },
             ],
         );
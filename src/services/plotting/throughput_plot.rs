@@ -1,7 +1,11 @@
 use crate::domain::throughput::Throughput;
+use crate::services::parsing::simulation_report_yaml::{
+    ReportParseError, load_simulation_report_from_file,
+};
 use crate::services::parsing::throughput_yaml::{
     ThroughputYamlError, deserialize_throughput_from_yaml_str,
 };
+use crate::services::project_simulation::simulation_types::SampleWindow;
 use plotters::prelude::*;
 use thiserror::Error;
 
@@ -13,30 +17,63 @@ pub enum ThroughputPlotError {
     ParseThroughput(#[from] ThroughputYamlError),
     #[error("throughput data is empty")]
     EmptyThroughput,
+    #[error("failed to parse simulation report yaml: {0}")]
+    ParseReport(#[from] ReportParseError),
+    #[error("simulation report has no sample window")]
+    MissingSampleWindow,
     #[error("failed to render throughput plot: {0}")]
     Plot(String),
 }
 
+/// Plots the throughput history; with a simulation report, the values its
+/// simulation sampled from are highlighted.
 pub fn plot_throughput_from_yaml_file(
     input_path: &str,
     output_path: &str,
+    report_path: Option<&str>,
 ) -> Result<(), ThroughputPlotError> {
     let throughput_yaml = std::fs::read_to_string(input_path)?;
-    let throughput = deserialize_throughput_from_yaml_str(&throughput_yaml)?;
+    let throughput = deserialize_throughput_from_yaml_str(&throughput_yaml)?.throughput;
     if throughput.is_empty() {
         return Err(ThroughputPlotError::EmptyThroughput);
     }
-    write_plot_png(output_path, &throughput)?;
+    let window = match report_path {
+        Some(report_path) => Some(
+            load_simulation_report_from_file(report_path)?
+                .sample_window
+                .ok_or(ThroughputPlotError::MissingSampleWindow)?,
+        ),
+        None => None,
+    };
+    write_plot_png(output_path, &throughput, window.as_ref())?;
     Ok(())
 }
 
-fn write_plot_png(output_path: &str, throughput: &[Throughput]) -> Result<(), ThroughputPlotError> {
-    render_plot_png(output_path, throughput)
+fn write_plot_png(
+    output_path: &str,
+    throughput: &[Throughput],
+    window: Option<&SampleWindow>,
+) -> Result<(), ThroughputPlotError> {
+    render_plot_png(output_path, throughput, window)
+}
+
+/// Index range of the bars inside the sample window.
+fn window_bars(throughput: &[Throughput], window: &SampleWindow) -> std::ops::Range<usize> {
+    let start = throughput
+        .iter()
+        .position(|item| item.date >= window.start_date)
+        .unwrap_or(throughput.len());
+    let end = throughput
+        .iter()
+        .rposition(|item| item.date <= window.end_date)
+        .map_or(start, |idx| (idx + 1).max(start));
+    start..end
 }
 
 fn render_plot_png(
     output_path: &str,
     throughput: &[Throughput],
+    window: Option<&SampleWindow>,
 ) -> Result<(), ThroughputPlotError> {
     if throughput.is_empty() {
         return Ok(());
@@ -62,7 +99,7 @@ fn render_plot_png(
         .build_cartesian_2d(0..max_x, 0..max_y)
         .map_err(|e| ThroughputPlotError::Plot(e.to_string()))?;
 
-    let label_count = throughput.len().min(10).max(1);
+    let label_count = throughput.len().clamp(1, 10);
     chart
         .configure_mesh()
         .disable_mesh()
@@ -86,14 +123,32 @@ fn render_plot_png(
 
     let bar_color = RGBColor(30, 122, 204);
     let bar_style = ShapeStyle::from(&bar_color).filled().stroke_width(1);
+    // Outside a sample window, bars are greyed out.
+    let unsampled_style = ShapeStyle::from(&RGBColor(170, 178, 189))
+        .filled()
+        .stroke_width(1);
+    let sampled = window.map(|window| window_bars(throughput, window));
+    if let Some(sampled) = sampled.clone().filter(|range| !range.is_empty()) {
+        let shade = RGBColor(255, 236, 179);
+        chart
+            .draw_series(std::iter::once(Rectangle::new(
+                [(sampled.start as i32, 0), (sampled.end as i32, max_y)],
+                ShapeStyle::from(&shade).filled(),
+            )))
+            .map_err(|e| ThroughputPlotError::Plot(e.to_string()))?;
+    }
     chart
         .draw_series(throughput.iter().enumerate().map(|(idx, item)| {
+            let style = match &sampled {
+                Some(sampled) if !sampled.contains(&idx) => unsampled_style,
+                _ => bar_style,
+            };
             Rectangle::new(
                 [
                     (idx as i32, 0),
                     (idx as i32 + 1, item.completed_issues as i32),
                 ],
-                bar_style.clone(),
+                style,
             )
         }))
         .map_err(|e| ThroughputPlotError::Plot(e.to_string()))?;
@@ -120,6 +175,7 @@ mod tests {
         plot_throughput_from_yaml_file(
             input_file.path().to_str().unwrap(),
             output_file.path().to_str().unwrap(),
+            None,
         )
         .unwrap();
 
@@ -137,9 +193,54 @@ mod tests {
         let error = plot_throughput_from_yaml_file(
             input_file.path().to_str().unwrap(),
             output_file.path().to_str().unwrap(),
+            None,
         )
         .expect_err("expected empty throughput error");
 
         assert!(matches!(error, ThroughputPlotError::EmptyThroughput));
     }
+
+    #[test]
+    fn window_bars_covers_values_inside_the_window() {
+        let date = |day| chrono::NaiveDate::from_ymd_opt(2026, 1, day).unwrap();
+        let throughput: Vec<Throughput> = (5..10)
+            .map(|day| Throughput {
+                date: date(day),
+                completed_issues: 1,
+                capacity: None,
+            })
+            .collect();
+        let window = SampleWindow {
+            start_date: date(7),
+            end_date: date(9),
+            samples: 3,
+            half_life_days: None,
+        };
+
+        assert_eq!(window_bars(&throughput, &window), 2..5);
+    }
+
+    #[test]
+    fn plot_throughput_from_yaml_file_highlights_sample_window() {
+        let input_file = assert_fs::NamedTempFile::new("throughput.yaml").unwrap();
+        input_file
+            .write_str("- date: 2026-01-26\n  completed_issues: 2\n- date: 2026-01-27\n  completed_issues: 1\n")
+            .unwrap();
+        let report_file = assert_fs::NamedTempFile::new("report.yaml").unwrap();
+        report_file
+            .write_str(
+                "data_source: throughput.yaml\nstart_date: 2026-02-02\nvelocity: null\niterations: 10\nsimulated_items: 5\npercentiles: []\nsample_window:\n  start_date: 2026-01-27\n  end_date: 2026-01-27\n  samples: 1\n",
+            )
+            .unwrap();
+        let output_file = assert_fs::NamedTempFile::new("throughput.png").unwrap();
+
+        plot_throughput_from_yaml_file(
+            input_file.path().to_str().unwrap(),
+            output_file.path().to_str().unwrap(),
+            Some(report_file.path().to_str().unwrap()),
+        )
+        .unwrap();
+
+        output_file.assert(predicate::path::exists());
+    }
 }
@@ -0,0 +1,218 @@
+use crate::services::project_simulation::simulation_types::{
+    SimulationReport, WorkPackageSimulation,
+};
+use plotters::prelude::*;
+use thiserror::Error;
+
+/// Number of work packages shown; the chart gets unreadable beyond that.
+const MAX_BARS: usize = 20;
+
+#[derive(Error, Debug)]
+pub enum TornadoChartError {
+    #[error("simulation report has no work package data")]
+    NoWorkPackages,
+    #[error("failed to render tornado chart: {0}")]
+    Plot(String),
+}
+
+/// Draws the duration correlation of every work package as a horizontal bar,
+/// with the most influential package on top. The labels also show the
+/// criticality index.
+pub fn write_tornado_chart_png(
+    output_path: &str,
+    report: &SimulationReport,
+) -> Result<(), TornadoChartError> {
+    let bars = collect_bars(report)?;
+    render_tornado_chart_png(output_path, &bars)
+}
+
+fn collect_bars(
+    report: &SimulationReport,
+) -> Result<Vec<&WorkPackageSimulation>, TornadoChartError> {
+    let work_packages = report
+        .work_packages
+        .as_ref()
+        .ok_or(TornadoChartError::NoWorkPackages)?;
+
+    let mut bars: Vec<&WorkPackageSimulation> = work_packages
+        .iter()
+        .filter(|item| !item.is_milestone)
+        .collect();
+    if bars.is_empty() {
+        return Err(TornadoChartError::NoWorkPackages);
+    }
+
+    bars.sort_by(|a, b| {
+        b.duration_correlation
+            .abs()
+            .total_cmp(&a.duration_correlation.abs())
+    });
+    bars.truncate(MAX_BARS);
+    Ok(bars)
+}
+
+/// Bars are drawn from the top, so bar `i` is centered at `len - i - 0.5`.
+fn bar_label_for_y(value: f32, bars: &[&WorkPackageSimulation]) -> String {
+    const HALF_STEP: f32 = 0.5;
+    const EPSILON: f32 = 0.001;
+
+    let position = bars.len() as f32 - value - HALF_STEP;
+    let rounded = position.round();
+    if (position - rounded).abs() > EPSILON {
+        return String::new();
+    }
+
+    let idx = rounded as isize;
+    if idx < 0 || idx as usize >= bars.len() {
+        return String::new();
+    }
+
+    let bar = bars[idx as usize];
+    format!("{} ({:.0}%)", bar.id, bar.criticality_index * 100.0)
+}
+
+fn render_tornado_chart_png(
+    output_path: &str,
+    bars: &[&WorkPackageSimulation],
+) -> Result<(), TornadoChartError> {
+    let max_y = bars.len() as f32;
+
+    let root = BitMapBackend::new(output_path, (1200, 700)).into_drawing_area();
+    root.fill(&WHITE)
+        .map_err(|e| TornadoChartError::Plot(e.to_string()))?;
+
+    let mut chart = ChartBuilder::on(&root)
+        .margin(20)
+        .caption("Sensitivity Tornado Chart", ("sans-serif", 30))
+        .x_label_area_size(80)
+        .y_label_area_size(200)
+        .build_cartesian_2d(-1.0_f32..1.0_f32, 0.0_f32..max_y)
+        .map_err(|e| TornadoChartError::Plot(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .x_desc("Correlation of duration with project duration")
+        .y_desc("Work packages (criticality index)")
+        .label_style(("sans-serif", 16))
+        .axis_desc_style(("sans-serif", 20))
+        .y_labels(bars.len().saturating_mul(2).saturating_add(1))
+        .y_label_formatter(&|value| bar_label_for_y(*value, bars))
+        .draw()
+        .map_err(|e| TornadoChartError::Plot(e.to_string()))?;
+
+    chart
+        .draw_series(bars.iter().enumerate().map(|(idx, item)| {
+            let top = max_y - idx as f32 - 0.1;
+            let bottom = max_y - idx as f32 - 0.9;
+            let color = if item.duration_correlation >= 0.0 {
+                RED.mix(0.6)
+            } else {
+                BLUE.mix(0.6)
+            };
+            Rectangle::new(
+                [(0.0, bottom), (item.duration_correlation, top)],
+                color.filled(),
+            )
+        }))
+        .map_err(|e| TornadoChartError::Plot(e.to_string()))?;
+
+    root.present()
+        .map_err(|e| TornadoChartError::Plot(e.to_string()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::project_simulation::report_percentiles::Percentiles;
+    use assert_fs::prelude::*;
+    use chrono::NaiveDate;
+    use predicates::prelude::*;
+
+    fn build_wp(
+        id: &str,
+        is_milestone: bool,
+        criticality: f32,
+        correlation: f32,
+    ) -> WorkPackageSimulation {
+        WorkPackageSimulation {
+            id: id.to_string(),
+            is_milestone,
+            percentiles: Percentiles::default(),
+            criticality_index: criticality,
+            duration_correlation: correlation,
+        }
+    }
+
+    fn build_report(work_packages: Option<Vec<WorkPackageSimulation>>) -> SimulationReport {
+        SimulationReport {
+            data_source: "unit".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+            velocity: None,
+            iterations: 100,
+            seed: None,
+            simulated_items: 3,
+            percentiles: Percentiles::default(),
+            work_packages,
+            deadlines: None,
+            sample_window: None,
+            scope_growth: None,
+            correlation: None,
+            risks: None,
+            constraints: None,
+        }
+    }
+
+    #[test]
+    fn write_tornado_chart_png_writes_png() {
+        let output_file = assert_fs::NamedTempFile::new("tornado.png").unwrap();
+        let report = build_report(Some(vec![
+            build_wp("WP1", false, 0.9, 0.8),
+            build_wp("WP2", false, 0.1, -0.2),
+            build_wp("M1", true, 1.0, 0.0),
+        ]));
+
+        write_tornado_chart_png(output_file.path().to_str().unwrap(), &report).unwrap();
+
+        output_file.assert(predicate::path::exists());
+        assert!(std::fs::metadata(output_file.path()).unwrap().len() > 0);
+    }
+
+    #[test]
+    fn write_tornado_chart_png_rejects_missing_work_packages() {
+        let output_file = assert_fs::NamedTempFile::new("tornado-missing.png").unwrap();
+        let report = build_report(None);
+
+        let error =
+            write_tornado_chart_png(output_file.path().to_str().unwrap(), &report).unwrap_err();
+
+        assert!(matches!(error, TornadoChartError::NoWorkPackages));
+    }
+
+    #[test]
+    fn bars_are_sorted_by_absolute_correlation_without_milestones() {
+        let report = build_report(Some(vec![
+            build_wp("SMALL", false, 0.2, 0.1),
+            build_wp("M1", true, 1.0, 0.0),
+            build_wp("NEGATIVE", false, 0.0, -0.7),
+            build_wp("LARGE", false, 1.0, 0.9),
+        ]));
+
+        let bars = collect_bars(&report).unwrap();
+
+        let ids: Vec<&str> = bars.iter().map(|bar| bar.id.as_str()).collect();
+        assert_eq!(ids, vec!["LARGE", "NEGATIVE", "SMALL"]);
+    }
+
+    #[test]
+    fn bar_label_for_y_puts_first_bar_on_top() {
+        let first = build_wp("WP1", false, 0.85, 0.9);
+        let second = build_wp("WP2", false, 0.1, 0.3);
+        let bars = vec![&first, &second];
+
+        assert_eq!(bar_label_for_y(1.5, &bars), "WP1 (85%)");
+        assert_eq!(bar_label_for_y(0.5, &bars), "WP2 (10%)");
+        assert_eq!(bar_label_for_y(1.0, &bars), "");
+    }
+}
@@ -1,29 +1,68 @@
+use std::collections::HashSet;
+
 use crate::domain::issue::Issue;
 use crate::domain::issue_status::IssueStatus;
 use crate::domain::project::Project;
 use crate::services::data_source::{DataQuery, DataSource, DataSourceError};
 
+/// A dependency that points to an issue outside the query result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalDependency {
+    pub issue_id: String,
+    pub dependency_id: String,
+}
+
+/// A project created from a data source. With linked dependencies,
+/// dependencies on issues that are not part of the project are removed from
+/// it and listed separately.
+#[derive(Debug)]
+pub struct CreatedProject {
+    pub project: Project,
+    pub external_dependencies: Vec<ExternalDependency>,
+}
+
 pub struct ProjectFactory<'a> {
     data_source: &'a dyn DataSource,
+    linked_dependencies: bool,
 }
 
 impl<'a> ProjectFactory<'a> {
     pub fn new(data_source: &'a dyn DataSource) -> Self {
-        Self { data_source }
+        Self {
+            data_source,
+            linked_dependencies: false,
+        }
+    }
+
+    /// Whether the dependencies of the issues come from links, so that issues
+    /// without dependencies are independent instead of following their
+    /// predecessor.
+    pub fn with_linked_dependencies(mut self, linked_dependencies: bool) -> Self {
+        self.linked_dependencies = linked_dependencies;
+        self
     }
 
     pub fn create_project(
         &self,
         project_name: String,
         query: DataQuery,
-    ) -> Result<Project, DataSourceError> {
+    ) -> Result<CreatedProject, DataSourceError> {
         let mut issues = self.data_source.get_issues(query)?;
         sort_issues_by_status(&mut issues);
-        clear_dependencies_for_boundary_issues(&mut issues);
+        let external_dependencies = if self.linked_dependencies {
+            remove_external_dependencies(&mut issues)
+        } else {
+            clear_dependencies_for_boundary_issues(&mut issues);
+            Vec::new()
+        };
 
-        Ok(Project {
-            name: project_name,
-            work_packages: issues,
+        Ok(CreatedProject {
+            project: Project {
+                name: project_name,
+                work_packages: issues,
+                risks: Vec::new(),
+            },
+            external_dependencies,
         })
     }
 }
@@ -54,6 +93,41 @@ fn clear_dependencies_for_boundary_issues(issues: &mut [Issue]) {
     }
 }
 
+fn remove_external_dependencies(issues: &mut [Issue]) -> Vec<ExternalDependency> {
+    let known_ids: HashSet<String> = issues
+        .iter()
+        .filter_map(|issue| issue.issue_id.as_ref().map(|id| id.id.clone()))
+        .collect();
+
+    let mut external = Vec::new();
+    for issue in issues.iter_mut() {
+        let Some(dependencies) = issue.dependencies.as_mut() else {
+            continue;
+        };
+
+        let issue_id = issue
+            .issue_id
+            .as_ref()
+            .map(|id| id.id.clone())
+            .unwrap_or_default();
+        dependencies.retain(|dependency| {
+            let is_known = known_ids.contains(&dependency.id);
+            if !is_known {
+                external.push(ExternalDependency {
+                    issue_id: issue_id.clone(),
+                    dependency_id: dependency.id.clone(),
+                });
+            }
+            is_known
+        });
+        // An empty list would mean "depends on the previous issue".
+        if dependencies.is_empty() {
+            issue.dependencies = None;
+        }
+    }
+    external
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,12 +163,13 @@ mod tests {
         };
         let factory = ProjectFactory::new(&source);
 
-        let project = factory
+        let created = factory
             .create_project(
                 "Project Alpha".to_string(),
                 DataQuery::StringQuery("project = ALPHA".to_string()),
             )
             .unwrap();
+        let project = created.project;
 
         let ids: Vec<&str> = project
             .work_packages
@@ -117,12 +192,13 @@ mod tests {
         };
         let factory = ProjectFactory::new(&source);
 
-        let project = factory
+        let created = factory
             .create_project(
                 "Project Done".to_string(),
                 DataQuery::StringQuery("project = DONE".to_string()),
             )
             .unwrap();
+        let project = created.project;
 
         assert_eq!(project.work_packages[0].dependencies, None);
         assert!(project.work_packages[1].dependencies.is_some());
@@ -138,17 +214,109 @@ mod tests {
         };
         let factory = ProjectFactory::new(&source);
 
-        let project = factory
+        let created = factory
             .create_project(
                 "Project Active".to_string(),
                 DataQuery::StringQuery("project = ACTIVE".to_string()),
             )
             .unwrap();
+        let project = created.project;
 
         assert_eq!(project.work_packages[0].dependencies, None);
         assert!(project.work_packages[1].dependencies.is_some());
     }
 
+    #[test]
+    fn create_project_with_linked_dependencies_keeps_unlinked_issues_independent() {
+        let source = MockDataSource {
+            mode: MockMode::Success(vec![
+                issue_with_status("TODO-1", Some(IssueStatus::ToDo), false),
+                issue_with_status("DONE-1", Some(IssueStatus::Done), false),
+                issue_with_status("TODO-2", Some(IssueStatus::ToDo), false),
+            ]),
+        };
+        let factory = ProjectFactory::new(&source).with_linked_dependencies(true);
+
+        let created = factory
+            .create_project(
+                "Project Unlinked".to_string(),
+                DataQuery::StringQuery("project = UNLINKED".to_string()),
+            )
+            .unwrap();
+
+        assert!(
+            created
+                .project
+                .work_packages
+                .iter()
+                .all(|issue| issue.dependencies.is_none())
+        );
+        assert!(created.external_dependencies.is_empty());
+    }
+
+    #[test]
+    fn create_project_with_linked_dependencies_keeps_them_for_boundary_issues() {
+        let source = MockDataSource {
+            mode: MockMode::Success(vec![
+                issue_with_dependencies("TODO-1", IssueStatus::ToDo, &["DONE-1"]),
+                issue_with_dependencies("DONE-1", IssueStatus::Done, &[]),
+            ]),
+        };
+        let factory = ProjectFactory::new(&source).with_linked_dependencies(true);
+
+        let created = factory
+            .create_project(
+                "Project Links".to_string(),
+                DataQuery::StringQuery("project = LINKS".to_string()),
+            )
+            .unwrap();
+
+        let work_packages = &created.project.work_packages;
+        assert_eq!(work_packages[0].dependencies, None);
+        assert_eq!(
+            work_packages[1].dependencies,
+            Some(vec![IssueId {
+                id: "DONE-1".to_string()
+            }])
+        );
+        assert!(created.external_dependencies.is_empty());
+    }
+
+    #[test]
+    fn create_project_with_linked_dependencies_reports_those_outside_the_result() {
+        let source = MockDataSource {
+            mode: MockMode::Success(vec![
+                issue_with_dependencies("DONE-1", IssueStatus::Done, &["OTHER-1"]),
+                issue_with_dependencies("TODO-1", IssueStatus::ToDo, &["DONE-1", "OTHER-2"]),
+            ]),
+        };
+        let factory = ProjectFactory::new(&source).with_linked_dependencies(true);
+
+        let created = factory
+            .create_project(
+                "Project External".to_string(),
+                DataQuery::StringQuery("project = EXTERNAL".to_string()),
+            )
+            .unwrap();
+
+        let work_packages = &created.project.work_packages;
+        assert_eq!(work_packages[0].dependencies, None);
+        assert_eq!(work_packages[1].dependencies.as_ref().unwrap().len(), 1);
+        assert_eq!(
+            created.external_dependencies,
+            vec![
+                ExternalDependency {
+                    issue_id: "DONE-1".to_string(),
+                    dependency_id: "OTHER-1".to_string(),
+                },
+                ExternalDependency {
+                    issue_id: "TODO-1".to_string(),
+                    dependency_id: "OTHER-2".to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn create_project_propagates_data_source_errors() {
         let source = MockDataSource {
@@ -177,4 +345,17 @@ mod tests {
         };
         issue
     }
+
+    fn issue_with_dependencies(id: &str, status: IssueStatus, dependencies: &[&str]) -> Issue {
+        let mut issue = issue_with_status(id, Some(status), false);
+        issue.dependencies = Some(
+            dependencies
+                .iter()
+                .map(|dependency| IssueId {
+                    id: dependency.to_string(),
+                })
+                .collect(),
+        );
+        issue
+    }
 }
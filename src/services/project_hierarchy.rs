@@ -0,0 +1,167 @@
+use crate::domain::estimate::{Estimate, ReferenceEstimate};
+use crate::domain::issue_status::IssueStatus;
+use crate::services::data_source::{DataQuery, DataSource, DataSourceError};
+use crate::services::project_factory::{CreatedProject, ProjectFactory};
+
+/// The project of a single epic, containing its child issues.
+#[derive(Debug)]
+pub struct EpicProject {
+    pub epic_id: String,
+    pub report_file_path: String,
+    pub created: CreatedProject,
+}
+
+/// An initiative split into one project per epic, plus a top-level project
+/// whose work packages are the epics.
+#[derive(Debug)]
+pub struct ProjectHierarchy {
+    pub top_level: CreatedProject,
+    pub epics: Vec<EpicProject>,
+}
+
+pub struct ProjectHierarchyFactory<'a> {
+    data_source: &'a dyn DataSource,
+    linked_dependencies: bool,
+}
+
+impl<'a> ProjectHierarchyFactory<'a> {
+    pub fn new(data_source: &'a dyn DataSource) -> Self {
+        Self {
+            data_source,
+            linked_dependencies: false,
+        }
+    }
+
+    /// See `ProjectFactory::with_linked_dependencies`.
+    pub fn with_linked_dependencies(mut self, linked_dependencies: bool) -> Self {
+        self.linked_dependencies = linked_dependencies;
+        self
+    }
+
+    /// Reads the epics of `initiative_id` and their child issues.
+    ///
+    /// `child_query` builds the query for the children of an issue.
+    /// Open epics of the top-level project get a reference estimate pointing
+    /// at `report_file_path(epic_id)`, where the simulation report of the
+    /// epic project is expected.
+    pub fn create_hierarchy(
+        &self,
+        initiative_id: &str,
+        child_query: impl Fn(&str) -> String,
+        report_file_path: impl Fn(&str) -> String,
+    ) -> Result<ProjectHierarchy, DataSourceError> {
+        let project_factory = ProjectFactory::new(self.data_source)
+            .with_linked_dependencies(self.linked_dependencies);
+        let mut top_level = project_factory.create_project(
+            initiative_id.to_string(),
+            DataQuery::StringQuery(child_query(initiative_id)),
+        )?;
+
+        let mut epics = Vec::new();
+        for epic in top_level.project.work_packages.iter_mut() {
+            let Some(epic_id) = epic.issue_id.as_ref().map(|id| id.id.clone()) else {
+                continue;
+            };
+
+            let created = project_factory.create_project(
+                epic_id.clone(),
+                DataQuery::StringQuery(child_query(&epic_id)),
+            )?;
+            let report_file_path = report_file_path(&epic_id);
+            if epic.status != Some(IssueStatus::Done) {
+                epic.estimate = Some(Estimate::Reference(ReferenceEstimate {
+                    report_file_path: report_file_path.clone(),
+                    cached_estimate: None,
+                }));
+            }
+            epics.push(EpicProject {
+                epic_id,
+                report_file_path,
+                created,
+            });
+        }
+
+        Ok(ProjectHierarchy { top_level, epics })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::issue::{Issue, IssueId};
+
+    struct MockDataSource;
+
+    impl DataSource for MockDataSource {
+        fn get_issues(&self, query: DataQuery) -> Result<Vec<Issue>, DataSourceError> {
+            let DataQuery::StringQuery(parent) = query;
+            let children: &[(&str, IssueStatus)] = match parent.as_str() {
+                "INIT-1" => &[
+                    ("EPIC-1", IssueStatus::Done),
+                    ("EPIC-2", IssueStatus::InProgress),
+                ],
+                "EPIC-1" => &[("STORY-1", IssueStatus::Done)],
+                "EPIC-2" => &[
+                    ("STORY-2", IssueStatus::InProgress),
+                    ("STORY-3", IssueStatus::ToDo),
+                ],
+                _ => return Err(DataSourceError::NotFound),
+            };
+            Ok(children
+                .iter()
+                .map(|(id, status)| {
+                    let mut issue = Issue::new();
+                    issue.issue_id = Some(IssueId { id: id.to_string() });
+                    issue.status = Some(status.clone());
+                    issue
+                })
+                .collect())
+        }
+    }
+
+    #[test]
+    fn create_hierarchy_creates_one_project_per_epic() {
+        let factory = ProjectHierarchyFactory::new(&MockDataSource);
+
+        let hierarchy = factory
+            .create_hierarchy(
+                "INIT-1",
+                |parent| parent.to_string(),
+                |epic| format!("{epic}.report.yaml"),
+            )
+            .unwrap();
+
+        assert_eq!(hierarchy.top_level.project.name, "INIT-1");
+        let epic_ids: Vec<&str> = hierarchy
+            .epics
+            .iter()
+            .map(|epic| epic.epic_id.as_str())
+            .collect();
+        assert_eq!(epic_ids, vec!["EPIC-1", "EPIC-2"]);
+        assert_eq!(hierarchy.epics[1].created.project.name, "EPIC-2");
+        assert_eq!(hierarchy.epics[1].created.project.work_packages.len(), 2);
+    }
+
+    #[test]
+    fn create_hierarchy_references_reports_of_open_epics() {
+        let factory = ProjectHierarchyFactory::new(&MockDataSource);
+
+        let hierarchy = factory
+            .create_hierarchy(
+                "INIT-1",
+                |parent| parent.to_string(),
+                |epic| format!("{epic}.report.yaml"),
+            )
+            .unwrap();
+
+        let epics = &hierarchy.top_level.project.work_packages;
+        assert_eq!(epics[0].estimate, None);
+        assert_eq!(
+            epics[1].estimate,
+            Some(Estimate::Reference(ReferenceEstimate {
+                report_file_path: "EPIC-2.report.yaml".to_string(),
+                cached_estimate: None,
+            }))
+        );
+    }
+}
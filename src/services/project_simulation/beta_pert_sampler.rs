@@ -1,6 +1,8 @@
 use rand::Rng;
 use rand_distr::{Beta, Distribution};
 
+use crate::domain::estimate::DurationDistribution;
+
 #[derive(Debug, thiserror::Error, PartialEq)]
 pub enum ThreePointSamplerError {
     #[error("pessimistic value must be >= optimistic value")]
@@ -9,6 +11,14 @@ pub enum ThreePointSamplerError {
     MostLikelyOutOfRange,
     #[error("beta distribution error: {0}")]
     BetaDistributionError(#[from] rand_distr::BetaError),
+    #[error("PERT shape must be a non-negative number, got {0}")]
+    InvalidShape(f32),
+    #[error("log-normal distribution requires a positive optimistic value")]
+    NonPositiveOptimistic,
+    #[error("triangular distribution error: {0}")]
+    TriangularDistributionError(#[from] rand_distr::TriangularError),
+    #[error("log-normal distribution error: {0}")]
+    LogNormalDistributionError(#[from] rand_distr::NormalError),
 }
 
 pub trait ThreePointSampler {
@@ -18,42 +28,79 @@ pub trait ThreePointSampler {
         most_likely: f32,
         pessimistic: f32,
     ) -> Result<f32, ThreePointSamplerError>;
-}
-
-pub struct BetaPertSampler<R: Rng> {
-    rng: R,
-}
 
-impl<R: Rng> BetaPertSampler<R> {
-    pub fn new(rng: R) -> Self {
-        Self { rng }
+    /// Samples from `distribution` if the estimate selects one. Samplers of
+    /// a single distribution ignore it.
+    fn sample_distribution(
+        &mut self,
+        _distribution: Option<DurationDistribution>,
+        optimistic: f32,
+        most_likely: f32,
+        pessimistic: f32,
+    ) -> Result<f32, ThreePointSamplerError> {
+        self.sample(optimistic, most_likely, pessimistic)
     }
-}
 
-impl<R: Rng> ThreePointSampler for BetaPertSampler<R> {
-    fn sample(
+    /// Returns the value of the distribution at `quantile` instead of a
+    /// random one, so that correlated estimates can share their randomness.
+    /// Samplers that do not draw from a distribution ignore the quantile.
+    fn sample_at_quantile(
         &mut self,
+        distribution: Option<DurationDistribution>,
         optimistic: f32,
         most_likely: f32,
         pessimistic: f32,
+        _quantile: f64,
     ) -> Result<f32, ThreePointSamplerError> {
-        if pessimistic < optimistic {
-            return Err(ThreePointSamplerError::PessimisticLessThanOptimistic);
-        }
-        if (pessimistic - optimistic).abs() < f32::EPSILON {
-            return Ok(optimistic);
-        }
-        if most_likely < optimistic || most_likely > pessimistic {
-            return Err(ThreePointSamplerError::MostLikelyOutOfRange);
-        }
-
-        let range = (pessimistic - optimistic) as f64;
-        let alpha = 1.0 + 4.0 * ((most_likely - optimistic) as f64 / range);
-        let beta = 1.0 + 4.0 * ((pessimistic - most_likely) as f64 / range);
-        let beta_dist = Beta::new(alpha, beta)?;
-        let sample = beta_dist.sample(&mut self.rng) as f32;
-        Ok(optimistic + sample * (pessimistic - optimistic))
+        self.sample_distribution(distribution, optimistic, most_likely, pessimistic)
+    }
+}
+
+/// Weight of the most likely value in classic PERT.
+pub(crate) const PERT_SHAPE: f32 = 4.0;
+
+/// Samples a PERT beta distribution whose most likely value has the weight
+/// `shape`; classic PERT uses `PERT_SHAPE`.
+pub(crate) fn sample_pert<R: Rng + ?Sized>(
+    rng: &mut R,
+    optimistic: f32,
+    most_likely: f32,
+    pessimistic: f32,
+    shape: f32,
+) -> Result<f32, ThreePointSamplerError> {
+    let Some((alpha, beta)) = pert_parameters(optimistic, most_likely, pessimistic, shape)? else {
+        return Ok(optimistic);
+    };
+    let beta_dist = Beta::new(alpha, beta)?;
+    let sample = beta_dist.sample(rng) as f32;
+    Ok(optimistic + sample * (pessimistic - optimistic))
+}
+
+/// Validates a PERT estimate and returns the parameters of its beta
+/// distribution, or `None` if the estimate has no spread.
+pub(crate) fn pert_parameters(
+    optimistic: f32,
+    most_likely: f32,
+    pessimistic: f32,
+    shape: f32,
+) -> Result<Option<(f64, f64)>, ThreePointSamplerError> {
+    if !shape.is_finite() || shape < 0.0 {
+        return Err(ThreePointSamplerError::InvalidShape(shape));
     }
+    if pessimistic < optimistic {
+        return Err(ThreePointSamplerError::PessimisticLessThanOptimistic);
+    }
+    if (pessimistic - optimistic).abs() < f32::EPSILON {
+        return Ok(None);
+    }
+    if most_likely < optimistic || most_likely > pessimistic {
+        return Err(ThreePointSamplerError::MostLikelyOutOfRange);
+    }
+
+    let range = (pessimistic - optimistic) as f64;
+    let alpha = 1.0 + shape as f64 * ((most_likely - optimistic) as f64 / range);
+    let beta = 1.0 + shape as f64 * ((pessimistic - most_likely) as f64 / range);
+    Ok(Some((alpha, beta)))
 }
 
 pub struct PertExpectedValueSampler;
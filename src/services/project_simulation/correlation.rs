@@ -0,0 +1,198 @@
+//! Correlated estimation errors of work packages.
+//!
+//! Estimates made by the same team tend to be wrong in the same direction.
+//! A Gaussian copula lets the work packages of a group share part of their
+//! randomness, while every package keeps the distribution of its estimate.
+
+use std::collections::HashMap;
+
+use rand::Rng;
+use rand_distr::StandardNormal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::issue::Issue;
+use crate::domain::project::Project;
+use crate::services::project_simulation::special_functions::standard_normal_cdf;
+
+/// Which work packages share their estimation error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorrelationGrouping {
+    /// Work packages with the same `correlation_group`.
+    CorrelationGroup,
+    /// Work packages in the same subgraph.
+    Subgraph,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CorrelationOptions {
+    /// Correlation between the durations of two packages of a group, from 0
+    /// (independent) to 1 (always at the same percentile of their estimates).
+    pub strength: f32,
+    pub grouping: CorrelationGrouping,
+}
+
+/// Draws the quantile at which every work package samples its estimate.
+pub(crate) struct CorrelatedQuantiles {
+    /// Group index per work package, `None` for packages outside any group.
+    groups: Vec<Option<usize>>,
+    group_count: usize,
+    strength: f64,
+}
+
+impl CorrelatedQuantiles {
+    pub fn new(project: &Project, options: &CorrelationOptions) -> Self {
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+        let groups = project
+            .work_packages
+            .iter()
+            .map(|issue| {
+                let name = group_name(issue, options.grouping)?;
+                let next = indices.len();
+                Some(*indices.entry(name).or_insert(next))
+            })
+            .collect();
+
+        Self {
+            groups,
+            group_count: indices.len(),
+            strength: options.strength.clamp(0.0, 1.0) as f64,
+        }
+    }
+
+    pub fn group_count(&self) -> usize {
+        self.group_count
+    }
+
+    /// One quantile per work package for a single iteration. Packages of the
+    /// same group share the factor `√strength · Z_group`; packages outside
+    /// any group get `None` and are sampled independently.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<Option<f64>> {
+        let shared: Vec<f64> = (0..self.group_count)
+            .map(|_| rng.sample(StandardNormal))
+            .collect();
+        let (shared_weight, own_weight) = (self.strength.sqrt(), (1.0 - self.strength).sqrt());
+
+        self.groups
+            .iter()
+            .map(|group| {
+                let group = (*group)?;
+                let own: f64 = rng.sample(StandardNormal);
+                Some(standard_normal_cdf(
+                    shared_weight * shared[group] + own_weight * own,
+                ))
+            })
+            .collect()
+    }
+}
+
+fn group_name(issue: &Issue, grouping: CorrelationGrouping) -> Option<&str> {
+    match grouping {
+        CorrelationGrouping::CorrelationGroup => issue.correlation_group.as_deref(),
+        CorrelationGrouping::Subgraph => issue.subgraph.as_deref(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn project(groups: &[Option<&str>]) -> Project {
+        Project {
+            name: "Test".to_string(),
+            work_packages: groups
+                .iter()
+                .map(|group| {
+                    let mut issue = Issue::new();
+                    issue.correlation_group = group.map(str::to_string);
+                    issue.subgraph = Some("Phase".to_string());
+                    issue
+                })
+                .collect(),
+            risks: Vec::new(),
+        }
+    }
+
+    fn options(strength: f32, grouping: CorrelationGrouping) -> CorrelationOptions {
+        CorrelationOptions { strength, grouping }
+    }
+
+    /// Pearson correlation of the quantiles of the first two packages.
+    fn correlation_of_first_two(quantiles: &CorrelatedQuantiles) -> f64 {
+        let mut rng = StdRng::seed_from_u64(11);
+        let pairs: Vec<(f64, f64)> = (0..20_000)
+            .map(|_| {
+                let sample = quantiles.sample(&mut rng);
+                (sample[0].unwrap(), sample[1].unwrap())
+            })
+            .collect();
+        let count = pairs.len() as f64;
+        let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / count;
+        let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / count;
+        let covariance = pairs
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>();
+        let variance_x = pairs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum::<f64>();
+        let variance_y = pairs.iter().map(|(_, y)| (y - mean_y).powi(2)).sum::<f64>();
+        covariance / (variance_x * variance_y).sqrt()
+    }
+
+    #[test]
+    fn packages_outside_a_group_are_independent() {
+        let project = project(&[Some("backend"), None, Some("backend")]);
+        let quantiles = CorrelatedQuantiles::new(
+            &project,
+            &options(0.5, CorrelationGrouping::CorrelationGroup),
+        );
+        let sample = quantiles.sample(&mut StdRng::seed_from_u64(1));
+
+        assert_eq!(quantiles.group_count(), 1);
+        assert!(sample[0].is_some());
+        assert_eq!(sample[1], None);
+        assert!(sample[2].is_some());
+    }
+
+    #[test]
+    fn packages_of_a_group_are_correlated_by_the_strength() {
+        let project = project(&[Some("backend"), Some("backend")]);
+        let weak = CorrelatedQuantiles::new(
+            &project,
+            &options(0.0, CorrelationGrouping::CorrelationGroup),
+        );
+        let strong = CorrelatedQuantiles::new(
+            &project,
+            &options(0.8, CorrelationGrouping::CorrelationGroup),
+        );
+
+        assert!(correlation_of_first_two(&weak).abs() < 0.03);
+        // The rank correlation of a Gaussian copula is slightly below its strength.
+        let correlation = correlation_of_first_two(&strong);
+        assert!((0.75..0.82).contains(&correlation), "{correlation}");
+    }
+
+    #[test]
+    fn packages_of_different_groups_are_independent() {
+        let project = project(&[Some("backend"), Some("frontend")]);
+        let quantiles = CorrelatedQuantiles::new(
+            &project,
+            &options(1.0, CorrelationGrouping::CorrelationGroup),
+        );
+
+        assert_eq!(quantiles.group_count(), 2);
+        assert!(correlation_of_first_two(&quantiles).abs() < 0.03);
+    }
+
+    #[test]
+    fn subgraph_grouping_ignores_correlation_groups() {
+        let project = project(&[Some("backend"), Some("frontend")]);
+        let quantiles =
+            CorrelatedQuantiles::new(&project, &options(1.0, CorrelationGrouping::Subgraph));
+        let sample = quantiles.sample(&mut StdRng::seed_from_u64(1));
+
+        assert_eq!(quantiles.group_count(), 1);
+        assert_eq!(sample[0], sample[1]);
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::services::project_simulation::network_nodes::SortedNetworkNodes;
+use crate::services::project_simulation::network_nodes::{NetworkNode, SortedNetworkNodes};
 use chrono::NaiveDate;
 use thiserror::Error;
 
@@ -23,12 +23,15 @@ pub struct ResultNode {
     pub latest_finish: NaiveDate,
     pub free_float: f32,  // ES of next node - EF of current node.
     pub total_float: f32, // LS - ES or LF - EF. If 0, then the node is on the critical path
+    /// The node finishes after the date its constraint allows.
+    pub constraint_violated: bool,
 }
 
 impl ResultNode {
-    /// Returns true if this node is on the critical path (total float is zero).
+    /// Returns true if this node is on the critical path (total float is zero,
+    /// or negative if a date constraint is missed).
     pub fn is_critical(&self) -> bool {
-        self.total_float.abs() < f32::EPSILON
+        self.total_float < f32::EPSILON
     }
 }
 
@@ -36,6 +39,18 @@ pub fn critical_path_method(
     network: SortedNetworkNodes,
     project_start: NaiveDate,
     calendar: Option<&TeamCalendar>,
+) -> Result<Vec<ResultNode>, CriticalPathMethodError> {
+    critical_path_method_with_wip_limit(network, project_start, calendar, None)
+}
+
+/// Like `critical_path_method`, but with at most `wip_limit` work packages in
+/// progress at the same time. The floats are calculated from the
+/// dependencies only, so packages delayed by the limit can still be critical.
+pub fn critical_path_method_with_wip_limit(
+    network: SortedNetworkNodes,
+    project_start: NaiveDate,
+    calendar: Option<&TeamCalendar>,
+    wip_limit: Option<usize>,
 ) -> Result<Vec<ResultNode>, CriticalPathMethodError> {
     let sorted_nodes = network.take();
     let nodes_count = sorted_nodes.len();
@@ -43,27 +58,29 @@ pub fn critical_path_method(
     let mut earliest_finish_dates: HashMap<String, chrono::NaiveDate> =
         HashMap::with_capacity(nodes_count);
     let mut result_nodes: HashMap<String, ResultNode> = HashMap::with_capacity(nodes_count);
+    let wip_schedule = match wip_limit {
+        Some(wip_limit) => Some(schedule_with_wip_limit(
+            &sorted_nodes,
+            project_start,
+            calendar,
+            wip_limit,
+        )?),
+        None => None,
+    };
 
     // Forward pass to calculate earliest start and finish times
     for node in &sorted_nodes {
-        let earliest_start = if let Some(start_date) = node.start_date {
-            start_date
-        } else {
-            node.dependencies
-                .iter()
-                .filter_map(|dep| earliest_finish_dates.get(dep))
-                .max()
-                .cloned()
-                .unwrap_or(project_start)
-        };
-
-        let earliest_finish = if let Some(end_date) = node.end_date {
-            end_date
-        } else {
-            calculate_end_date(earliest_start, node.duration, calendar)?
+        let (earliest_start, earliest_finish) = match &wip_schedule {
+            Some(schedule) => schedule[&node.id],
+            None => {
+                let earliest_start = ready_date(node, &earliest_finish_dates, project_start);
+                (earliest_start, finish_date(node, earliest_start, calendar)?)
+            }
         };
 
         earliest_finish_dates.insert(node.id.clone(), earliest_finish);
+        let constraint_violated = constrained_finish(node, calendar)
+            .is_some_and(|latest_finish| earliest_finish > latest_finish);
 
         result_nodes.insert(
             node.id.clone(),
@@ -76,6 +93,7 @@ pub fn critical_path_method(
                 latest_finish: project_start, // Placeholder, will be calculated in backward pass
                 free_float: 0.0,              // Placeholder, will be calculated in backward pass
                 total_float: 0.0,             // Placeholder, will be calculated in backward pass
+                constraint_violated,
             },
         );
     }
@@ -99,7 +117,9 @@ pub fn critical_path_method(
         .cloned()
         .unwrap_or(project_start);
 
-    // Backward pass to calculate latest start and finish times
+    // Backward pass to calculate latest start and finish times. Finish
+    // constraints can move the latest finish before the earliest finish, so
+    // the total float of late nodes and their predecessors turns negative.
     let mut latest_start: HashMap<String, chrono::NaiveDate> = HashMap::with_capacity(nodes_count);
 
     for node in sorted_nodes.iter().rev() {
@@ -109,6 +129,10 @@ pub fn critical_path_method(
             .min()
             .cloned()
             .unwrap_or(project_end);
+        let latest_finish_date = match constrained_finish(node, calendar) {
+            Some(constrained_finish) => latest_finish_date.min(constrained_finish),
+            None => latest_finish_date,
+        };
 
         let latest_start_date = calculate_start_date(latest_finish_date, node.duration, calendar)?;
 
@@ -140,6 +164,124 @@ pub fn critical_path_method(
     Ok(result_vector)
 }
 
+/// The date a node can start once its dependencies are finished, but not
+/// before the date its constraint allows.
+fn ready_date(
+    node: &NetworkNode,
+    earliest_finish_dates: &HashMap<String, NaiveDate>,
+    project_start: NaiveDate,
+) -> NaiveDate {
+    if let Some(start_date) = node.start_date {
+        return start_date;
+    }
+    let ready = node
+        .dependencies
+        .iter()
+        .filter_map(|dep| earliest_finish_dates.get(dep))
+        .max()
+        .cloned()
+        .unwrap_or(project_start);
+    match node
+        .constraint
+        .and_then(|constraint| constraint.earliest_start())
+    {
+        Some(constrained_start) => ready.max(constrained_start),
+        None => ready,
+    }
+}
+
+/// The latest finish allowed by the node's constraint. Finish dates are the
+/// day after the work is done, and so is this: the first working day after
+/// the inclusive constraint date.
+fn constrained_finish(node: &NetworkNode, calendar: Option<&TeamCalendar>) -> Option<NaiveDate> {
+    let date = node.constraint?.latest_finish()? + chrono::Duration::days(1);
+    let Some(calendar) = calendar else {
+        return Some(date);
+    };
+    let next_working_day = (0..365 * 200)
+        .map(|i| date + chrono::Duration::days(i))
+        .find(|d| calendar.get_capacity(*d) > 0.0)
+        .unwrap_or(date);
+    Some(next_working_day)
+}
+
+fn finish_date(
+    node: &NetworkNode,
+    start_date: NaiveDate,
+    calendar: Option<&TeamCalendar>,
+) -> Result<NaiveDate, CriticalPathMethodError> {
+    if let Some(end_date) = node.end_date {
+        Ok(end_date)
+    } else {
+        calculate_end_date(start_date, node.duration, calendar)
+    }
+}
+
+/// Forward pass that keeps at most `wip_limit` packages in progress. Of the
+/// packages whose dependencies are scheduled, the one that is ready first
+/// starts next, waiting for the earliest free slot if all are taken; ties go
+/// to the package that comes first in `sorted_nodes`. Done packages and
+/// milestones take no slot, and packages already started keep their start.
+fn schedule_with_wip_limit(
+    sorted_nodes: &[NetworkNode],
+    project_start: NaiveDate,
+    calendar: Option<&TeamCalendar>,
+    wip_limit: usize,
+) -> Result<HashMap<String, (NaiveDate, NaiveDate)>, CriticalPathMethodError> {
+    let mut schedule = HashMap::with_capacity(sorted_nodes.len());
+    let mut earliest_finish_dates: HashMap<String, NaiveDate> =
+        HashMap::with_capacity(sorted_nodes.len());
+    // Finish dates of the packages occupying a slot.
+    let mut slots: Vec<NaiveDate> = Vec::with_capacity(wip_limit);
+    let mut pending: Vec<&NetworkNode> = sorted_nodes.iter().collect();
+
+    while !pending.is_empty() {
+        // The first pending node has all its dependencies scheduled, as the
+        // nodes are topologically sorted.
+        let (position, ready) = pending
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| {
+                node.dependencies
+                    .iter()
+                    .all(|dep| earliest_finish_dates.contains_key(dep))
+            })
+            .map(|(position, node)| {
+                (
+                    position,
+                    ready_date(node, &earliest_finish_dates, project_start),
+                )
+            })
+            .min_by_key(|(_, ready)| *ready)
+            .unwrap_or((0, project_start));
+        let node = pending.remove(position);
+
+        let takes_slot = node.end_date.is_none() && node.duration > 0.0;
+        let mut start = ready;
+        if takes_slot && node.start_date.is_none() {
+            while slots.len() >= wip_limit.max(1) {
+                let (index, free_date) = slots
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .min_by_key(|(_, date)| *date)
+                    .unwrap_or((0, start));
+                slots.swap_remove(index);
+                start = start.max(free_date);
+            }
+        }
+        let finish = finish_date(node, start, calendar)?;
+        if takes_slot {
+            slots.push(finish);
+        }
+
+        earliest_finish_dates.insert(node.id.clone(), finish);
+        schedule.insert(node.id.clone(), (start, finish));
+    }
+
+    Ok(schedule)
+}
+
 fn calculate_end_date(
     start_date: chrono::NaiveDate,
     duration_days: f32,
@@ -239,6 +381,7 @@ mod tests {
     use crate::test_support::on_date;
 
     use super::*;
+    use crate::domain::issue::DateConstraint;
     use chrono::NaiveDate;
 
     fn build_network_node(id: &str, duration: f32, dependencies: &[&str]) -> NetworkNode {
@@ -248,7 +391,8 @@ mod tests {
             duration,
             start_date: None,
             end_date: None,
-            dependencies: dependencies.into_iter().map(|s| s.to_string()).collect(),
+            constraint: None,
+            dependencies: dependencies.iter().map(|s| s.to_string()).collect(),
         }
     }
 
@@ -303,6 +447,7 @@ mod tests {
     }
 
     impl WorkPackageTestCase {
+        #[allow(clippy::too_many_arguments)]
         fn new(
             id: &'static str,
             duration: f32,
@@ -494,13 +639,7 @@ mod tests {
             let network: Vec<NetworkNode> = test
                 .work_packages
                 .iter()
-                .map(|wp| {
-                    build_network_node(
-                        wp.id,
-                        wp.duration,
-                        &wp.dependencies.iter().map(|s| *s).collect::<Vec<_>>(),
-                    )
-                })
+                .map(|wp| build_network_node(wp.id, wp.duration, &wp.dependencies))
                 .collect();
             let network = SortedNetworkNodes::new(network).unwrap();
 
@@ -563,6 +702,7 @@ mod tests {
                 duration: 3.0,
                 start_date: Some(on_date(2026, 1, 5)),
                 end_date: Some(on_date(2026, 1, 8)),
+                constraint: None,
                 dependencies: vec![],
             },
             NetworkNode {
@@ -571,6 +711,7 @@ mod tests {
                 duration: 4.0,
                 start_date: Some(on_date(2026, 1, 12)),
                 end_date: None,
+                constraint: None,
                 dependencies: vec!["WP0".to_string()],
             },
             NetworkNode {
@@ -579,6 +720,7 @@ mod tests {
                 duration: 4.0,
                 start_date: None,
                 end_date: None,
+                constraint: None,
                 dependencies: vec!["WP1".to_string()],
             },
         ];
@@ -600,6 +742,182 @@ mod tests {
         assert_eq!(wp2.earliest_finish, on_date(2026, 1, 20));
     }
 
+    #[test]
+    fn wip_limit_delays_packages_until_a_slot_is_free() {
+        let network = vec![
+            build_network_node("WP0", 2.0, &[]),
+            build_network_node("WP1", 2.0, &[]),
+            build_network_node("WP2", 2.0, &[]),
+            build_network_node("FIN", 0.0, &["WP0", "WP1", "WP2"]),
+        ];
+        let network = SortedNetworkNodes::new(network).unwrap();
+        let project_start = on_date(2026, 1, 1);
+
+        let result =
+            critical_path_method_with_wip_limit(network, project_start, None, Some(2)).unwrap();
+
+        let mut starts: Vec<NaiveDate> = result
+            .iter()
+            .filter(|node| node.id != "FIN")
+            .map(|node| node.earliest_start)
+            .collect();
+        starts.sort();
+        // The third package waits for one of the first two to free its slot.
+        assert_eq!(
+            starts,
+            vec![
+                on_date(2026, 1, 1),
+                on_date(2026, 1, 1),
+                on_date(2026, 1, 3)
+            ]
+        );
+        let fin = result.iter().find(|node| node.id == "FIN").unwrap();
+        assert_eq!(fin.earliest_finish, on_date(2026, 1, 5));
+        assert!(result.iter().all(|node| node.total_float >= 0.0));
+    }
+
+    #[test]
+    fn wip_limit_leaves_done_packages_and_milestones_out() {
+        let mut done = build_network_node("WP0", 2.0, &[]);
+        done.start_date = Some(on_date(2025, 12, 1));
+        done.end_date = Some(on_date(2025, 12, 3));
+        let network = vec![
+            done,
+            build_network_node("M1", 0.0, &["WP0"]),
+            build_network_node("WP1", 2.0, &["M1"]),
+        ];
+        let network = SortedNetworkNodes::new(network).unwrap();
+        let project_start = on_date(2026, 1, 1);
+
+        let result =
+            critical_path_method_with_wip_limit(network, project_start, None, Some(1)).unwrap();
+
+        let wp1 = result.iter().find(|node| node.id == "WP1").unwrap();
+        assert_eq!(wp1.earliest_start, on_date(2025, 12, 3));
+        assert_eq!(wp1.earliest_finish, on_date(2025, 12, 5));
+    }
+
+    fn build_constrained_node(
+        id: &str,
+        duration: f32,
+        dependencies: &[&str],
+        constraint: DateConstraint,
+    ) -> NetworkNode {
+        NetworkNode {
+            constraint: Some(constraint),
+            ..build_network_node(id, duration, dependencies)
+        }
+    }
+
+    fn find<'a>(result: &'a [ResultNode], id: &str) -> &'a ResultNode {
+        result.iter().find(|node| node.id == id).unwrap()
+    }
+
+    #[test]
+    fn start_no_earlier_than_delays_the_start() {
+        let network = vec![
+            build_network_node("WP0", 2.0, &[]),
+            build_constrained_node(
+                "WP1",
+                1.0,
+                &[],
+                DateConstraint::StartNoEarlierThan(on_date(2026, 1, 10)),
+            ),
+            build_network_node("FIN", 0.0, &["WP0", "WP1"]),
+        ];
+        let network = SortedNetworkNodes::new(network).unwrap();
+
+        let result = critical_path_method(network, on_date(2026, 1, 1), None).unwrap();
+
+        let wp1 = find(&result, "WP1");
+        assert_eq!(wp1.earliest_start, on_date(2026, 1, 10));
+        assert_eq!(wp1.earliest_finish, on_date(2026, 1, 11));
+        assert!(wp1.is_critical());
+        assert!(!wp1.constraint_violated);
+        assert_eq!(find(&result, "WP0").total_float, 8.0);
+    }
+
+    #[test]
+    fn missed_finish_constraints_lead_to_negative_total_float() {
+        let network = vec![
+            build_network_node("WP0", 3.0, &[]),
+            build_constrained_node(
+                "WP1",
+                2.0,
+                &["WP0"],
+                DateConstraint::MustFinishOnOrBefore(on_date(2026, 1, 4)),
+            ),
+            build_network_node("WP2", 1.0, &[]),
+        ];
+        let network = SortedNetworkNodes::new(network).unwrap();
+
+        let result = critical_path_method(network, on_date(2026, 1, 1), None).unwrap();
+
+        // WP1 works on the 4th and the 5th, one day longer than allowed.
+        let wp1 = find(&result, "WP1");
+        assert_eq!(wp1.earliest_finish, on_date(2026, 1, 6));
+        assert_eq!(wp1.latest_finish, on_date(2026, 1, 5));
+        assert!(wp1.constraint_violated);
+        assert_eq!(wp1.total_float, -1.0);
+        let wp0 = find(&result, "WP0");
+        assert_eq!(wp0.total_float, -1.0);
+        assert!(wp0.is_critical());
+        assert!(!wp0.constraint_violated);
+        assert_eq!(find(&result, "WP2").total_float, 4.0);
+    }
+
+    #[test]
+    fn fixed_date_milestones_wait_for_their_date() {
+        let milestone = |dependency_days: f32| {
+            let mut milestone = build_constrained_node(
+                "M1",
+                0.0,
+                &["WP0"],
+                DateConstraint::FixedDate(on_date(2026, 1, 10)),
+            );
+            milestone.is_milestone = true;
+            vec![
+                build_network_node("WP0", dependency_days, &[]),
+                milestone,
+                build_network_node("WP1", 1.0, &["M1"]),
+            ]
+        };
+
+        let network = SortedNetworkNodes::new(milestone(2.0)).unwrap();
+        let result = critical_path_method(network, on_date(2026, 1, 1), None).unwrap();
+        let m1 = find(&result, "M1");
+        assert_eq!(m1.earliest_finish, on_date(2026, 1, 10));
+        assert!(!m1.constraint_violated);
+        assert_eq!(find(&result, "WP1").earliest_start, on_date(2026, 1, 10));
+
+        let network = SortedNetworkNodes::new(milestone(12.0)).unwrap();
+        let result = critical_path_method(network, on_date(2026, 1, 1), None).unwrap();
+        let m1 = find(&result, "M1");
+        assert_eq!(m1.earliest_finish, on_date(2026, 1, 13));
+        assert!(m1.constraint_violated);
+        assert_eq!(m1.total_float, -2.0);
+    }
+
+    #[test]
+    fn finishing_on_the_constraint_date_is_no_violation() {
+        // Work from Monday the 5th to Friday the 9th finishes on Monday the 12th.
+        let network = vec![build_constrained_node(
+            "WP0",
+            5.0,
+            &[],
+            DateConstraint::MustFinishOnOrBefore(on_date(2026, 1, 9)),
+        )];
+        let network = SortedNetworkNodes::new(network).unwrap();
+        let calendar = TeamCalendar::new();
+
+        let result = critical_path_method(network, on_date(2026, 1, 5), Some(&calendar)).unwrap();
+
+        let wp0 = find(&result, "WP0");
+        assert_eq!(wp0.earliest_finish, on_date(2026, 1, 12));
+        assert!(!wp0.constraint_violated);
+        assert_eq!(wp0.total_float, 0.0);
+    }
+
     #[test]
     fn when_a_calendar_is_given_it_is_applied() {
         use crate::domain::calendar::Calendar;
@@ -0,0 +1,199 @@
+//! Durations of work packages drawn from the cycle times of done issues.
+//!
+//! Instead of converting estimates with a velocity, every remaining work
+//! package takes as long as a randomly chosen done issue did, optionally
+//! chosen among the done issues of the same size or with a shared label.
+
+use std::collections::HashMap;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::domain::cycle_time::CycleTime;
+use crate::domain::issue::Issue;
+use crate::services::project_simulation::project_simulation::ProjectSimulationError;
+
+/// Which done issues a work package draws its cycle time from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleTimeGrouping {
+    /// Done issues with the same story points.
+    Size,
+    /// Done issues sharing at least one label.
+    Label,
+}
+
+/// Draws durations in calendar days from a cycle time history. Work packages
+/// without a matching group sample from all cycle times.
+pub(crate) struct CycleTimeSampler {
+    all: Vec<f32>,
+    grouping: Option<CycleTimeGrouping>,
+    groups: HashMap<String, Vec<f32>>,
+}
+
+impl CycleTimeSampler {
+    pub fn new(
+        history: &[CycleTime],
+        grouping: Option<CycleTimeGrouping>,
+    ) -> Result<Self, ProjectSimulationError> {
+        if history.is_empty() {
+            return Err(ProjectSimulationError::EmptyCycleTimes);
+        }
+
+        let mut groups: HashMap<String, Vec<f32>> = HashMap::new();
+        for cycle_time in history {
+            let keys = match grouping {
+                Some(CycleTimeGrouping::Size) => {
+                    cycle_time.story_points.map(size_key).into_iter().collect()
+                }
+                Some(CycleTimeGrouping::Label) => cycle_time.labels.clone(),
+                None => Vec::new(),
+            };
+            for key in keys {
+                groups.entry(key).or_default().push(cycle_time.days as f32);
+            }
+        }
+
+        Ok(Self {
+            all: history
+                .iter()
+                .map(|cycle_time| cycle_time.days as f32)
+                .collect(),
+            grouping,
+            groups,
+        })
+    }
+
+    /// Samples the duration of `issue` in calendar days.
+    pub fn sample<R: Rng + ?Sized>(&self, issue: &Issue, rng: &mut R) -> f32 {
+        let pools: Vec<&Vec<f32>> = match self.grouping {
+            Some(CycleTimeGrouping::Size) => issue
+                .story_point_value()
+                .and_then(|points| self.groups.get(&size_key(points)))
+                .into_iter()
+                .collect(),
+            Some(CycleTimeGrouping::Label) => issue
+                .labels
+                .iter()
+                .filter_map(|label| self.groups.get(label))
+                .collect(),
+            None => Vec::new(),
+        };
+        if pools.is_empty() {
+            return self.all.choose(rng).copied().unwrap_or(0.0);
+        }
+
+        // Uniform over the union of the pools, without collecting them.
+        let total: usize = pools.iter().map(|pool| pool.len()).sum();
+        let mut index = rng.gen_range(0..total);
+        for pool in pools {
+            if index < pool.len() {
+                return pool[index];
+            }
+            index -= pool.len();
+        }
+        0.0
+    }
+}
+
+fn size_key(story_points: f32) -> String {
+    story_points.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::estimate::{Estimate, StoryPointEstimate};
+    use crate::test_support::on_date;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn cycle_time(days: i64, story_points: Option<f32>, labels: &[&str]) -> CycleTime {
+        CycleTime {
+            issue_id: format!("ISSUE-{days}"),
+            start_date: on_date(2026, 1, 1),
+            done_date: on_date(2026, 1, 1) + chrono::Duration::days(days - 1),
+            days,
+            story_points,
+            labels: labels.iter().map(|label| label.to_string()).collect(),
+        }
+    }
+
+    fn issue(story_points: Option<f32>, labels: &[&str]) -> Issue {
+        let mut issue = Issue::new();
+        issue.estimate = story_points.map(|estimate| {
+            Estimate::StoryPoint(StoryPointEstimate {
+                estimate: Some(estimate),
+            })
+        });
+        issue.labels = labels.iter().map(|label| label.to_string()).collect();
+        issue
+    }
+
+    fn sampled_days(sampler: &CycleTimeSampler, issue: &Issue) -> Vec<f32> {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut days: Vec<f32> = (0..200).map(|_| sampler.sample(issue, &mut rng)).collect();
+        days.sort_by(f32::total_cmp);
+        days.dedup();
+        days
+    }
+
+    #[test]
+    fn empty_history_is_rejected() {
+        let error = CycleTimeSampler::new(&[], None).err();
+
+        assert!(matches!(
+            error,
+            Some(ProjectSimulationError::EmptyCycleTimes)
+        ));
+    }
+
+    #[test]
+    fn ungrouped_sampling_uses_all_cycle_times() {
+        let history = [cycle_time(1, Some(1.0), &[]), cycle_time(8, Some(5.0), &[])];
+        let sampler = CycleTimeSampler::new(&history, None).unwrap();
+
+        assert_eq!(
+            sampled_days(&sampler, &issue(Some(1.0), &[])),
+            vec![1.0, 8.0]
+        );
+    }
+
+    #[test]
+    fn size_grouping_samples_issues_with_the_same_story_points() {
+        let history = [
+            cycle_time(1, Some(1.0), &[]),
+            cycle_time(2, Some(1.0), &[]),
+            cycle_time(8, Some(5.0), &[]),
+        ];
+        let sampler = CycleTimeSampler::new(&history, Some(CycleTimeGrouping::Size)).unwrap();
+
+        assert_eq!(
+            sampled_days(&sampler, &issue(Some(1.0), &[])),
+            vec![1.0, 2.0]
+        );
+        // No done issue has 3 story points.
+        assert_eq!(
+            sampled_days(&sampler, &issue(Some(3.0), &[])),
+            vec![1.0, 2.0, 8.0]
+        );
+    }
+
+    #[test]
+    fn label_grouping_samples_the_union_of_the_labels() {
+        let history = [
+            cycle_time(1, None, &["frontend"]),
+            cycle_time(4, None, &["backend"]),
+            cycle_time(9, None, &["ops"]),
+        ];
+        let sampler = CycleTimeSampler::new(&history, Some(CycleTimeGrouping::Label)).unwrap();
+
+        assert_eq!(
+            sampled_days(&sampler, &issue(None, &["frontend", "backend"])),
+            vec![1.0, 4.0]
+        );
+        assert_eq!(
+            sampled_days(&sampler, &issue(None, &[])),
+            vec![1.0, 4.0, 9.0]
+        );
+    }
+}
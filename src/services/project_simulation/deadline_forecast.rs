@@ -0,0 +1,75 @@
+//! Probability of meeting a target date, derived from the simulated end dates.
+
+use chrono::NaiveDate;
+
+use crate::services::project_simulation::percentiles;
+use crate::services::project_simulation::simulation_types::DeadlineForecast;
+
+/// Evaluates `due_date` against end dates sorted in ascending order.
+///
+/// The probability is the share of iterations finishing on or before the due
+/// date. The slack is measured from the P85 end date, so it turns negative as
+/// soon as the P85 forecast misses the due date.
+pub(crate) fn deadline_forecast(
+    id: Option<String>,
+    due_date: NaiveDate,
+    sorted_end_dates: &[NaiveDate],
+) -> DeadlineForecast {
+    let met = sorted_end_dates.partition_point(|end_date| *end_date <= due_date);
+    let probability = if sorted_end_dates.is_empty() {
+        0.0
+    } else {
+        met as f32 / sorted_end_dates.len() as f32
+    };
+    let slack_days_p85 = percentiles::get_percentile_value(sorted_end_dates, 85.0)
+        .map(|p85| (due_date - p85).num_days())
+        .unwrap_or(0);
+
+    DeadlineForecast {
+        id,
+        due_date,
+        probability,
+        slack_days_p85,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::on_date;
+
+    fn end_dates(days: &[u32]) -> Vec<NaiveDate> {
+        days.iter().map(|day| on_date(2026, 3, *day)).collect()
+    }
+
+    #[test]
+    fn probability_counts_end_dates_on_or_before_due_date() {
+        let dates = end_dates(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+        let forecast = deadline_forecast(Some("M1".to_string()), on_date(2026, 3, 5), &dates);
+
+        assert_eq!(forecast.id.as_deref(), Some("M1"));
+        assert_eq!(forecast.probability, 0.4);
+    }
+
+    #[test]
+    fn slack_is_measured_from_p85_end_date() {
+        // P85 of ten samples is the ninth value, 2026-03-10.
+        let dates = end_dates(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+        let early = deadline_forecast(None, on_date(2026, 3, 13), &dates);
+        let late = deadline_forecast(None, on_date(2026, 3, 7), &dates);
+
+        assert_eq!(early.slack_days_p85, 3);
+        assert_eq!(early.probability, 1.0);
+        assert_eq!(late.slack_days_p85, -3);
+    }
+
+    #[test]
+    fn empty_samples_never_meet_the_due_date() {
+        let forecast = deadline_forecast(None, on_date(2026, 3, 7), &[]);
+
+        assert_eq!(forecast.probability, 0.0);
+        assert_eq!(forecast.slack_days_p85, 0);
+    }
+}
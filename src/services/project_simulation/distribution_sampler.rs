@@ -0,0 +1,367 @@
+use rand::Rng;
+use rand_distr::{Distribution, LogNormal, Triangular};
+
+use crate::domain::estimate::DurationDistribution;
+use crate::services::project_simulation::beta_pert_sampler::{
+    PERT_SHAPE, ThreePointSampler, ThreePointSamplerError, pert_parameters, sample_pert,
+};
+use crate::services::project_simulation::special_functions::{
+    beta_quantile, standard_normal_quantile,
+};
+
+/// z-score of the 90th percentile of the standard normal distribution.
+const Z_90: f64 = 1.281_551_6;
+
+/// Samples three-point estimates from the distribution they select, or from
+/// the project's `default` distribution if they select none.
+pub struct DistributionSampler<R: Rng> {
+    rng: R,
+    default: DurationDistribution,
+}
+
+impl<R: Rng> DistributionSampler<R> {
+    pub fn new(rng: R, default: DurationDistribution) -> Self {
+        Self { rng, default }
+    }
+}
+
+impl<R: Rng> ThreePointSampler for DistributionSampler<R> {
+    fn sample(
+        &mut self,
+        optimistic: f32,
+        most_likely: f32,
+        pessimistic: f32,
+    ) -> Result<f32, ThreePointSamplerError> {
+        self.sample_distribution(None, optimistic, most_likely, pessimistic)
+    }
+
+    fn sample_distribution(
+        &mut self,
+        distribution: Option<DurationDistribution>,
+        optimistic: f32,
+        most_likely: f32,
+        pessimistic: f32,
+    ) -> Result<f32, ThreePointSamplerError> {
+        let distribution = distribution.unwrap_or(self.default);
+        match distribution {
+            DurationDistribution::BetaPert => sample_pert(
+                &mut self.rng,
+                optimistic,
+                most_likely,
+                pessimistic,
+                PERT_SHAPE,
+            ),
+            DurationDistribution::ModifiedPert { shape } => {
+                sample_pert(&mut self.rng, optimistic, most_likely, pessimistic, shape)
+            }
+            _ => sample_other(
+                &mut self.rng,
+                distribution,
+                optimistic,
+                most_likely,
+                pessimistic,
+            ),
+        }
+    }
+
+    fn sample_at_quantile(
+        &mut self,
+        distribution: Option<DurationDistribution>,
+        optimistic: f32,
+        most_likely: f32,
+        pessimistic: f32,
+        quantile: f64,
+    ) -> Result<f32, ThreePointSamplerError> {
+        quantile_value(
+            distribution.unwrap_or(self.default),
+            optimistic,
+            most_likely,
+            pessimistic,
+            quantile,
+        )
+    }
+}
+
+/// The value below which `quantile` of the distribution's samples fall.
+pub(crate) fn quantile_value(
+    distribution: DurationDistribution,
+    optimistic: f32,
+    most_likely: f32,
+    pessimistic: f32,
+    quantile: f64,
+) -> Result<f32, ThreePointSamplerError> {
+    let shape = match distribution {
+        DurationDistribution::BetaPert => Some(PERT_SHAPE),
+        DurationDistribution::ModifiedPert { shape } => Some(shape),
+        _ => None,
+    };
+    if let Some(shape) = shape {
+        let Some((alpha, beta)) = pert_parameters(optimistic, most_likely, pessimistic, shape)?
+        else {
+            return Ok(optimistic);
+        };
+        let fraction = beta_quantile(alpha, beta, quantile) as f32;
+        return Ok(optimistic + fraction * (pessimistic - optimistic));
+    }
+    if !validate_range(optimistic, most_likely, pessimistic)? {
+        return Ok(optimistic);
+    }
+
+    // Quantiles of exactly 0 or 1 would be unbounded for the log-normal.
+    let quantile = quantile.clamp(f64::EPSILON, 1.0 - f64::EPSILON);
+    let (optimistic, most_likely, pessimistic) =
+        (optimistic as f64, most_likely as f64, pessimistic as f64);
+    let range = pessimistic - optimistic;
+    let value = match distribution {
+        DurationDistribution::Triangular => {
+            let mode = (most_likely - optimistic) / range;
+            if quantile < mode {
+                optimistic + (quantile * range * (most_likely - optimistic)).sqrt()
+            } else {
+                pessimistic - ((1.0 - quantile) * range * (pessimistic - most_likely)).sqrt()
+            }
+        }
+        DurationDistribution::Uniform => optimistic + quantile * range,
+        DurationDistribution::LogNormal => {
+            if optimistic <= 0.0 {
+                return Err(ThreePointSamplerError::NonPositiveOptimistic);
+            }
+            let (low, high) = (optimistic.ln(), pessimistic.ln());
+            let sigma = (high - low) / (2.0 * Z_90);
+            ((low + high) / 2.0 + sigma * standard_normal_quantile(quantile)).exp()
+        }
+        DurationDistribution::BetaPert | DurationDistribution::ModifiedPert { .. } => {
+            unreachable!("PERT variants are handled above")
+        }
+    };
+    Ok(value as f32)
+}
+
+/// Validates an estimate for the distributions that are not PERT variants.
+/// Returns false if the estimate has no spread.
+fn validate_range(
+    optimistic: f32,
+    most_likely: f32,
+    pessimistic: f32,
+) -> Result<bool, ThreePointSamplerError> {
+    if pessimistic < optimistic {
+        return Err(ThreePointSamplerError::PessimisticLessThanOptimistic);
+    }
+    if most_likely < optimistic || most_likely > pessimistic {
+        return Err(ThreePointSamplerError::MostLikelyOutOfRange);
+    }
+    Ok((pessimistic - optimistic).abs() >= f32::EPSILON)
+}
+
+/// Samples the distributions that are not PERT variants.
+fn sample_other<R: Rng + ?Sized>(
+    rng: &mut R,
+    distribution: DurationDistribution,
+    optimistic: f32,
+    most_likely: f32,
+    pessimistic: f32,
+) -> Result<f32, ThreePointSamplerError> {
+    if !validate_range(optimistic, most_likely, pessimistic)? {
+        return Ok(optimistic);
+    }
+
+    let (optimistic, most_likely, pessimistic) =
+        (optimistic as f64, most_likely as f64, pessimistic as f64);
+    let sample = match distribution {
+        DurationDistribution::Triangular => {
+            Triangular::new(optimistic, pessimistic, most_likely)?.sample(rng)
+        }
+        DurationDistribution::Uniform => rng.gen_range(optimistic..=pessimistic),
+        DurationDistribution::LogNormal => {
+            if optimistic <= 0.0 {
+                return Err(ThreePointSamplerError::NonPositiveOptimistic);
+            }
+            let (low, high) = (optimistic.ln(), pessimistic.ln());
+            LogNormal::new((low + high) / 2.0, (high - low) / (2.0 * Z_90))?.sample(rng)
+        }
+        DurationDistribution::BetaPert | DurationDistribution::ModifiedPert { .. } => {
+            unreachable!("PERT variants are sampled by sample_pert")
+        }
+    };
+    Ok(sample as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    const SAMPLES: usize = 100_000;
+
+    /// Mean and variance of many samples of the estimate 2 / 4 / 12.
+    fn moments(distribution: DurationDistribution) -> (f64, f64) {
+        let mut sampler = DistributionSampler::new(StdRng::seed_from_u64(3), distribution);
+        let samples: Vec<f64> = (0..SAMPLES)
+            .map(|_| sampler.sample(2.0, 4.0, 12.0).unwrap() as f64)
+            .collect();
+        let mean = samples.iter().sum::<f64>() / SAMPLES as f64;
+        let variance =
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (SAMPLES - 1) as f64;
+        (mean, variance)
+    }
+
+    fn assert_close(actual: f64, expected: f64, name: &str) {
+        let tolerance = 0.02 * expected.abs();
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{name}: expected {expected}, got {actual}"
+        );
+    }
+
+    /// Mean and variance of a PERT beta distribution over 2 / 4 / 12.
+    fn pert_moments(shape: f64) -> (f64, f64) {
+        let (a, m, b) = (2.0, 4.0, 12.0);
+        let alpha = 1.0 + shape * (m - a) / (b - a);
+        let beta = 1.0 + shape * (b - m) / (b - a);
+        let mean = a + (b - a) * alpha / (alpha + beta);
+        let variance = (b - a) * (b - a) * alpha * beta
+            / ((alpha + beta) * (alpha + beta) * (alpha + beta + 1.0));
+        (mean, variance)
+    }
+
+    #[test]
+    fn beta_pert_matches_classic_pert() {
+        let (mean, variance) = moments(DurationDistribution::BetaPert);
+        let (expected_mean, expected_variance) = pert_moments(4.0);
+
+        // (2 + 4 * 4 + 12) / 6
+        assert_close(expected_mean, 5.0, "classic PERT mean");
+        assert_close(mean, expected_mean, "mean");
+        assert_close(variance, expected_variance, "variance");
+    }
+
+    #[test]
+    fn modified_pert_narrows_with_a_higher_shape() {
+        let (mean, variance) = moments(DurationDistribution::ModifiedPert { shape: 8.0 });
+        let (expected_mean, expected_variance) = pert_moments(8.0);
+
+        // (2 + 8 * 4 + 12) / 10
+        assert_close(expected_mean, 4.6, "modified PERT mean");
+        assert_close(mean, expected_mean, "mean");
+        assert_close(variance, expected_variance, "variance");
+        assert!(variance < pert_moments(4.0).1);
+    }
+
+    #[test]
+    fn triangular_has_the_mean_of_its_corners() {
+        let (mean, variance) = moments(DurationDistribution::Triangular);
+
+        assert_close(mean, 6.0, "mean");
+        // (a² + b² + c² - ab - ac - bc) / 18
+        assert_close(variance, 84.0 / 18.0, "variance");
+    }
+
+    #[test]
+    fn uniform_ignores_the_most_likely_value() {
+        let (mean, variance) = moments(DurationDistribution::Uniform);
+
+        assert_close(mean, 7.0, "mean");
+        assert_close(variance, 100.0 / 12.0, "variance");
+    }
+
+    #[test]
+    fn log_normal_has_optimistic_and_pessimistic_as_p10_and_p90() {
+        let (mean, variance) = moments(DurationDistribution::LogNormal);
+        let mu = (2.0_f64.ln() + 12.0_f64.ln()) / 2.0;
+        let sigma = (12.0_f64.ln() - 2.0_f64.ln()) / (2.0 * Z_90);
+
+        assert_close(mean, (mu + sigma * sigma / 2.0).exp(), "mean");
+        assert_close(
+            variance,
+            ((sigma * sigma).exp() - 1.0) * (2.0 * mu + sigma * sigma).exp(),
+            "variance",
+        );
+
+        let mut sampler =
+            DistributionSampler::new(StdRng::seed_from_u64(3), DurationDistribution::LogNormal);
+        let below_optimistic = (0..SAMPLES)
+            .filter(|_| sampler.sample(2.0, 4.0, 12.0).unwrap() < 2.0)
+            .count() as f64;
+        assert_close(below_optimistic / SAMPLES as f64, 0.1, "P10");
+    }
+
+    #[test]
+    fn log_normal_rejects_non_positive_optimistic_values() {
+        let mut sampler =
+            DistributionSampler::new(StdRng::seed_from_u64(1), DurationDistribution::LogNormal);
+
+        assert_eq!(
+            sampler.sample(0.0, 1.0, 2.0),
+            Err(ThreePointSamplerError::NonPositiveOptimistic)
+        );
+    }
+
+    #[test]
+    fn quantile_values_match_the_sampled_distribution() {
+        let distributions = [
+            DurationDistribution::BetaPert,
+            DurationDistribution::ModifiedPert { shape: 8.0 },
+            DurationDistribution::Triangular,
+            DurationDistribution::Uniform,
+            DurationDistribution::LogNormal,
+        ];
+        for distribution in distributions {
+            let mut sampler = DistributionSampler::new(StdRng::seed_from_u64(3), distribution);
+            let samples: Vec<f32> = (0..SAMPLES)
+                .map(|_| sampler.sample(2.0, 4.0, 12.0).unwrap())
+                .collect();
+
+            let mut previous = f32::MIN;
+            for quantile in [0.1, 0.5, 0.85] {
+                let value = quantile_value(distribution, 2.0, 4.0, 12.0, quantile).unwrap();
+                let below = samples.iter().filter(|sample| **sample < value).count();
+                assert_close(
+                    below as f64 / SAMPLES as f64,
+                    quantile,
+                    &format!("{distribution:?} at {quantile}"),
+                );
+                assert!(value > previous);
+                previous = value;
+            }
+        }
+    }
+
+    #[test]
+    fn quantile_values_stay_within_the_estimate() {
+        let lowest = quantile_value(DurationDistribution::Triangular, 2.0, 4.0, 12.0, 0.0);
+        let highest = quantile_value(DurationDistribution::BetaPert, 2.0, 4.0, 12.0, 1.0);
+        let fixed = quantile_value(DurationDistribution::Uniform, 3.0, 3.0, 3.0, 0.7);
+
+        assert_eq!(lowest, Ok(2.0));
+        assert_eq!(highest, Ok(12.0));
+        assert_eq!(fixed, Ok(3.0));
+        assert_eq!(
+            quantile_value(DurationDistribution::LogNormal, 0.0, 1.0, 2.0, 0.5),
+            Err(ThreePointSamplerError::NonPositiveOptimistic)
+        );
+    }
+
+    #[test]
+    fn estimate_distribution_overrides_the_default() {
+        let mut sampler =
+            DistributionSampler::new(StdRng::seed_from_u64(1), DurationDistribution::LogNormal);
+
+        // Log-normal would reject the optimistic value of 0.
+        for _ in 0..100 {
+            let sample = sampler
+                .sample_distribution(Some(DurationDistribution::Triangular), 0.0, 1.0, 2.0)
+                .unwrap();
+            assert!((0.0..=2.0).contains(&sample));
+        }
+        assert_eq!(
+            sampler.sample_distribution(
+                Some(DurationDistribution::ModifiedPert { shape: -1.0 }),
+                1.0,
+                2.0,
+                3.0,
+            ),
+            Err(ThreePointSamplerError::InvalidShape(-1.0))
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! Forecasts how many items will be finished by a target date.
+//!
+//! This is the inverse of `throughput_simulation`: instead of sampling days
+//! until a fixed number of items is done, every iteration samples the daily
+//! throughput from `start_date` up to and including `end_date` and records the
+//! number of finished items.
+
+use chrono::NaiveDate;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::domain::calendar::TeamCalendar;
+use crate::domain::throughput::ThroughputHistory;
+use crate::services::parsing::team_calendar_yaml::load_team_calendar_if_provided;
+use crate::services::parsing::throughput_yaml::deserialize_throughput_from_yaml_str;
+use crate::services::project_simulation::percentiles;
+use crate::services::project_simulation::report_percentiles::with_standard_percentiles;
+use crate::services::project_simulation::simulation_types::{
+    ItemCountPercentile, ItemForecastOutput, ItemForecastReport,
+};
+use crate::services::project_simulation::throughput_sampler::{SamplingOptions, ThroughputSampler};
+use crate::services::project_simulation::throughput_simulation::SimulationError;
+use crate::services::util::data_source_name;
+
+pub(crate) fn simulate_items_from_throughput_file(
+    throughput_path: &str,
+    iterations: usize,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    calendar_path: Option<&str>,
+    seed: u64,
+    percentiles: &[f64],
+) -> Result<ItemForecastOutput, SimulationError> {
+    let throughput_yaml = std::fs::read_to_string(throughput_path)?;
+    let history = deserialize_throughput_from_yaml_str(&throughput_yaml)?;
+
+    let calendar = load_team_calendar_if_provided(calendar_path)?;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut simulation = run_items_simulation_with_rng(
+        &history, iterations, start_date, end_date, &calendar, &mut rng,
+    )?;
+    simulation.report.percentiles =
+        item_count_percentiles(&simulation.results, &with_standard_percentiles(percentiles));
+    simulation.report.data_source = data_source_name(throughput_path);
+    simulation.report.seed = Some(seed);
+    Ok(simulation)
+}
+
+pub(crate) fn run_items_simulation_with_rng<R: Rng + ?Sized>(
+    history: &ThroughputHistory,
+    iterations: usize,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    calendar: &TeamCalendar,
+    rng: &mut R,
+) -> Result<ItemForecastOutput, SimulationError> {
+    if iterations == 0 {
+        return Err(SimulationError::InvalidIterations);
+    }
+    if end_date < start_date {
+        return Err(SimulationError::EndDateBeforeStartDate {
+            start_date,
+            end_date,
+        });
+    }
+    let sampler = ThroughputSampler::new(history, SamplingOptions::default())?;
+    let capacities: Vec<f32> = start_date
+        .iter_days()
+        .take_while(|date| *date <= end_date)
+        .map(|date| calendar.get_capacity(date).max(0.0))
+        .collect();
+
+    let mut results: Vec<f32> = (0..iterations)
+        .map(|_| {
+            simulate_single_run(
+                &sampler,
+                history.bucket.length_days() as usize,
+                &capacities,
+                rng,
+            ) as f32
+        })
+        .collect();
+    results.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let report = ItemForecastReport {
+        data_source: String::new(),
+        start_date,
+        end_date,
+        iterations,
+        seed: None,
+        percentiles: item_count_percentiles(&results, &with_standard_percentiles(&[])),
+    };
+
+    Ok(ItemForecastOutput { report, results })
+}
+
+/// Samples one value per bucket of `bucket_days`, applied to the capacity of
+/// the days in that bucket.
+fn simulate_single_run<R: Rng + ?Sized>(
+    sampler: &ThroughputSampler,
+    bucket_days: usize,
+    capacities: &[f32],
+    rng: &mut R,
+) -> usize {
+    let completed: f32 = capacities
+        .chunks(bucket_days)
+        .map(|bucket_capacities| {
+            let sampled_throughput = sampler.sample(rng);
+            sampled_throughput * bucket_capacities.iter().sum::<f32>()
+        })
+        .sum();
+
+    completed.floor() as usize
+}
+
+/// Percentile `p` is the item count that was reached or exceeded in `p`
+/// percent of the iterations, so higher percentiles are more conservative,
+/// just like for completion dates.
+fn item_count_percentiles(sorted_counts: &[f32], percentiles: &[f64]) -> Vec<ItemCountPercentile> {
+    percentiles
+        .iter()
+        .map(|&percentile| ItemCountPercentile {
+            percentile,
+            items: percentiles::get_percentile_value_f32(sorted_counts, 100.0 - percentile)
+                as usize,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::throughput::{Throughput, ThroughputBucket};
+    use crate::test_support::{create_calendar_without_any_free_days, on_date};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn throughput(values: &[usize]) -> ThroughputHistory {
+        ThroughputHistory {
+            bucket: ThroughputBucket::Day,
+            throughput: values
+                .iter()
+                .enumerate()
+                .map(|(offset, completed_issues)| Throughput {
+                    date: on_date(2026, 1, 1) + chrono::Duration::days(offset as i64),
+                    completed_issues: *completed_issues,
+                    capacity: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn constant_throughput_counts_items_on_workdays_only() {
+        let mut rng = StdRng::seed_from_u64(1);
+        // Monday to Sunday with the default calendar: five workdays.
+        let simulation = run_items_simulation_with_rng(
+            &throughput(&[2]),
+            5,
+            on_date(2026, 2, 16),
+            on_date(2026, 2, 22),
+            &TeamCalendar::new(),
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(simulation.results, vec![10.0; 5]);
+        assert!(simulation.report.percentiles.iter().all(|p| p.items == 10));
+    }
+
+    #[test]
+    fn weekly_samples_apply_to_the_capacity_of_whole_weeks() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let weeks = ThroughputHistory {
+            bucket: ThroughputBucket::Week,
+            throughput: vec![Throughput {
+                date: on_date(2026, 1, 5),
+                completed_issues: 10,
+                capacity: Some(5.0),
+            }],
+        };
+
+        // Two weeks and a Monday with the default calendar.
+        let simulation = run_items_simulation_with_rng(
+            &weeks,
+            3,
+            on_date(2026, 2, 16),
+            on_date(2026, 3, 2),
+            &TeamCalendar::new(),
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(simulation.results, vec![22.0; 3]);
+    }
+
+    #[test]
+    fn end_date_is_inclusive() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let calendar = create_calendar_without_any_free_days();
+
+        let simulation = run_items_simulation_with_rng(
+            &throughput(&[1]),
+            1,
+            on_date(2026, 2, 16),
+            on_date(2026, 2, 16),
+            &calendar,
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(simulation.results, vec![1.0]);
+    }
+
+    #[test]
+    fn higher_percentiles_report_fewer_items() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let calendar = create_calendar_without_any_free_days();
+
+        let simulation = run_items_simulation_with_rng(
+            &throughput(&[0, 1, 2, 5]),
+            500,
+            on_date(2026, 2, 2),
+            on_date(2026, 2, 20),
+            &calendar,
+            &mut rng,
+        )
+        .unwrap();
+
+        let items: Vec<usize> = simulation
+            .report
+            .percentiles
+            .iter()
+            .map(|p| p.items)
+            .collect();
+        assert!(items.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert!(items.first() > items.last());
+    }
+
+    #[test]
+    fn rejects_end_date_before_start_date() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let error = run_items_simulation_with_rng(
+            &throughput(&[1]),
+            10,
+            on_date(2026, 2, 16),
+            on_date(2026, 2, 15),
+            &TeamCalendar::new(),
+            &mut rng,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            SimulationError::EndDateBeforeStartDate { .. }
+        ));
+    }
+
+    #[test]
+    fn simulate_items_from_throughput_file_sets_report_fields() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let input_path = std::env::temp_dir().join(format!("items-{nanos}.yaml"));
+        std::fs::write(&input_path, "- date: 2026-01-01\n  completed_issues: 2\n").unwrap();
+
+        let output = simulate_items_from_throughput_file(
+            input_path.to_str().unwrap(),
+            20,
+            on_date(2026, 2, 16),
+            on_date(2026, 2, 20),
+            None,
+            3,
+            &[70.0],
+        )
+        .unwrap();
+
+        let report = output.report;
+        assert_eq!(
+            report.data_source,
+            input_path.file_name().unwrap().to_str().unwrap()
+        );
+        assert_eq!(report.seed, Some(3));
+        assert_eq!(report.percentiles.len(), 6);
+        assert!(report.percentiles.iter().any(|p| p.percentile == 70.0));
+    }
+}
@@ -1,9 +1,21 @@
 pub(crate) mod beta_pert_sampler;
+pub(crate) mod correlation;
 pub(crate) mod critical_path_method;
+pub(crate) mod cycle_time_sampler;
+pub(crate) mod deadline_forecast;
+pub(crate) mod distribution_sampler;
+pub(crate) mod items_by_date_simulation;
 pub(crate) mod network_nodes;
 pub(crate) mod percentiles;
+#[allow(clippy::module_inception)]
 pub(crate) mod project_simulation;
+pub(crate) mod report_percentiles;
+pub(crate) mod risk_events;
 pub(crate) mod sample_duration;
+pub(crate) mod scope_growth;
+pub(crate) mod sensitivity;
 pub(crate) mod simulation_types;
+pub(crate) mod special_functions;
+pub(crate) mod throughput_sampler;
 pub(crate) mod throughput_simulation;
 pub(crate) mod velocity_calculation;
@@ -1,10 +1,13 @@
+use crate::domain::issue::{DateConstraint, Issue};
 use crate::domain::project::Project;
 use crate::services::project_simulation::beta_pert_sampler::ThreePointSampler;
+use crate::services::project_simulation::cycle_time_sampler::CycleTimeSampler;
 use crate::services::project_simulation::sample_duration::SamplingError;
 use crate::services::project_simulation::sample_duration::sample_duration_days;
 use chrono::NaiveDate;
 use petgraph::algo::toposort;
 use petgraph::graph::DiGraph;
+use rand::Rng;
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -31,6 +34,7 @@ pub struct NetworkNode {
     pub duration: f32,
     pub start_date: Option<NaiveDate>,
     pub end_date: Option<NaiveDate>,
+    pub constraint: Option<DateConstraint>,
     pub dependencies: Vec<String>,
 }
 
@@ -51,6 +55,52 @@ pub fn build_network_nodes<R: ThreePointSampler + ?Sized>(
     project: &Project,
     velocity: Option<f32>,
     sampler: &mut R,
+) -> Result<Vec<NetworkNode>, NetworkNodesError> {
+    build_correlated_network_nodes(project, velocity, sampler, &[])
+}
+
+/// Builds the nodes like `build_network_nodes`, but samples a work package
+/// at its quantile in `quantiles`, which is indexed like the work packages.
+/// Packages without a quantile are sampled independently.
+pub fn build_correlated_network_nodes<R: ThreePointSampler + ?Sized>(
+    project: &Project,
+    velocity: Option<f32>,
+    sampler: &mut R,
+    quantiles: &[Option<f64>],
+) -> Result<Vec<NetworkNode>, NetworkNodesError> {
+    let mut quantiles = quantiles.iter().copied();
+    build_nodes(project, |issue, id| {
+        let quantile = quantiles.next().flatten();
+        let estimate = issue
+            .estimate
+            .clone()
+            .ok_or_else(|| NetworkNodesError::MissingEstimate(id.to_string()))?;
+        Ok(sample_duration_days(
+            &estimate, velocity, sampler, quantile, id,
+        )?)
+    })
+}
+
+/// Builds the nodes with durations drawn from cycle times instead of
+/// estimates, so work packages need no estimate. Done work packages and
+/// milestones take no time.
+pub fn build_cycle_time_network_nodes<R: Rng + ?Sized>(
+    project: &Project,
+    sampler: &CycleTimeSampler,
+    rng: &mut R,
+) -> Result<Vec<NetworkNode>, NetworkNodesError> {
+    build_nodes(project, |issue, _| {
+        if issue.is_milestone() || issue.done_date.is_some() {
+            Ok(0.0)
+        } else {
+            Ok(sampler.sample(issue, rng))
+        }
+    })
+}
+
+fn build_nodes(
+    project: &Project,
+    mut duration: impl FnMut(&Issue, &str) -> Result<f32, NetworkNodesError>,
 ) -> Result<Vec<NetworkNode>, NetworkNodesError> {
     let mut nodes = Vec::with_capacity(project.work_packages.len());
 
@@ -62,17 +112,13 @@ pub fn build_network_nodes<R: ThreePointSampler + ?Sized>(
             .ok_or(NetworkNodesError::MissingIssueId)?;
         let start_date = issue.start_date;
         let end_date = issue.done_date;
-        let estimate = issue
-            .estimate
-            .clone()
-            .ok_or_else(|| NetworkNodesError::MissingEstimate(id.clone()))?;
         let dependencies = issue
             .dependencies
             .as_ref()
             .map(|deps| deps.iter().map(|dep| dep.id.clone()).collect())
             .unwrap_or_default();
 
-        let duration = sample_duration_days(&estimate, velocity, sampler, &id)?;
+        let duration = duration(issue, &id)?;
 
         nodes.push(NetworkNode {
             id,
@@ -80,6 +126,7 @@ pub fn build_network_nodes<R: ThreePointSampler + ?Sized>(
             duration,
             start_date,
             end_date,
+            constraint: issue.constraint,
             dependencies,
         });
     }
@@ -104,13 +151,14 @@ fn topological_sort(network: Vec<NetworkNode>) -> Result<Vec<NetworkNode>, Netwo
         nodes_by_index.insert(graph_node_index, node);
     }
 
-    // Add edges to graph based on dependencies
-    for (graph_node_index, node) in &nodes_by_index {
-        for dependency in &node.dependencies {
+    // Add edges to graph based on dependencies, in input order so that the
+    // sort order does not depend on the hash map iteration order.
+    for graph_node_index in graph.node_indices().collect::<Vec<_>>() {
+        for dependency in &nodes_by_index[&graph_node_index].dependencies {
             let dependency_index = index_by_id
                 .get(dependency)
                 .ok_or_else(|| NetworkNodesError::MissingDependency(dependency.clone()))?;
-            graph.add_edge(*dependency_index, *graph_node_index, ());
+            graph.add_edge(*dependency_index, graph_node_index, ());
         }
     }
 
@@ -137,7 +185,8 @@ mod tests {
             duration,
             start_date: None,
             end_date: None,
-            dependencies: dependencies.into_iter().map(|s| s.to_string()).collect(),
+            constraint: None,
+            dependencies: dependencies.iter().map(|s| s.to_string()).collect(),
         }
     }
 
@@ -1,10 +1,10 @@
-/// Percentile helpers for already-sorted slices.
-///
-/// - Empty input => `None` (or `0.0` for the f32 convenience wrapper).
-/// - `percentile <= 0` => first element.
-/// - `percentile >= 100` => last element.
-/// - Otherwise we compute a position within `[0, len-1]` and round to the
-///   nearest index.
+//! Percentile helpers for already-sorted slices.
+//!
+//! - Empty input => `None` (or `0.0` for the f32 convenience wrapper).
+//! - `percentile <= 0` => first element.
+//! - `percentile >= 100` => last element.
+//! - Otherwise we compute a position within `[0, len-1]` and round to the
+//!   nearest index.
 
 /// Returns the percentile value from a slice that is already sorted in
 /// ascending order.
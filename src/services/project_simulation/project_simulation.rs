@@ -3,9 +3,20 @@ use std::collections::HashMap;
 use thiserror::Error;
 
 use crate::domain::calendar::TeamCalendar;
+use crate::domain::cycle_time::CycleTime;
+use crate::domain::estimate::{DurationDistribution, Estimate, ThreePointEstimate};
+use crate::domain::issue::DateConstraint;
 
+use crate::services::project_simulation::correlation::{CorrelatedQuantiles, CorrelationOptions};
+use crate::services::project_simulation::cycle_time_sampler::{
+    CycleTimeGrouping, CycleTimeSampler,
+};
 use crate::services::project_simulation::network_nodes::SortedNetworkNodes;
+#[cfg(test)]
 use crate::services::project_simulation::network_nodes::build_network_nodes;
+use crate::services::project_simulation::network_nodes::{
+    NetworkNode, build_correlated_network_nodes, build_cycle_time_network_nodes,
+};
 
 use chrono::NaiveDate;
 
@@ -14,25 +25,69 @@ use crate::services::parsing::project_yaml::{ProjectYamlError, load_project_from
 use crate::services::parsing::team_calendar_yaml::TeamCalendarYamlError;
 use crate::services::parsing::team_calendar_yaml::load_team_calendar_if_provided;
 use crate::services::plotting::histogram::HistogramError;
-use crate::services::project_simulation::beta_pert_sampler::BetaPertSampler;
+#[cfg(test)]
 use crate::services::project_simulation::beta_pert_sampler::ThreePointSampler;
+use crate::services::project_simulation::deadline_forecast::deadline_forecast;
+use crate::services::project_simulation::distribution_sampler::DistributionSampler;
 use crate::services::project_simulation::percentiles;
+use crate::services::project_simulation::report_percentiles::{
+    Percentiles, with_standard_percentiles,
+};
+use crate::services::project_simulation::risk_events::RiskRoller;
 use crate::services::project_simulation::sample_duration::SamplingError;
+use crate::services::project_simulation::sensitivity::{criticality_index, duration_correlation};
 use crate::services::project_simulation::simulation_types::{
-    SimulationOutput, SimulationPercentile, SimulationReport, WorkPackagePercentiles,
-    WorkPackageSimulation,
+    ConstraintForecast, ConstraintType, CorrelationForecast, DeadlineForecast, RiskForecast,
+    SimulationOutput, SimulationPercentile, SimulationReport, WorkPackageSimulation,
 };
 use crate::services::project_simulation::velocity_calculation::VelocityCalculationError;
 use crate::services::project_simulation::velocity_calculation::calculate_project_velocity;
 use crate::services::util::data_source_name;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
 
 use crate::services::project_simulation::critical_path_method::CriticalPathMethodError;
-use crate::services::project_simulation::critical_path_method::critical_path_method;
+use crate::services::project_simulation::critical_path_method::critical_path_method_with_wip_limit;
 use crate::services::project_simulation::network_nodes::NetworkNodesError;
 
+/// Number of iterations that share one seeded sampler.
+const ITERATIONS_PER_CHUNK: usize = 1000;
+
 #[derive(Debug, Clone, Copy)]
 struct WorkItemSample {
     end_date: chrono::NaiveDate,
+    duration: f32,
+    is_critical: bool,
+    total_float: f32,
+    constraint_violated: bool,
+}
+
+/// Samples collected over a number of iterations, either by a single
+/// serial run or by one worker of a parallel run. The samples of every work
+/// package are stored in the same iteration order as `project_end_dates`.
+#[derive(Debug, Default)]
+struct IterationSamples {
+    project_end_dates: Vec<chrono::NaiveDate>,
+    samples_by_id: HashMap<String, Vec<WorkItemSample>>,
+    /// Whether each risk of the project occurred, per iteration in the order
+    /// of `project_end_dates`.
+    risk_occurred: Vec<Vec<bool>>,
+}
+
+impl IterationSamples {
+    fn merge(&mut self, other: IterationSamples) {
+        self.project_end_dates.extend(other.project_end_dates);
+        for (id, samples) in other.samples_by_id {
+            self.samples_by_id.entry(id).or_default().extend(samples);
+        }
+        if self.risk_occurred.len() < other.risk_occurred.len() {
+            self.risk_occurred
+                .resize(other.risk_occurred.len(), Vec::new());
+        }
+        for (merged, occurred) in self.risk_occurred.iter_mut().zip(other.risk_occurred) {
+            merged.extend(occurred);
+        }
+    }
 }
 
 #[derive(Error, Debug)]
@@ -47,6 +102,8 @@ pub enum ProjectSimulationError {
     VelocityCalculation(#[from] VelocityCalculationError),
     #[error("iterations must be greater than zero")]
     InvalidIterations,
+    #[error("number of threads must be greater than zero")]
+    InvalidThreads,
     #[error("project has no work packages")]
     EmptyProject,
     #[error("failed to render histogram: {0}")]
@@ -57,49 +114,242 @@ pub enum ProjectSimulationError {
     CriticalPathMethod(#[from] CriticalPathMethodError),
     #[error("failed to build network nodes: {0}")]
     NetworkNodes(#[from] NetworkNodesError),
+    #[error("cycle time history is empty")]
+    EmptyCycleTimes,
+    #[error("{0} needs a positive optimistic value for the log-normal distribution")]
+    NonPositiveLogNormal(String),
+}
+
+/// Settings of a project simulation run.
+#[derive(Debug, Clone)]
+pub struct ProjectSimulationOptions {
+    pub iterations: usize,
+    pub start_date: NaiveDate,
+    /// Number of worker threads the iterations are split across.
+    pub threads: usize,
+    /// Seed of the random number generator; the same seed reproduces the report.
+    pub seed: u64,
+    /// Percentiles reported in addition to `STANDARD_PERCENTILES`.
+    pub percentiles: Vec<f64>,
+    /// Project deadline whose hit probability is reported.
+    pub deadline: Option<NaiveDate>,
+    /// Samples durations from these cycle times instead of the estimates.
+    pub cycle_times: Option<CycleTimeOptions>,
+    /// Maximum number of work packages in progress at the same time.
+    pub wip_limit: Option<usize>,
+    /// Distribution of three-point estimates that do not select their own.
+    pub distribution: DurationDistribution,
+    /// Correlates the estimation errors of grouped work packages. Cycle
+    /// times are sampled independently.
+    pub correlation: Option<CorrelationOptions>,
+}
+
+/// Cycle times of done issues that remaining work packages are sampled from.
+#[derive(Debug, Clone)]
+pub struct CycleTimeOptions {
+    pub history: Vec<CycleTime>,
+    pub grouping: Option<CycleTimeGrouping>,
+}
+
+impl ProjectSimulationOptions {
+    /// Single threaded run with seed 0, the standard percentiles, no deadline
+    /// and no WIP limit, sampling from the uncorrelated estimates with
+    /// classic PERT.
+    pub fn new(iterations: usize, start_date: NaiveDate) -> Self {
+        Self {
+            iterations,
+            start_date,
+            threads: 1,
+            seed: 0,
+            percentiles: Vec::new(),
+            deadline: None,
+            cycle_times: None,
+            wip_limit: None,
+            distribution: DurationDistribution::default(),
+            correlation: None,
+        }
+    }
 }
 
 pub fn simulate_project_from_yaml_file(
     path: &str,
-    iterations: usize,
-    start_date: NaiveDate,
     calendar_path: Option<&str>,
+    options: &ProjectSimulationOptions,
 ) -> Result<SimulationOutput, ProjectSimulationError> {
-    let project = load_project_from_yaml_file(path, &Some(start_date))?;
+    let project = load_project_from_yaml_file(path, &Some(options.start_date))?;
     let calendar = load_team_calendar_if_provided(calendar_path)?;
-    let mut output = simulate_project(&project, iterations, start_date, calendar)?;
+    let mut output = simulate_project(&project, calendar, options)?;
     output.report.data_source = data_source_name(path);
     Ok(output)
 }
 
 pub fn simulate_project(
     project: &Project,
-    iterations: usize,
-    start_date: NaiveDate,
     calendar: TeamCalendar,
+    options: &ProjectSimulationOptions,
 ) -> Result<SimulationOutput, ProjectSimulationError> {
-    if iterations == 0 {
+    if options.iterations == 0 {
         return Err(ProjectSimulationError::InvalidIterations);
     }
+    if options.threads == 0 {
+        return Err(ProjectSimulationError::InvalidThreads);
+    }
     if project.work_packages.is_empty() {
         return Err(ProjectSimulationError::EmptyProject);
     }
 
-    let velocity = calculate_project_velocity(project, &calendar)?;
+    check_log_normal_estimates(project, options)?;
 
-    let mut rng = rand::thread_rng();
-    let mut sampler = BetaPertSampler::new(&mut rng);
-    let output = run_simulation(
-        project,
-        velocity,
-        iterations,
-        start_date,
-        &mut sampler,
-        &calendar,
-    )?;
+    // Cycle times are measured in calendar days, so the calendar is not
+    // applied to them.
+    let (velocity, cycle_time_sampler, calendar_option) = match &options.cycle_times {
+        Some(cycle_times) => (
+            None,
+            Some(CycleTimeSampler::new(
+                &cycle_times.history,
+                cycle_times.grouping,
+            )?),
+            None,
+        ),
+        None => (
+            calculate_project_velocity(project, &calendar)?,
+            None,
+            calendar_for_project(project, &calendar),
+        ),
+    };
+
+    let correlated_quantiles = match (&options.correlation, &cycle_time_sampler) {
+        (Some(correlation), None) => Some(CorrelatedQuantiles::new(project, correlation)),
+        _ => None,
+    };
+
+    let run = |correlated_quantiles: Option<&CorrelatedQuantiles>, disabled_risk: Option<usize>| {
+        run_parallel_simulation(
+            project,
+            velocity,
+            cycle_time_sampler.as_ref(),
+            correlated_quantiles,
+            disabled_risk,
+            calendar_option,
+            options,
+        )
+    };
+
+    let mut samples = run(correlated_quantiles.as_ref(), None)?;
+    let risk_occurred = std::mem::take(&mut samples.risk_occurred);
+    let project_end_dates = if project.risks.is_empty() {
+        Vec::new()
+    } else {
+        samples.project_end_dates.clone()
+    };
+    let mut output = build_simulation_output(project, velocity, samples, options);
+    output.report.seed = Some(options.seed);
+
+    // The baselines use the same seed, which keeps the difference down to
+    // the correlation or the risk left out.
+    if let (Some(correlation), Some(quantiles)) = (options.correlation, &correlated_quantiles) {
+        let baseline_end_dates = sorted(run(None, None)?.project_end_dates);
+        output.report.correlation = Some(CorrelationForecast {
+            strength: correlation.strength,
+            grouping: correlation.grouping,
+            groups: quantiles.group_count(),
+            percentiles_without_correlation: to_percentiles(
+                &baseline_end_dates,
+                &with_standard_percentiles(&options.percentiles),
+                options.start_date,
+            ),
+        });
+    }
+    if !project.risks.is_empty() {
+        let p85_days = |sorted_end_dates: &[NaiveDate]| {
+            to_percentiles(sorted_end_dates, &[85.0], options.start_date)
+                .get(85.0)
+                .map_or(0.0, |value| value.days)
+        };
+        let p85_with_all_risks = output
+            .report
+            .percentiles
+            .get(85.0)
+            .map_or(0.0, |value| value.days);
+        let mut risks = Vec::with_capacity(project.risks.len());
+        for (index, risk) in project.risks.iter().enumerate() {
+            let occurred = risk_occurred.get(index).map_or(&[][..], Vec::as_slice);
+            let occurrences = occurred.iter().filter(|&&occurs| occurs).count();
+            // Risks are rolled independently of everything else, so the
+            // iterations without the risk are a sample of the project
+            // without it. Only a risk that occurred in every iteration needs
+            // a run of its own.
+            let baseline_end_dates = if occurrences < occurred.len() {
+                sorted(
+                    project_end_dates
+                        .iter()
+                        .zip(occurred)
+                        .filter(|(_, occurs)| !**occurs)
+                        .map(|(end_date, _)| *end_date)
+                        .collect(),
+                )
+            } else {
+                sorted(run(correlated_quantiles.as_ref(), Some(index))?.project_end_dates)
+            };
+            risks.push(RiskForecast {
+                id: risk.id.clone(),
+                description: risk.description.clone(),
+                probability: risk.probability,
+                occurrence_rate: occurrences as f32 / options.iterations as f32,
+                affects: risk.affects.clone(),
+                p85_contribution_days: p85_with_all_risks - p85_days(&baseline_end_dates),
+            });
+        }
+        output.report.risks = Some(risks);
+    }
     Ok(output)
 }
 
+/// A log-normal distribution cannot be fitted to an optimistic value of 0
+/// or less. The estimates are checked before the first iteration, so that a
+/// run does not fail halfway through.
+fn check_log_normal_estimates(
+    project: &Project,
+    options: &ProjectSimulationOptions,
+) -> Result<(), ProjectSimulationError> {
+    // Cycle times replace the estimates of the work packages.
+    let work_packages = match options.cycle_times {
+        Some(_) => &[][..],
+        None => &project.work_packages[..],
+    };
+    let sampled_estimates = work_packages.iter().filter_map(|issue| {
+        let estimate = match issue.estimate.as_ref()? {
+            Estimate::ThreePoint(estimate) => estimate,
+            Estimate::Reference(reference) => reference.cached_estimate.as_ref()?,
+            Estimate::StoryPoint(_) | Estimate::Milestone => return None,
+        };
+        let id = issue.issue_id.as_ref()?;
+        Some((format!("work package {}", id.id), estimate))
+    });
+    let risk_impacts = project
+        .risks
+        .iter()
+        .map(|risk| (format!("risk {}", risk.id), &risk.impact));
+
+    let is_invalid = |estimate: &ThreePointEstimate| {
+        estimate.distribution.unwrap_or(options.distribution) == DurationDistribution::LogNormal
+            && estimate
+                .optimistic
+                .is_some_and(|optimistic| optimistic <= 0.0)
+    };
+    match sampled_estimates
+        .chain(risk_impacts)
+        .find(|(_, estimate)| is_invalid(estimate))
+    {
+        Some((name, _)) => Err(ProjectSimulationError::NonPositiveLogNormal(name)),
+        None => Ok(()),
+    }
+}
+
+/// Runs all iterations with a single injected sampler and reports the standard
+/// percentiles. The tests use this to get deterministic durations from a mock
+/// sampler.
+#[cfg(test)]
 fn run_simulation<R: ThreePointSampler + ?Sized>(
     project: &Project,
     velocity: Option<f32>,
@@ -108,75 +358,273 @@ fn run_simulation<R: ThreePointSampler + ?Sized>(
     sampler: &mut R,
     calendar: &TeamCalendar,
 ) -> Result<SimulationOutput, ProjectSimulationError> {
-    let mut samples_by_id: HashMap<String, Vec<WorkItemSample>> = HashMap::new();
-    let mut project_end_dates = Vec::with_capacity(iterations);
-    let calendar_option = if project.has_story_points() {
+    let calendar_option = calendar_for_project(project, calendar);
+    let samples = sample_iterations(iterations, start_date, calendar_option, None, None, || {
+        Ok(build_network_nodes(project, velocity, sampler)?)
+    })?;
+    let options = ProjectSimulationOptions::new(iterations, start_date);
+    Ok(build_simulation_output(
+        project, velocity, samples, &options,
+    ))
+}
+
+/// Splits the iterations into chunks of `ITERATIONS_PER_CHUNK` and spreads
+/// the chunks across the worker threads. Every chunk samples with its own
+/// random number generator, seeded from the seed and the chunk index, and the
+/// per-package samples are merged. Because chunks rather than threads are
+/// seeded, the same seed yields the same report for any number of threads.
+/// Durations come from the cycle time sampler if one is given, and from the
+/// estimates otherwise, correlated by `correlated_quantiles` if given. The
+/// risks of the project are rolled in every iteration, but `disabled_risk`
+/// never delays a work package.
+fn run_parallel_simulation(
+    project: &Project,
+    velocity: Option<f32>,
+    cycle_time_sampler: Option<&CycleTimeSampler>,
+    correlated_quantiles: Option<&CorrelatedQuantiles>,
+    disabled_risk: Option<usize>,
+    calendar: Option<&TeamCalendar>,
+    options: &ProjectSimulationOptions,
+) -> Result<IterationSamples, ProjectSimulationError> {
+    let chunks = chunk_sizes(options.iterations);
+    let workers = options.threads.clamp(1, chunks.len().max(1));
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|worker| {
+                let chunks = &chunks;
+                scope.spawn(move || {
+                    let mut worker_samples = IterationSamples::default();
+                    for (chunk_index, chunk_iterations) in chunks
+                        .iter()
+                        .enumerate()
+                        .filter(|(chunk_index, _)| chunk_index % workers == worker)
+                    {
+                        let chunk_seed = options.seed.wrapping_add(chunk_index as u64);
+                        let mut rng = StdRng::seed_from_u64(chunk_seed);
+                        // Risks draw from their own generator, seeded from
+                        // the chunk's generator.
+                        let risk_roller = (!project.risks.is_empty()).then(|| {
+                            RiskRoller::new(
+                                project,
+                                options.distribution,
+                                disabled_risk,
+                                rng.next_u64(),
+                            )
+                        });
+                        let chunk_samples = match cycle_time_sampler {
+                            Some(cycle_time_sampler) => sample_iterations(
+                                *chunk_iterations,
+                                options.start_date,
+                                calendar,
+                                options.wip_limit,
+                                risk_roller,
+                                || {
+                                    Ok(build_cycle_time_network_nodes(
+                                        project,
+                                        cycle_time_sampler,
+                                        &mut rng,
+                                    )?)
+                                },
+                            ),
+                            None => {
+                                // The copula draws from its own generator,
+                                // seeded from the chunk's generator.
+                                let mut correlation = correlated_quantiles.map(|quantiles| {
+                                    (quantiles, StdRng::seed_from_u64(rng.next_u64()))
+                                });
+                                let mut sampler =
+                                    DistributionSampler::new(rng, options.distribution);
+                                sample_iterations(
+                                    *chunk_iterations,
+                                    options.start_date,
+                                    calendar,
+                                    options.wip_limit,
+                                    risk_roller,
+                                    || {
+                                        let quantiles = correlation
+                                            .as_mut()
+                                            .map(|(quantiles, rng)| quantiles.sample(rng))
+                                            .unwrap_or_default();
+                                        Ok(build_correlated_network_nodes(
+                                            project,
+                                            velocity,
+                                            &mut sampler,
+                                            &quantiles,
+                                        )?)
+                                    },
+                                )
+                            }
+                        };
+                        worker_samples.merge(chunk_samples?);
+                    }
+                    Ok::<_, ProjectSimulationError>(worker_samples)
+                })
+            })
+            .collect();
+
+        let mut merged = IterationSamples::default();
+        for handle in handles {
+            let worker_samples = handle.join().expect("simulation worker panicked")?;
+            merged.merge(worker_samples);
+        }
+        Ok(merged)
+    })
+}
+
+fn chunk_sizes(iterations: usize) -> Vec<usize> {
+    (0..iterations)
+        .step_by(ITERATIONS_PER_CHUNK)
+        .map(|first| ITERATIONS_PER_CHUNK.min(iterations - first))
+        .collect()
+}
+
+fn calendar_for_project<'a>(
+    project: &Project,
+    calendar: &'a TeamCalendar,
+) -> Option<&'a TeamCalendar> {
+    if project.has_story_points() {
         println!("Project contains story points, using calendar the calendar.");
         Some(calendar)
     } else {
         println!("Project does not contain story points, ignoring calendar.");
         None
+    }
+}
+
+/// Runs the critical path method on freshly sampled network nodes in every
+/// iteration.
+fn sample_iterations(
+    iterations: usize,
+    start_date: chrono::NaiveDate,
+    calendar: Option<&TeamCalendar>,
+    wip_limit: Option<usize>,
+    mut risk_roller: Option<RiskRoller>,
+    mut build_nodes: impl FnMut() -> Result<Vec<NetworkNode>, ProjectSimulationError>,
+) -> Result<IterationSamples, ProjectSimulationError> {
+    let mut samples = IterationSamples {
+        project_end_dates: Vec::with_capacity(iterations),
+        samples_by_id: HashMap::new(),
+        risk_occurred: Vec::new(),
     };
 
     for _ in 0..iterations {
-        let network_nodes = build_network_nodes(&project, velocity, sampler)?;
+        let mut network_nodes = build_nodes()?;
+        if let Some(risk_roller) = risk_roller.as_mut() {
+            risk_roller.apply(&mut network_nodes)?;
+        }
+        let durations: HashMap<String, f32> = network_nodes
+            .iter()
+            .map(|node| (node.id.clone(), node.duration))
+            .collect();
         let sorted_nodes = SortedNetworkNodes::new(network_nodes)?;
 
-        let result_nodes = critical_path_method(sorted_nodes, start_date, calendar_option)?;
+        let result_nodes =
+            critical_path_method_with_wip_limit(sorted_nodes, start_date, calendar, wip_limit)?;
 
         let project_end_date = result_nodes
             .iter()
             .map(|node| node.earliest_finish)
             .max()
             .unwrap_or(start_date);
-        project_end_dates.push(project_end_date);
+        samples.project_end_dates.push(project_end_date);
 
         for result_node in result_nodes {
-            samples_by_id
+            samples
+                .samples_by_id
                 .entry(result_node.id.clone())
                 .or_insert_with(|| Vec::with_capacity(iterations))
                 .push(WorkItemSample {
                     end_date: result_node.earliest_finish,
+                    duration: durations.get(&result_node.id).copied().unwrap_or(0.0),
+                    is_critical: result_node.is_critical(),
+                    total_float: result_node.total_float,
+                    constraint_violated: result_node.constraint_violated,
                 });
         }
     }
 
-    let work_packages = project
-        .work_packages
+    samples.risk_occurred = risk_roller
+        .map(RiskRoller::into_occurred)
+        .unwrap_or_default();
+    Ok(samples)
+}
+
+fn build_simulation_output(
+    project: &Project,
+    velocity: Option<f32>,
+    samples: IterationSamples,
+    options: &ProjectSimulationOptions,
+) -> SimulationOutput {
+    let IterationSamples {
+        mut project_end_dates,
+        samples_by_id,
+        ..
+    } = samples;
+    let start_date = options.start_date;
+    let percentiles = with_standard_percentiles(&options.percentiles);
+    // Taken before sorting, so the values stay aligned with the work package samples.
+    let project_durations: Vec<f32> = project_end_dates
         .iter()
-        .map(|issue| {
-            let id = issue
-                .issue_id
-                .as_ref()
-                .map(|issue_id| issue_id.id.clone())
-                .unwrap_or_default();
-            WorkPackageSimulation {
-                id,
-                is_milestone: issue.is_milestone(),
-                percentiles: percentiles_from_samples(
-                    samples_by_id
-                        .get(issue.issue_id.as_ref().unwrap().id.as_str())
-                        .map(Vec::as_slice)
-                        .unwrap_or(&[]),
-                    start_date,
-                ),
-            }
-        })
+        .map(|date| calculate_days(start_date, *date))
         .collect();
 
     project_end_dates.sort();
+    let mut deadlines: Vec<DeadlineForecast> = options
+        .deadline
+        .map(|deadline| deadline_forecast(None, deadline, &project_end_dates))
+        .into_iter()
+        .collect();
+
+    let mut work_packages = Vec::with_capacity(project.work_packages.len());
+    let mut constraints = Vec::new();
+    for issue in &project.work_packages {
+        let id = issue
+            .issue_id
+            .as_ref()
+            .map(|issue_id| issue_id.id.clone())
+            .unwrap_or_default();
+        let samples = samples_by_id
+            .get(id.as_str())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let sorted_end_dates = sorted_end_dates(samples);
+        let critical_flags: Vec<bool> = samples.iter().map(|s| s.is_critical).collect();
+        let durations: Vec<f32> = samples.iter().map(|s| s.duration).collect();
+        if let Some(due_date) = issue.due_date {
+            deadlines.push(deadline_forecast(
+                Some(id.clone()),
+                due_date,
+                &sorted_end_dates,
+            ));
+        }
+        if let Some(constraint) = issue.constraint {
+            constraints.push(constraint_forecast(id.clone(), constraint, samples));
+        }
+        work_packages.push(WorkPackageSimulation {
+            id,
+            is_milestone: issue.is_milestone(),
+            percentiles: to_percentiles(&sorted_end_dates, &percentiles, start_date),
+            criticality_index: criticality_index(&critical_flags),
+            duration_correlation: duration_correlation(&durations, &project_durations),
+        });
+    }
+
     let report = SimulationReport {
         data_source: String::new(),
         start_date,
         velocity,
-        iterations,
+        iterations: options.iterations,
+        seed: None,
         simulated_items: project.work_packages.len(),
-        p0: to_simulation_percentile(&project_end_dates, 0.0, start_date),
-        p15: to_simulation_percentile(&project_end_dates, 15.0, start_date),
-        p50: to_simulation_percentile(&project_end_dates, 50.0, start_date),
-        p85: to_simulation_percentile(&project_end_dates, 85.0, start_date),
-        p100: to_simulation_percentile(&project_end_dates, 100.0, start_date),
+        percentiles: to_percentiles(&project_end_dates, &percentiles, start_date),
         work_packages: Some(work_packages),
+        deadlines: (!deadlines.is_empty()).then_some(deadlines),
+        sample_window: None,
+        scope_growth: None,
+        correlation: None,
+        risks: None,
+        constraints: (!constraints.is_empty()).then_some(constraints),
     };
 
     let results = project_end_dates
@@ -184,25 +632,58 @@ fn run_simulation<R: ThreePointSampler + ?Sized>(
         .map(|date| calculate_days(start_date, *date))
         .collect();
 
-    Ok(SimulationOutput { report, results })
+    SimulationOutput { report, results }
 }
 
-fn percentiles_from_samples(
+/// Counts the iterations in which a package missed its date constraint.
+fn constraint_forecast(
+    id: String,
+    constraint: DateConstraint,
     samples: &[WorkItemSample],
-    start_date: chrono::NaiveDate,
-) -> WorkPackagePercentiles {
-    let mut sorted_end_dates = samples.iter().map(|s| s.end_date).collect::<Vec<_>>();
-    sorted_end_dates.sort();
-
-    WorkPackagePercentiles {
-        p0: to_simulation_percentile(&sorted_end_dates, 0.0, start_date),
-        p15: to_simulation_percentile(&sorted_end_dates, 15.0, start_date),
-        p50: to_simulation_percentile(&sorted_end_dates, 50.0, start_date),
-        p85: to_simulation_percentile(&sorted_end_dates, 85.0, start_date),
-        p100: to_simulation_percentile(&sorted_end_dates, 100.0, start_date),
+) -> ConstraintForecast {
+    let mut total_floats: Vec<f32> = samples.iter().map(|s| s.total_float).collect();
+    total_floats.sort_by(f32::total_cmp);
+    ConstraintForecast {
+        id,
+        constraint_type: match constraint {
+            DateConstraint::StartNoEarlierThan(_) => ConstraintType::StartNoEarlierThan,
+            DateConstraint::MustFinishOnOrBefore(_) => ConstraintType::MustFinishOnOrBefore,
+            DateConstraint::FixedDate(_) => ConstraintType::FixedDate,
+        },
+        date: constraint.date(),
+        violations: samples.iter().filter(|s| s.constraint_violated).count(),
+        // The float that 85% of the iterations reach is the 15th percentile.
+        total_float_days_p85: percentiles::get_percentile_value_f32(&total_floats, 15.0),
     }
 }
 
+fn sorted(mut end_dates: Vec<NaiveDate>) -> Vec<NaiveDate> {
+    end_dates.sort();
+    end_dates
+}
+
+fn sorted_end_dates(samples: &[WorkItemSample]) -> Vec<chrono::NaiveDate> {
+    let mut end_dates: Vec<chrono::NaiveDate> = samples.iter().map(|s| s.end_date).collect();
+    end_dates.sort();
+    end_dates
+}
+
+fn to_percentiles(
+    sorted_end_dates: &[chrono::NaiveDate],
+    percentiles: &[f64],
+    start_date: chrono::NaiveDate,
+) -> Percentiles {
+    percentiles
+        .iter()
+        .map(|&percentile| {
+            (
+                percentile,
+                to_simulation_percentile(sorted_end_dates, percentile, start_date),
+            )
+        })
+        .collect()
+}
+
 fn to_simulation_percentile(
     sorted_end_dates: &[chrono::NaiveDate],
     percentile: f64,
@@ -221,11 +702,14 @@ fn calculate_days(start_date: chrono::NaiveDate, end_date: chrono::NaiveDate) ->
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::estimate::{Estimate, ThreePointEstimate};
     use crate::domain::issue::IssueId;
+    use crate::domain::risk::Risk;
+    use crate::services::project_simulation::correlation::CorrelationGrouping;
     use crate::test_support::{MockSampler, build_in_progress_story_point_issue};
     use crate::test_support::{
         build_constant_three_point_issue, build_done_issue, build_done_issue_with_deps,
-        build_story_point_issue, build_story_point_issue_with_start_date,
+        build_story_point_issue, build_story_point_issue_with_start_date, build_three_point_issue,
         create_calendar_without_any_free_days, on_date,
     };
     use chrono::NaiveDate;
@@ -252,10 +736,13 @@ mod tests {
         let project = Project {
             name: "Demo".to_string(),
             work_packages: vec![issue_a, issue_b],
+            risks: Vec::new(),
         };
         let calendar = create_calendar_without_any_free_days();
 
-        let error = simulate_project(&project, 10, on_date(2026, 1, 1), calendar).unwrap_err();
+        let options = ProjectSimulationOptions::new(10, on_date(2026, 1, 1));
+
+        let error = simulate_project(&project, calendar, &options).unwrap_err();
         assert!(matches!(
             error,
             ProjectSimulationError::NetworkNodes(NetworkNodesError::CycleDetected)
@@ -295,6 +782,7 @@ mod tests {
                 build_story_point_issue("SP-4", 8.0, &["SP-3"]),
                 build_story_point_issue("SP-5", 8.0, &["SP-4"]),
             ],
+            risks: Vec::new(),
         };
         let calendar = TeamCalendar::new(); // default calendar which assumes weekends to be free
 
@@ -320,12 +808,30 @@ mod tests {
         let wp4 = work_packages.iter().find(|wp| wp.id == "SP-4").unwrap();
         let wp5 = work_packages.iter().find(|wp| wp.id == "SP-5").unwrap();
 
-        assert_eq!(wp0.percentiles.p0.end_date, on_date(2026, 1, 8));
-        assert_eq!(wp1.percentiles.p0.end_date, on_date(2026, 1, 14));
-        assert_eq!(wp2.percentiles.p0.end_date, on_date(2026, 1, 20));
-        assert_eq!(wp3.percentiles.p0.end_date, on_date(2026, 2, 20));
-        assert_eq!(wp4.percentiles.p0.end_date, on_date(2026, 2, 26));
-        assert_eq!(wp5.percentiles.p0.end_date, on_date(2026, 3, 4));
+        assert_eq!(
+            wp0.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 1, 8)
+        );
+        assert_eq!(
+            wp1.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 1, 14)
+        );
+        assert_eq!(
+            wp2.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 1, 20)
+        );
+        assert_eq!(
+            wp3.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 2, 20)
+        );
+        assert_eq!(
+            wp4.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 2, 26)
+        );
+        assert_eq!(
+            wp5.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 3, 4)
+        );
     }
 
     #[test]
@@ -346,6 +852,7 @@ mod tests {
                 build_in_progress_story_point_issue("SP-1", 8.0, feb_sixteen, &["SP-0"]),
                 build_story_point_issue("SP-2", 8.0, &["SP-1"]),
             ],
+            risks: Vec::new(),
         };
         let calendar = TeamCalendar::new(); // default calendar which assumes weekends to be free
 
@@ -368,9 +875,18 @@ mod tests {
         let wp1 = work_packages.iter().find(|wp| wp.id == "SP-1").unwrap();
         let wp2 = work_packages.iter().find(|wp| wp.id == "SP-2").unwrap();
 
-        assert_eq!(wp0.percentiles.p0.end_date, on_date(2026, 1, 8));
-        assert_eq!(wp1.percentiles.p0.end_date, on_date(2026, 2, 20));
-        assert_eq!(wp2.percentiles.p0.end_date, on_date(2026, 2, 26));
+        assert_eq!(
+            wp0.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 1, 8)
+        );
+        assert_eq!(
+            wp1.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 2, 20)
+        );
+        assert_eq!(
+            wp2.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 2, 26)
+        );
     }
 
     #[test]
@@ -399,7 +915,7 @@ mod tests {
         //     +----+--+-----+
         //            |
         //           FIN
-        for (_idx, (wp0, wp1, wp2, wp3, expected)) in test_cases.into_iter().enumerate() {
+        for (wp0, wp1, wp2, wp3, expected) in test_cases.into_iter() {
             let mut sampler = MockSampler;
             let project = Project {
                 name: "Dependent Project".to_string(),
@@ -413,6 +929,7 @@ mod tests {
                     build_constant_three_point_issue("WP3", wp3, &["WP1"]),
                     build_constant_three_point_issue("FIN", 0.0, &["WP0", "WP2", "WP3"]),
                 ],
+                risks: Vec::new(),
             };
             let calendar = create_calendar_without_any_free_days();
 
@@ -426,7 +943,7 @@ mod tests {
             )
             .unwrap();
 
-            assert_eq!(output.report.p85.days, expected);
+            assert_eq!(output.report.percentiles.get(85.0).unwrap().days, expected);
             assert_eq!(output.report.iterations, 25);
             assert!(output.report.velocity.is_some());
         }
@@ -443,6 +960,7 @@ mod tests {
                 build_constant_three_point_issue("WP2", 3.0, &["WP0", "WP1"]),
                 build_constant_three_point_issue("FIN", 0.0, &["WP2"]),
             ],
+            risks: Vec::new(),
         };
         let calendar = create_calendar_without_any_free_days();
 
@@ -464,24 +982,24 @@ mod tests {
         let wp2 = work_packages.iter().find(|wp| wp.id == "WP2").unwrap();
         let fin = work_packages.iter().find(|wp| wp.id == "FIN").unwrap();
 
-        assert_eq!(wp0.percentiles.p0.days, 2.0);
+        assert_eq!(wp0.percentiles.get(0.0).unwrap().days, 2.0);
         assert_eq!(
-            wp0.percentiles.p0.end_date,
+            wp0.percentiles.get(0.0).unwrap().end_date,
             project_start_date + chrono::Duration::days(2)
         );
 
         let wp1_end_date = project_start_date + chrono::Duration::days(4);
 
-        assert_eq!(wp1.percentiles.p0.days, 4.0);
-        assert_eq!(wp1.percentiles.p0.end_date, wp1_end_date);
+        assert_eq!(wp1.percentiles.get(0.0).unwrap().days, 4.0);
+        assert_eq!(wp1.percentiles.get(0.0).unwrap().end_date, wp1_end_date);
 
         let wp2_end_date = wp1_end_date + chrono::Duration::days(3);
 
-        assert_eq!(wp2.percentiles.p0.days, 7.0);
-        assert_eq!(wp2.percentiles.p0.end_date, wp2_end_date);
+        assert_eq!(wp2.percentiles.get(0.0).unwrap().days, 7.0);
+        assert_eq!(wp2.percentiles.get(0.0).unwrap().end_date, wp2_end_date);
 
-        assert_eq!(fin.percentiles.p0.days, 7.0);
-        assert_eq!(fin.percentiles.p0.end_date, wp2_end_date);
+        assert_eq!(fin.percentiles.get(0.0).unwrap().days, 7.0);
+        assert_eq!(fin.percentiles.get(0.0).unwrap().end_date, wp2_end_date);
     }
 
     #[test]
@@ -498,6 +1016,7 @@ mod tests {
                 build_story_point_issue("SP-5", 2.0, &["SP-4"]),
                 build_story_point_issue("SP-6", 2.0, &["SP-5"]),
             ],
+            risks: Vec::new(),
         };
         let calendar = TeamCalendar::new(); // default calendar which assumes weekends to be free
 
@@ -512,10 +1031,11 @@ mod tests {
         .unwrap();
 
         let velocity = output.report.velocity.unwrap();
-        let p50_days = output.report.p50.days;
+        let p50_days = output.report.percentiles.get(50.0).unwrap().days;
 
         assert_eq!(
-            output.report.p0.days, output.report.p100.days,
+            output.report.percentiles.get(0.0).unwrap().days,
+            output.report.percentiles.get(100.0).unwrap().days,
             "With deterministic sampling, p0 and p100 should be the same"
         );
 
@@ -529,6 +1049,184 @@ mod tests {
         );
     }
 
+    #[test]
+    fn simulate_project_rejects_zero_threads() {
+        let project = Project {
+            name: "Demo".to_string(),
+            work_packages: vec![build_constant_three_point_issue("WP0", 1.0, &[])],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+
+        let options = ProjectSimulationOptions {
+            threads: 0,
+            ..ProjectSimulationOptions::new(10, on_date(2026, 1, 1))
+        };
+
+        let error = simulate_project(&project, calendar, &options).unwrap_err();
+        assert!(matches!(error, ProjectSimulationError::InvalidThreads));
+    }
+
+    #[test]
+    fn log_normal_estimates_without_positive_optimistic_are_rejected_up_front() {
+        let mut explicit = build_three_point_issue("WP1", 0.0, 1.0, 2.0, &[]);
+        if let Some(Estimate::ThreePoint(estimate)) = explicit.estimate.as_mut() {
+            estimate.distribution = Some(DurationDistribution::LogNormal);
+        }
+        let project = Project {
+            name: "Demo".to_string(),
+            work_packages: vec![build_three_point_issue("WP0", 0.0, 1.0, 2.0, &[]), explicit],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let options = ProjectSimulationOptions::new(10, on_date(2026, 1, 1));
+
+        let error = simulate_project(&project, calendar.clone(), &options).unwrap_err();
+        assert!(
+            matches!(error, ProjectSimulationError::NonPositiveLogNormal(name) if name == "work package WP1")
+        );
+
+        let options = ProjectSimulationOptions {
+            distribution: DurationDistribution::LogNormal,
+            ..options
+        };
+        let error = simulate_project(&project, calendar, &options).unwrap_err();
+        assert!(
+            matches!(error, ProjectSimulationError::NonPositiveLogNormal(name) if name == "work package WP0")
+        );
+    }
+
+    #[test]
+    fn iterations_are_split_into_fixed_size_chunks() {
+        assert_eq!(chunk_sizes(2500), vec![1000, 1000, 500]);
+        assert_eq!(chunk_sizes(2000), vec![1000, 1000]);
+        assert_eq!(chunk_sizes(25), vec![25]);
+        assert!(chunk_sizes(0).is_empty());
+    }
+
+    #[test]
+    fn same_seed_reproduces_report_for_any_thread_count() {
+        let project = Project {
+            name: "Wide Project".to_string(),
+            work_packages: vec![
+                build_three_point_issue("WP0", 1.0, 5.0, 30.0, &[]),
+                build_three_point_issue("WP1", 2.0, 10.0, 40.0, &["WP0"]),
+            ],
+            risks: Vec::new(),
+        };
+        let start_date = on_date(2026, 1, 1);
+
+        let simulate = |threads: usize, seed: u64| {
+            let calendar = create_calendar_without_any_free_days();
+            let options = ProjectSimulationOptions {
+                threads,
+                seed,
+                ..ProjectSimulationOptions::new(3000, start_date)
+            };
+            simulate_project(&project, calendar, &options).unwrap()
+        };
+
+        let single = simulate(1, 42);
+        let parallel = simulate(3, 42);
+        let other_seed = simulate(3, 43);
+
+        assert_eq!(single.results, parallel.results);
+        assert_eq!(single.report.seed, Some(42));
+        assert_eq!(parallel.report.seed, Some(42));
+        assert_ne!(single.results, other_seed.results);
+    }
+
+    #[test]
+    fn parallel_simulation_collects_samples_from_all_workers() {
+        let project = Project {
+            name: "Dependent Project".to_string(),
+            work_packages: vec![
+                build_constant_three_point_issue("WP0", 2.0, &[]),
+                build_constant_three_point_issue("WP1", 4.0, &[]),
+                build_constant_three_point_issue("WP2", 3.0, &["WP0", "WP1"]),
+            ],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let start_date = on_date(2026, 1, 1);
+
+        let options = ProjectSimulationOptions {
+            threads: 4,
+            seed: 1,
+            ..ProjectSimulationOptions::new(2500, start_date)
+        };
+
+        let calendar_option = calendar_for_project(&project, &calendar);
+        let samples =
+            run_parallel_simulation(&project, None, None, None, None, calendar_option, &options)
+                .unwrap();
+        let output = build_simulation_output(&project, None, samples, &options);
+
+        assert_eq!(output.results.len(), 2500);
+        assert_eq!(output.report.iterations, 2500);
+        assert_eq!(output.report.percentiles.get(0.0).unwrap().days, 7.0);
+        assert_eq!(output.report.percentiles.get(100.0).unwrap().days, 7.0);
+
+        let work_packages = output.report.work_packages.unwrap();
+        let wp2 = work_packages.iter().find(|wp| wp.id == "WP2").unwrap();
+        assert_eq!(
+            wp2.percentiles.get(50.0).unwrap().end_date,
+            on_date(2026, 1, 8)
+        );
+    }
+
+    #[test]
+    fn parallel_simulation_is_statistically_equivalent_to_serial_simulation() {
+        let project = Project {
+            name: "Wide Project".to_string(),
+            work_packages: vec![
+                build_three_point_issue("WP0", 1.0, 5.0, 30.0, &[]),
+                build_three_point_issue("WP1", 2.0, 10.0, 40.0, &["WP0"]),
+            ],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let start_date = on_date(2026, 1, 1);
+        let iterations = 4000;
+
+        let mut sampler =
+            DistributionSampler::new(rand::thread_rng(), DurationDistribution::BetaPert);
+        let serial = run_simulation(
+            &project,
+            None,
+            iterations,
+            start_date,
+            &mut sampler,
+            &calendar,
+        )
+        .unwrap();
+        let options = ProjectSimulationOptions {
+            threads: 4,
+            seed: 1,
+            ..ProjectSimulationOptions::new(iterations, start_date)
+        };
+        let calendar_option = calendar_for_project(&project, &calendar);
+        let samples =
+            run_parallel_simulation(&project, None, None, None, None, calendar_option, &options)
+                .unwrap();
+        let parallel = build_simulation_output(&project, None, samples, &options);
+
+        assert_eq!(parallel.results.len(), serial.results.len());
+        // Expected total duration is about 27 days, so two days is a generous tolerance.
+        assert!(
+            (serial.report.percentiles.get(50.0).unwrap().days
+                - parallel.report.percentiles.get(50.0).unwrap().days)
+                .abs()
+                <= 2.0
+        );
+        assert!(
+            (serial.report.percentiles.get(85.0).unwrap().days
+                - parallel.report.percentiles.get(85.0).unwrap().days)
+                .abs()
+                <= 2.0
+        );
+    }
+
     #[test]
     fn simulate_project_from_yaml_file_sets_report_fields() {
         let nanos = SystemTime::now()
@@ -540,13 +1238,15 @@ mod tests {
         let yaml = "name: Demo\nwork_packages:\n  - id: WP-1\n    estimate:\n      type: three_point\n      optimistic: 1\n      most_likely: 2\n      pessimistic: 3\n";
         std::fs::write(&input_path, yaml).unwrap();
 
-        let output = simulate_project_from_yaml_file(
-            input_path.to_str().unwrap(),
-            5,
-            on_date(2026, 1, 1),
-            None,
-        )
-        .unwrap();
+        let options = ProjectSimulationOptions {
+            threads: 2,
+            seed: 11,
+            percentiles: vec![70.0, 95.0],
+            ..ProjectSimulationOptions::new(5, on_date(2026, 1, 1))
+        };
+
+        let output =
+            simulate_project_from_yaml_file(input_path.to_str().unwrap(), None, &options).unwrap();
 
         assert_eq!(
             output.report.data_source,
@@ -554,5 +1254,332 @@ mod tests {
         );
         assert_eq!(output.report.iterations, 5);
         assert_eq!(output.report.velocity, None);
+        assert_eq!(output.report.seed, Some(11));
+        let reported: Vec<f64> = output.report.percentiles.iter().map(|(p, _)| p).collect();
+        assert_eq!(reported, vec![0.0, 15.0, 50.0, 70.0, 85.0, 95.0, 100.0]);
+    }
+
+    #[test]
+    fn report_contains_deadline_and_milestone_due_date_probabilities() {
+        let mut milestone = build_constant_three_point_issue("M1", 0.0, &["WP0"]);
+        milestone.estimate = Some(Estimate::Milestone);
+        milestone.due_date = Some(on_date(2026, 1, 3));
+        let project = Project {
+            name: "Demo".to_string(),
+            work_packages: vec![
+                build_constant_three_point_issue("WP0", 4.0, &[]),
+                milestone,
+                build_constant_three_point_issue("WP1", 3.0, &["M1"]),
+            ],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let options = ProjectSimulationOptions {
+            deadline: Some(on_date(2026, 1, 10)),
+            ..ProjectSimulationOptions::new(20, on_date(2026, 1, 1))
+        };
+
+        let output = simulate_project(&project, calendar, &options).unwrap();
+
+        // WP0 ends on 2026-01-05 and the project on 2026-01-08.
+        let deadlines = output.report.deadlines.unwrap();
+        assert_eq!(
+            deadlines,
+            vec![
+                DeadlineForecast {
+                    id: None,
+                    due_date: on_date(2026, 1, 10),
+                    probability: 1.0,
+                    slack_days_p85: 2,
+                },
+                DeadlineForecast {
+                    id: Some("M1".to_string()),
+                    due_date: on_date(2026, 1, 3),
+                    probability: 0.0,
+                    slack_days_p85: -2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn report_has_no_deadlines_without_targets() {
+        let project = Project {
+            name: "Demo".to_string(),
+            work_packages: vec![build_constant_three_point_issue("WP0", 1.0, &[])],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let options = ProjectSimulationOptions::new(5, on_date(2026, 1, 1));
+
+        let output = simulate_project(&project, calendar, &options).unwrap();
+
+        assert!(output.report.deadlines.is_none());
+    }
+
+    #[test]
+    fn work_packages_report_criticality_and_duration_correlation() {
+        let project = Project {
+            name: "Parallel Project".to_string(),
+            work_packages: vec![
+                build_constant_three_point_issue("SHORT", 2.0, &[]),
+                build_three_point_issue("LONG", 3.0, 6.0, 20.0, &[]),
+                build_constant_three_point_issue("FIN", 1.0, &["SHORT", "LONG"]),
+            ],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let options = ProjectSimulationOptions {
+            seed: 5,
+            ..ProjectSimulationOptions::new(200, on_date(2026, 1, 1))
+        };
+
+        let output = simulate_project(&project, calendar, &options).unwrap();
+
+        let work_packages = output.report.work_packages.unwrap();
+        let find = |id: &str| work_packages.iter().find(|wp| wp.id == id).unwrap();
+        assert_eq!(find("LONG").criticality_index, 1.0);
+        assert_eq!(find("SHORT").criticality_index, 0.0);
+        assert_eq!(find("FIN").criticality_index, 1.0);
+        assert!(find("LONG").duration_correlation > 0.9);
+        assert_eq!(find("SHORT").duration_correlation, 0.0);
+    }
+
+    fn cycle_time(id: &str, days: i64) -> CycleTime {
+        CycleTime {
+            issue_id: id.to_string(),
+            start_date: on_date(2025, 12, 1),
+            done_date: on_date(2025, 12, 1) + chrono::Duration::days(days - 1),
+            days,
+            story_points: None,
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn cycle_times_replace_estimates_and_the_calendar() {
+        let mut unestimated = build_story_point_issue("WP1", 8.0, &["WP0"]);
+        unestimated.estimate = None;
+        let project = Project {
+            name: "Cycle Time Project".to_string(),
+            work_packages: vec![build_story_point_issue("WP0", 8.0, &[]), unestimated],
+            risks: Vec::new(),
+        };
+        let options = ProjectSimulationOptions {
+            cycle_times: Some(CycleTimeOptions {
+                history: vec![cycle_time("DONE-1", 3), cycle_time("DONE-2", 3)],
+                grouping: None,
+            }),
+            ..ProjectSimulationOptions::new(20, on_date(2026, 1, 2))
+        };
+
+        // Weekends of the default calendar do not stretch cycle times.
+        let output = simulate_project(&project, TeamCalendar::new(), &options).unwrap();
+
+        assert_eq!(output.report.velocity, None);
+        assert_eq!(output.results, vec![6.0; 20]);
+        assert_eq!(
+            output.report.percentiles.get(85.0).unwrap().end_date,
+            on_date(2026, 1, 8)
+        );
+    }
+
+    #[test]
+    fn wip_limit_serializes_independent_work_packages() {
+        let project = Project {
+            name: "Parallel Project".to_string(),
+            work_packages: vec![
+                build_constant_three_point_issue("WP0", 2.0, &[]),
+                build_constant_three_point_issue("WP1", 2.0, &[]),
+                build_constant_three_point_issue("WP2", 2.0, &[]),
+            ],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let options = ProjectSimulationOptions {
+            wip_limit: Some(1),
+            ..ProjectSimulationOptions::new(5, on_date(2026, 1, 1))
+        };
+
+        let output = simulate_project(&project, calendar, &options).unwrap();
+
+        assert_eq!(output.results, vec![6.0; 5]);
+    }
+
+    #[test]
+    fn correlation_widens_the_upper_percentiles() {
+        let work_packages = (0..6)
+            .map(|index| {
+                let previous = format!("WP{}", index.max(1) - 1);
+                let deps: &[&str] = if index == 0 { &[] } else { &[&previous] };
+                let mut issue =
+                    build_three_point_issue(&format!("WP{index}"), 2.0, 4.0, 12.0, deps);
+                issue.correlation_group = Some("team".to_string());
+                issue
+            })
+            .collect();
+        let project = Project {
+            name: "Correlated Project".to_string(),
+            work_packages,
+            risks: Vec::new(),
+        };
+        let options = ProjectSimulationOptions {
+            seed: 7,
+            correlation: Some(CorrelationOptions {
+                strength: 0.8,
+                grouping: CorrelationGrouping::CorrelationGroup,
+            }),
+            ..ProjectSimulationOptions::new(4000, on_date(2026, 1, 1))
+        };
+
+        let report = simulate_project(&project, create_calendar_without_any_free_days(), &options)
+            .unwrap()
+            .report;
+
+        let correlation = report.correlation.unwrap();
+        assert_eq!(correlation.groups, 1);
+        for percentile in [85.0, 100.0] {
+            let with = report.percentiles.get(percentile).unwrap().days;
+            let without = correlation
+                .percentiles_without_correlation
+                .get(percentile)
+                .unwrap()
+                .days;
+            assert!(with > without, "P{percentile}: {with} <= {without}");
+        }
+    }
+
+    #[test]
+    fn risks_delay_affected_work_packages_and_report_their_contribution() {
+        let risk = |id: &str, probability: f32| Risk {
+            id: id.to_string(),
+            description: None,
+            probability,
+            impact: ThreePointEstimate {
+                optimistic: Some(3.0),
+                most_likely: Some(3.0),
+                pessimistic: Some(3.0),
+                distribution: None,
+            },
+            affects: vec!["WP1".to_string()],
+        };
+        let project = Project {
+            name: "Risky Project".to_string(),
+            work_packages: vec![
+                build_constant_three_point_issue("WP0", 2.0, &[]),
+                build_constant_three_point_issue("WP1", 2.0, &["WP0"]),
+            ],
+            risks: vec![risk("CERTAIN", 1.0), risk("IMPOSSIBLE", 0.0)],
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let options = ProjectSimulationOptions::new(20, on_date(2026, 1, 1));
+
+        let output = simulate_project(&project, calendar, &options).unwrap();
+
+        assert_eq!(output.results, vec![7.0; 20]);
+        let risks = output.report.risks.unwrap();
+        assert_eq!(risks[0].id, "CERTAIN");
+        assert_eq!(risks[0].occurrence_rate, 1.0);
+        assert_eq!(risks[0].p85_contribution_days, 3.0);
+        assert_eq!(risks[1].occurrence_rate, 0.0);
+        assert_eq!(risks[1].p85_contribution_days, 0.0);
+    }
+
+    #[test]
+    fn risk_contributions_compare_with_the_iterations_without_the_risk() {
+        let project = Project {
+            name: "Risky Project".to_string(),
+            work_packages: vec![
+                build_constant_three_point_issue("WP0", 2.0, &[]),
+                build_constant_three_point_issue("WP1", 2.0, &["WP0"]),
+            ],
+            risks: vec![Risk {
+                id: "LIKELY".to_string(),
+                description: None,
+                probability: 0.5,
+                impact: ThreePointEstimate {
+                    optimistic: Some(3.0),
+                    most_likely: Some(3.0),
+                    pessimistic: Some(3.0),
+                    distribution: None,
+                },
+                affects: vec!["WP1".to_string()],
+            }],
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let options = ProjectSimulationOptions::new(200, on_date(2026, 1, 1));
+
+        let output = simulate_project(&project, calendar, &options).unwrap();
+
+        let risks = output.report.risks.unwrap();
+        let occurrences = output.results.iter().filter(|&&days| days == 7.0).count();
+        assert_eq!(risks[0].occurrence_rate, occurrences as f32 / 200.0);
+        assert!((0.3..0.7).contains(&risks[0].occurrence_rate));
+        assert_eq!(risks[0].p85_contribution_days, 3.0);
+    }
+
+    #[test]
+    fn date_constraints_are_reported_with_violations_and_total_float() {
+        let mut finish = build_constant_three_point_issue("WP1", 2.0, &["WP0"]);
+        finish.constraint = Some(DateConstraint::MustFinishOnOrBefore(on_date(2026, 1, 5)));
+        let mut delivery = build_constant_three_point_issue("WP2", 1.0, &[]);
+        delivery.constraint = Some(DateConstraint::StartNoEarlierThan(on_date(2026, 1, 10)));
+        let project = Project {
+            name: "Constrained Project".to_string(),
+            work_packages: vec![
+                build_constant_three_point_issue("WP0", 4.0, &[]),
+                finish,
+                delivery,
+            ],
+            risks: Vec::new(),
+        };
+        let calendar = create_calendar_without_any_free_days();
+        let options = ProjectSimulationOptions::new(20, on_date(2026, 1, 1));
+
+        let output = simulate_project(&project, calendar, &options).unwrap();
+
+        assert_eq!(output.results, vec![10.0; 20]);
+        let constraints = output.report.constraints.unwrap();
+        assert_eq!(
+            constraints,
+            vec![
+                ConstraintForecast {
+                    id: "WP1".to_string(),
+                    constraint_type: ConstraintType::MustFinishOnOrBefore,
+                    date: on_date(2026, 1, 5),
+                    violations: 20,
+                    total_float_days_p85: -1.0,
+                },
+                ConstraintForecast {
+                    id: "WP2".to_string(),
+                    constraint_type: ConstraintType::StartNoEarlierThan,
+                    date: on_date(2026, 1, 10),
+                    violations: 0,
+                    total_float_days_p85: 0.0,
+                },
+            ]
+        );
+        let work_packages = output.report.work_packages.unwrap();
+        assert_eq!(work_packages[0].criticality_index, 1.0);
+    }
+
+    #[test]
+    fn empty_cycle_time_history_is_rejected() {
+        let project = Project {
+            name: "Demo".to_string(),
+            work_packages: vec![build_story_point_issue("WP0", 1.0, &[])],
+            risks: Vec::new(),
+        };
+        let options = ProjectSimulationOptions {
+            cycle_times: Some(CycleTimeOptions {
+                history: Vec::new(),
+                grouping: None,
+            }),
+            ..ProjectSimulationOptions::new(5, on_date(2026, 1, 1))
+        };
+
+        let error = simulate_project(&project, TeamCalendar::new(), &options).unwrap_err();
+
+        assert!(matches!(error, ProjectSimulationError::EmptyCycleTimes));
     }
 }
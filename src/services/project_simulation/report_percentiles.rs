@@ -0,0 +1,264 @@
+//! Percentile lists stored in simulation reports.
+//!
+//! Reports serialize their percentiles as a list of
+//! `{ percentile, days, end_date }` entries. Reports written before the list
+//! existed store fixed `p0`, `p15`, `p50`, `p85` and `p100` fields instead;
+//! those still deserialize into a `Percentiles` list.
+
+use crate::services::project_simulation::simulation_types::SimulationPercentile;
+use chrono::NaiveDate;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+/// Percentiles every report contains, because the plots and the reference
+/// estimate loader read them.
+pub const STANDARD_PERCENTILES: [f64; 5] = [0.0, 15.0, 50.0, 85.0, 100.0];
+
+#[derive(Error, Debug)]
+#[error("simulation report has no {} value", percentile_label(*.0))]
+pub struct MissingPercentile(pub f64);
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Percentiles {
+    entries: Vec<(f64, SimulationPercentile)>,
+}
+
+impl Percentiles {
+    /// Returns the entry for `percentile`, if the report contains it.
+    pub fn get(&self, percentile: f64) -> Option<&SimulationPercentile> {
+        self.entries
+            .iter()
+            .find(|(value, _)| (value - percentile).abs() < f64::EPSILON)
+            .map(|(_, entry)| entry)
+    }
+
+    /// Like `get`, but fails for reports that do not contain `percentile`.
+    pub fn require(&self, percentile: f64) -> Result<&SimulationPercentile, MissingPercentile> {
+        self.get(percentile).ok_or(MissingPercentile(percentile))
+    }
+
+    /// Iterates the entries in ascending percentile order.
+    pub fn iter(&self) -> impl Iterator<Item = (f64, &SimulationPercentile)> {
+        self.entries.iter().map(|(value, entry)| (*value, entry))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl FromIterator<(f64, SimulationPercentile)> for Percentiles {
+    fn from_iter<I: IntoIterator<Item = (f64, SimulationPercentile)>>(iter: I) -> Self {
+        let mut entries: Vec<(f64, SimulationPercentile)> = iter.into_iter().collect();
+        entries.sort_by(|a, b| a.0.total_cmp(&b.0));
+        entries.dedup_by(|a, b| (a.0 - b.0).abs() < f64::EPSILON);
+        Self { entries }
+    }
+}
+
+/// Merges the requested percentiles with `STANDARD_PERCENTILES`, sorted and
+/// without duplicates.
+pub fn with_standard_percentiles(requested: &[f64]) -> Vec<f64> {
+    let mut percentiles: Vec<f64> = STANDARD_PERCENTILES
+        .iter()
+        .chain(requested.iter())
+        .copied()
+        .collect();
+    percentiles.sort_by(f64::total_cmp);
+    percentiles.dedup_by(|a, b| (*a - *b).abs() < f64::EPSILON);
+    percentiles
+}
+
+/// Formats a percentile as a label like `P85` or `P97.5`.
+pub fn percentile_label(percentile: f64) -> String {
+    format!("P{percentile}")
+}
+
+#[derive(Serialize, Deserialize)]
+struct PercentileEntry {
+    percentile: f64,
+    days: f32,
+    end_date: NaiveDate,
+}
+
+#[derive(Deserialize)]
+struct LegacyPercentiles {
+    p0: SimulationPercentile,
+    p15: SimulationPercentile,
+    p50: SimulationPercentile,
+    p85: SimulationPercentile,
+    p100: SimulationPercentile,
+}
+
+impl From<LegacyPercentiles> for Percentiles {
+    fn from(legacy: LegacyPercentiles) -> Self {
+        [
+            (0.0, legacy.p0),
+            (15.0, legacy.p15),
+            (50.0, legacy.p50),
+            (85.0, legacy.p85),
+            (100.0, legacy.p100),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PercentilesYaml {
+    List(Vec<PercentileEntry>),
+    Legacy(LegacyPercentiles),
+}
+
+impl Serialize for Percentiles {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let entries: Vec<PercentileEntry> = self
+            .iter()
+            .map(|(percentile, entry)| PercentileEntry {
+                percentile,
+                days: entry.days,
+                end_date: entry.end_date,
+            })
+            .collect();
+        entries.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Percentiles {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match PercentilesYaml::deserialize(deserializer)? {
+            PercentilesYaml::List(entries) => Ok(entries
+                .into_iter()
+                .map(|entry| {
+                    (
+                        entry.percentile,
+                        SimulationPercentile {
+                            days: entry.days,
+                            end_date: entry.end_date,
+                        },
+                    )
+                })
+                .collect()),
+            PercentilesYaml::Legacy(legacy) => Ok(legacy.into()),
+        }
+    }
+}
+
+/// Serde helpers for the report-level percentiles, which are flattened into
+/// the report so that the legacy top-level `p0`..`p100` fields are found.
+pub(crate) mod flattened {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct ReportPercentilesRef<'a> {
+        percentiles: &'a Percentiles,
+    }
+
+    #[derive(Deserialize)]
+    struct ReportPercentilesYaml {
+        percentiles: Option<Percentiles>,
+        #[serde(flatten)]
+        legacy: Option<LegacyPercentiles>,
+    }
+
+    pub fn serialize<S: Serializer>(
+        percentiles: &Percentiles,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ReportPercentilesRef { percentiles }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Percentiles, D::Error> {
+        let yaml = ReportPercentilesYaml::deserialize(deserializer)?;
+        match (yaml.percentiles, yaml.legacy) {
+            (Some(percentiles), _) => Ok(percentiles),
+            (None, Some(legacy)) => Ok(legacy.into()),
+            (None, None) => Err(D::Error::missing_field("percentiles")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::on_date;
+
+    fn entry(days: f32) -> SimulationPercentile {
+        SimulationPercentile {
+            days,
+            end_date: on_date(2026, 3, 1) + chrono::Duration::days(days as i64),
+        }
+    }
+
+    #[test]
+    fn entries_are_sorted_and_deduplicated() {
+        let percentiles: Percentiles = [(85.0, entry(8.0)), (50.0, entry(5.0)), (85.0, entry(9.0))]
+            .into_iter()
+            .collect();
+
+        let values: Vec<f64> = percentiles.iter().map(|(value, _)| value).collect();
+        assert_eq!(values, vec![50.0, 85.0]);
+        assert_eq!(percentiles.get(85.0).unwrap().days, 8.0);
+        assert!(percentiles.get(70.0).is_none());
+        assert_eq!(
+            percentiles.require(70.0).unwrap_err().to_string(),
+            "simulation report has no P70 value"
+        );
+    }
+
+    #[test]
+    fn requested_percentiles_are_merged_with_standard_set() {
+        assert_eq!(
+            with_standard_percentiles(&[95.0, 50.0, 70.0]),
+            vec![0.0, 15.0, 50.0, 70.0, 85.0, 95.0, 100.0]
+        );
+        assert_eq!(
+            with_standard_percentiles(&[]),
+            STANDARD_PERCENTILES.to_vec()
+        );
+    }
+
+    #[test]
+    fn labels_omit_trailing_zero_fraction() {
+        assert_eq!(percentile_label(85.0), "P85");
+        assert_eq!(percentile_label(97.5), "P97.5");
+    }
+
+    #[test]
+    fn percentiles_round_trip_as_list() {
+        let percentiles: Percentiles = [(70.0, entry(7.0)), (95.0, entry(12.0))]
+            .into_iter()
+            .collect();
+
+        let yaml = serde_yaml::to_string(&percentiles).unwrap();
+        assert!(yaml.contains("percentile: 70.0"));
+
+        let decoded: Percentiles = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(decoded, percentiles);
+    }
+
+    #[test]
+    fn legacy_percentile_fields_deserialize_into_list() {
+        let yaml = r#"
+p0: { days: 1.0, end_date: 2026-03-02 }
+p15: { days: 2.0, end_date: 2026-03-03 }
+p50: { days: 5.0, end_date: 2026-03-06 }
+p85: { days: 8.0, end_date: 2026-03-09 }
+p100: { days: 9.0, end_date: 2026-03-10 }
+"#;
+
+        let decoded: Percentiles = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(decoded.len(), 5);
+        assert_eq!(decoded.get(50.0).unwrap().end_date, on_date(2026, 3, 6));
+        assert_eq!(decoded.get(100.0).unwrap().days, 9.0);
+    }
+}
@@ -0,0 +1,218 @@
+//! Risks that delay work packages if they occur.
+//!
+//! Every iteration rolls each risk of the project once. An occurring risk
+//! samples its delay and adds it to the duration of every work package it
+//! affects, before the critical path method schedules the iteration.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::domain::estimate::DurationDistribution;
+use crate::domain::project::Project;
+use crate::domain::risk::Risk;
+use crate::services::project_simulation::distribution_sampler::quantile_value;
+use crate::services::project_simulation::network_nodes::NetworkNode;
+use crate::services::project_simulation::sample_duration::SamplingError;
+
+/// Rolls the risks of a project in the iterations of one chunk.
+pub(crate) struct RiskRoller<'a> {
+    risks: &'a [Risk],
+    /// Work package indices affected by each risk.
+    affected: Vec<Vec<usize>>,
+    distribution: DurationDistribution,
+    /// Risk that is rolled but never applied, to measure its contribution.
+    disabled: Option<usize>,
+    rng: StdRng,
+    /// Whether each risk occurred, per iteration.
+    occurred: Vec<Vec<bool>>,
+}
+
+impl<'a> RiskRoller<'a> {
+    /// `distribution` applies to impacts that do not select their own.
+    pub fn new(
+        project: &'a Project,
+        distribution: DurationDistribution,
+        disabled: Option<usize>,
+        seed: u64,
+    ) -> Self {
+        let affected = project
+            .risks
+            .iter()
+            .map(|risk| {
+                project
+                    .work_packages
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, issue)| {
+                        issue
+                            .issue_id
+                            .as_ref()
+                            .is_some_and(|id| risk.affects.contains(&id.id))
+                    })
+                    .map(|(index, _)| index)
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            risks: &project.risks,
+            affected,
+            distribution,
+            disabled,
+            rng: StdRng::seed_from_u64(seed),
+            occurred: vec![Vec::new(); project.risks.len()],
+        }
+    }
+
+    /// Rolls every risk once and delays the nodes affected by those that
+    /// occur. `nodes` must be in the order of the project's work packages.
+    pub fn apply(&mut self, nodes: &mut [NetworkNode]) -> Result<(), SamplingError> {
+        for (index, risk) in self.risks.iter().enumerate() {
+            // Both numbers are drawn in every iteration, so disabling one
+            // risk leaves the rolls of all others unchanged.
+            let roll: f32 = self.rng.gen_range(0.0..1.0);
+            let quantile: f64 = self.rng.gen_range(0.0..1.0);
+            let occurs = roll < risk.probability;
+            self.occurred[index].push(occurs);
+            if !occurs || self.disabled == Some(index) {
+                continue;
+            }
+
+            let delay = sample_delay(risk, self.distribution, quantile)?;
+            for &node_index in &self.affected[index] {
+                if let Some(node) = nodes.get_mut(node_index) {
+                    node.duration += delay;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether each risk occurred, per iteration in the order of the calls
+    /// to `apply`.
+    pub fn into_occurred(self) -> Vec<Vec<bool>> {
+        self.occurred
+    }
+}
+
+fn sample_delay(
+    risk: &Risk,
+    distribution: DurationDistribution,
+    quantile: f64,
+) -> Result<f32, SamplingError> {
+    let impact = &risk.impact;
+    let (Some(optimistic), Some(most_likely), Some(pessimistic)) =
+        (impact.optimistic, impact.most_likely, impact.pessimistic)
+    else {
+        return Err(SamplingError::InvalidEstimate(format!(
+            "Missing impact of risk: {}",
+            risk.id
+        )));
+    };
+    quantile_value(
+        impact.distribution.unwrap_or(distribution),
+        optimistic,
+        most_likely,
+        pessimistic,
+        quantile,
+    )
+    .map_err(|_| SamplingError::InvalidEstimate(format!("Sampling failed: {}", risk.id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::estimate::ThreePointEstimate;
+    use crate::test_support::build_three_point_issue;
+
+    fn risk(id: &str, probability: f32, delay: f32, affects: &[&str]) -> Risk {
+        Risk {
+            id: id.to_string(),
+            description: None,
+            probability,
+            impact: ThreePointEstimate {
+                optimistic: Some(delay),
+                most_likely: Some(delay),
+                pessimistic: Some(delay),
+                distribution: None,
+            },
+            affects: affects.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    fn project(risks: Vec<Risk>) -> Project {
+        Project {
+            name: "Risky".to_string(),
+            work_packages: vec![
+                build_three_point_issue("WP0", 1.0, 1.0, 1.0, &[]),
+                build_three_point_issue("WP1", 1.0, 1.0, 1.0, &[]),
+            ],
+            risks,
+        }
+    }
+
+    fn nodes() -> Vec<NetworkNode> {
+        ["WP0", "WP1"]
+            .into_iter()
+            .map(|id| NetworkNode {
+                id: id.to_string(),
+                is_milestone: false,
+                duration: 1.0,
+                start_date: None,
+                end_date: None,
+                constraint: None,
+                dependencies: Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn certain_risks_delay_the_affected_work_packages() {
+        let project = project(vec![risk("R1", 1.0, 3.0, &["WP1"])]);
+        let mut roller = RiskRoller::new(&project, DurationDistribution::BetaPert, None, 1);
+        let mut nodes = nodes();
+
+        roller.apply(&mut nodes).unwrap();
+
+        assert_eq!(nodes[0].duration, 1.0);
+        assert_eq!(nodes[1].duration, 4.0);
+        assert_eq!(roller.into_occurred(), vec![vec![true]]);
+    }
+
+    #[test]
+    fn risks_occur_at_their_probability() {
+        let project = project(vec![
+            risk("R1", 0.25, 1.0, &["WP0"]),
+            risk("R2", 0.0, 1.0, &["WP1"]),
+        ]);
+        let mut roller = RiskRoller::new(&project, DurationDistribution::BetaPert, None, 1);
+
+        for _ in 0..10_000 {
+            roller.apply(&mut nodes()).unwrap();
+        }
+
+        let occurrences: Vec<usize> = roller
+            .into_occurred()
+            .iter()
+            .map(|occurred| occurred.iter().filter(|&&occurs| occurs).count())
+            .collect();
+        assert!((2300..2700).contains(&occurrences[0]), "{occurrences:?}");
+        assert_eq!(occurrences[1], 0);
+    }
+
+    #[test]
+    fn disabled_risks_are_counted_but_not_applied() {
+        let project = project(vec![
+            risk("R1", 1.0, 3.0, &["WP0", "WP1"]),
+            risk("R2", 1.0, 2.0, &["WP1"]),
+        ]);
+        let mut roller = RiskRoller::new(&project, DurationDistribution::BetaPert, Some(0), 1);
+        let mut nodes = nodes();
+
+        roller.apply(&mut nodes).unwrap();
+
+        assert_eq!(nodes[0].duration, 1.0);
+        assert_eq!(nodes[1].duration, 3.0);
+        assert_eq!(roller.into_occurred(), vec![vec![true], vec![true]]);
+    }
+}
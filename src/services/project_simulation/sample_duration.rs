@@ -1,5 +1,5 @@
 use crate::domain::estimate::{
-    Estimate, ReferenceEstimate, StoryPointEstimate, ThreePointEstimate,
+    DurationDistribution, Estimate, ReferenceEstimate, StoryPointEstimate, ThreePointEstimate,
 };
 use crate::services::project_simulation::beta_pert_sampler::ThreePointSampler;
 use thiserror::Error;
@@ -18,6 +18,7 @@ pub(crate) fn sample_duration_days<R: ThreePointSampler + ?Sized>(
     estimate: &Estimate,
     velocity: Option<f32>,
     sampler: &mut R,
+    quantile: Option<f64>,
     issue_id: &str,
 ) -> Result<f32, SamplingError> {
     let (optimistic, most_likely, pessimistic, is_story_point_estimate) = match estimate {
@@ -26,17 +27,30 @@ pub(crate) fn sample_duration_days<R: ThreePointSampler + ?Sized>(
         Estimate::Reference(estimate) => to_reference_triplet(estimate, issue_id)?,
         Estimate::Milestone => (0.0, 0.0, 0.0, false),
     };
+    // The project's distribution only applies to three-point estimates; the
+    // Fibonacci bounds of story points are always sampled with PERT.
+    let distribution = match estimate {
+        Estimate::StoryPoint(_) => Some(DurationDistribution::BetaPert),
+        Estimate::ThreePoint(estimate) => estimate.distribution,
+        Estimate::Reference(estimate) => estimate
+            .cached_estimate
+            .as_ref()
+            .and_then(|cached| cached.distribution),
+        Estimate::Milestone => None,
+    };
 
-    let sampled = sampler
-        .sample(optimistic, most_likely, pessimistic)
-        .map_err(|_| {
-            SamplingError::InvalidEstimate(format!("Sampling failed: {}", issue_id.to_string()))
-        })?;
+    let sampled = match quantile {
+        Some(quantile) => {
+            sampler.sample_at_quantile(distribution, optimistic, most_likely, pessimistic, quantile)
+        }
+        None => sampler.sample_distribution(distribution, optimistic, most_likely, pessimistic),
+    }
+    .map_err(|_| SamplingError::InvalidEstimate(format!("Sampling failed: {}", issue_id)))?;
 
     if !sampled.is_finite() {
         return Err(SamplingError::InvalidEstimate(format!(
             "sample is infinite: {}",
-            issue_id.to_string()
+            issue_id
         )));
     }
 
@@ -56,10 +70,7 @@ fn to_reference_triplet(
     issue_id: &str,
 ) -> Result<(f32, f32, f32, bool), SamplingError> {
     let cached = reference.cached_estimate.as_ref().ok_or_else(|| {
-        SamplingError::InvalidEstimate(format!(
-            "Missing referenced estimate: {}",
-            issue_id.to_string()
-        ))
+        SamplingError::InvalidEstimate(format!("Missing referenced estimate: {}", issue_id))
     })?;
     to_three_point_triplet(cached)
 }
@@ -69,10 +80,7 @@ fn to_story_point_triplet(
     issue_id: &str,
 ) -> Result<(f32, f32, f32, bool), SamplingError> {
     let value = story_points.estimate.ok_or_else(|| {
-        SamplingError::InvalidEstimate(format!(
-            "Missing story point estimate: {}",
-            issue_id.to_string()
-        ))
+        SamplingError::InvalidEstimate(format!("Missing story point estimate: {}", issue_id))
     })?;
     let (lower, upper) = fibonacci_bounds(value);
     let is_story_point_estimate = true;
@@ -187,4 +195,32 @@ mod tests {
             assert!(is_story_point_estimate);
         }
     }
+
+    #[test]
+    fn estimate_distribution_is_passed_to_the_sampler() {
+        use crate::services::project_simulation::distribution_sampler::DistributionSampler;
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut sampler =
+            DistributionSampler::new(StdRng::seed_from_u64(1), DurationDistribution::LogNormal);
+        // Log-normal would reject the optimistic value of 0.
+        let three_point = Estimate::ThreePoint(ThreePointEstimate {
+            optimistic: Some(0.0),
+            most_likely: Some(1.0),
+            pessimistic: Some(2.0),
+            distribution: Some(DurationDistribution::Uniform),
+        });
+        let story_points = Estimate::StoryPoint(StoryPointEstimate {
+            estimate: Some(1.0),
+        });
+
+        let three_point_days =
+            sample_duration_days(&three_point, None, &mut sampler, None, "ABC-1").unwrap();
+        let story_point_days =
+            sample_duration_days(&story_points, Some(1.0), &mut sampler, None, "ABC-2").unwrap();
+
+        assert!((0.0..=2.0).contains(&three_point_days));
+        assert!((0.0..=3.0).contains(&story_point_days));
+    }
 }
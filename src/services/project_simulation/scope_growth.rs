@@ -0,0 +1,125 @@
+//! Growth of a backlog while it is worked on.
+//!
+//! Stories split and new bugs are reported, so more issues are done than
+//! were counted at the start. A `ScopeModel` inflates the number of issues of
+//! every simulation iteration accordingly.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::throughput::ThroughputHistory;
+use crate::services::parsing::throughput_yaml::deserialize_throughput_from_yaml_str;
+use crate::services::project_simulation::throughput_simulation::SimulationError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScopeModel {
+    /// Every issue turns into between `min` and `max` issues; the factor is
+    /// sampled once per iteration.
+    SplitFactor { min: f32, max: f32 },
+    /// New issues arrive at this rate per working day at full capacity.
+    ArrivalRate { issues_per_day: f32 },
+}
+
+impl ScopeModel {
+    /// Learns the arrival rate from a throughput file counting created
+    /// instead of done issues, as written by `get throughput --created`.
+    pub fn arrival_rate_from_file(path: &str) -> Result<Self, SimulationError> {
+        let yaml = std::fs::read_to_string(path)?;
+        Self::arrival_rate_from_history(&deserialize_throughput_from_yaml_str(&yaml)?)
+    }
+
+    /// The mean number of issues created per working day at full capacity.
+    pub fn arrival_rate_from_history(history: &ThroughputHistory) -> Result<Self, SimulationError> {
+        let rates: Vec<f32> = history
+            .throughput
+            .iter()
+            .filter_map(|value| history.bucket.rate_at_full_capacity(value))
+            .collect();
+        if rates.is_empty() {
+            return Err(SimulationError::EmptyArrivals);
+        }
+
+        Ok(Self::ArrivalRate {
+            issues_per_day: rates.iter().sum::<f32>() / rates.len() as f32,
+        })
+    }
+
+    /// The number of issues an iteration starts with.
+    pub(crate) fn sample_issue_count<R: Rng + ?Sized>(
+        &self,
+        number_of_issues: usize,
+        rng: &mut R,
+    ) -> f32 {
+        match *self {
+            Self::SplitFactor { min, max } => number_of_issues as f32 * rng.gen_range(min..=max),
+            Self::ArrivalRate { .. } => number_of_issues as f32,
+        }
+    }
+
+    /// Issues added per working day at full capacity.
+    pub(crate) fn arrivals_per_day(&self) -> f32 {
+        match *self {
+            Self::SplitFactor { .. } => 0.0,
+            Self::ArrivalRate { issues_per_day } => issues_per_day,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::throughput::{Throughput, ThroughputBucket};
+    use chrono::NaiveDate;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn arrival_rate_is_the_mean_rate_at_full_capacity() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let history = ThroughputHistory {
+            bucket: ThroughputBucket::Week,
+            throughput: vec![
+                Throughput {
+                    date: date(2),
+                    completed_issues: 5,
+                    capacity: Some(5.0),
+                },
+                Throughput {
+                    date: date(9),
+                    completed_issues: 6,
+                    capacity: Some(2.0),
+                },
+            ],
+        };
+
+        let model = ScopeModel::arrival_rate_from_history(&history).unwrap();
+
+        assert_eq!(
+            model,
+            ScopeModel::ArrivalRate {
+                issues_per_day: 2.0
+            }
+        );
+        assert_eq!(model.arrivals_per_day(), 2.0);
+    }
+
+    #[test]
+    fn empty_arrival_history_is_rejected() {
+        let error = ScopeModel::arrival_rate_from_history(&ThroughputHistory::default()).err();
+
+        assert!(matches!(error, Some(SimulationError::EmptyArrivals)));
+    }
+
+    #[test]
+    fn split_factor_inflates_the_issue_count_within_its_range() {
+        let model = ScopeModel::SplitFactor { min: 1.5, max: 2.0 };
+        let mut rng = StdRng::seed_from_u64(4);
+
+        for _ in 0..100 {
+            let issues = model.sample_issue_count(10, &mut rng);
+            assert!((15.0..=20.0).contains(&issues), "{issues} issues");
+        }
+        assert_eq!(model.arrivals_per_day(), 0.0);
+    }
+}
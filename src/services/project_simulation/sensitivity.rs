@@ -0,0 +1,80 @@
+//! Sensitivity measures of a work package, collected over all iterations.
+//!
+//! The criticality index tells how often a package ended up on the critical
+//! path. The duration correlation tells how strongly its sampled duration
+//! drives the project duration. Together they show which estimates are worth
+//! refining first.
+
+/// Share of the iterations in which the package was critical.
+pub(crate) fn criticality_index(critical_flags: &[bool]) -> f32 {
+    if critical_flags.is_empty() {
+        return 0.0;
+    }
+    let critical = critical_flags
+        .iter()
+        .filter(|is_critical| **is_critical)
+        .count();
+    critical as f32 / critical_flags.len() as f32
+}
+
+/// Pearson correlation of `durations` with `project_durations`, where both
+/// slices hold one value per iteration in the same order.
+///
+/// Returns 0.0 if either side does not vary, e.g. for done work packages.
+pub(crate) fn duration_correlation(durations: &[f32], project_durations: &[f32]) -> f32 {
+    let count = durations.len().min(project_durations.len());
+    if count < 2 {
+        return 0.0;
+    }
+
+    let mean = |values: &[f32]| values.iter().map(|v| *v as f64).sum::<f64>() / count as f64;
+    let mean_x = mean(&durations[..count]);
+    let mean_y = mean(&project_durations[..count]);
+
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    let mut variance_y = 0.0;
+    for (x, y) in durations.iter().zip(project_durations) {
+        let dx = *x as f64 - mean_x;
+        let dy = *y as f64 - mean_y;
+        covariance += dx * dy;
+        variance_x += dx * dx;
+        variance_y += dy * dy;
+    }
+
+    if variance_x <= f64::EPSILON || variance_y <= f64::EPSILON {
+        return 0.0;
+    }
+    (covariance / (variance_x * variance_y).sqrt()) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn criticality_index_is_share_of_critical_iterations() {
+        assert_eq!(criticality_index(&[true, false, true, true]), 0.75);
+        assert_eq!(criticality_index(&[]), 0.0);
+    }
+
+    #[test]
+    fn duration_correlation_detects_linear_relations() {
+        let durations = [1.0, 2.0, 3.0, 4.0];
+
+        let positive = duration_correlation(&durations, &[10.0, 12.0, 14.0, 16.0]);
+        let negative = duration_correlation(&durations, &[16.0, 14.0, 12.0, 10.0]);
+
+        assert!((positive - 1.0).abs() < 1e-6);
+        assert!((negative + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn duration_correlation_is_zero_without_variance() {
+        assert_eq!(
+            duration_correlation(&[2.0, 2.0, 2.0], &[5.0, 6.0, 7.0]),
+            0.0
+        );
+        assert_eq!(duration_correlation(&[1.0], &[5.0]), 0.0);
+    }
+}
@@ -1,8 +1,11 @@
+use crate::services::project_simulation::correlation::CorrelationGrouping;
+use crate::services::project_simulation::report_percentiles::{Percentiles, flattened};
+use crate::services::project_simulation::scope_growth::ScopeModel;
 use chrono::NaiveDate;
 use serde::Deserialize;
 use serde::Serialize;
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct SimulationPercentile {
     pub days: f32,
     pub end_date: NaiveDate,
@@ -14,29 +17,120 @@ pub struct SimulationReport {
     pub start_date: NaiveDate,
     pub velocity: Option<f32>,
     pub iterations: usize,
+    /// Seed of the random number generator; rerunning with it reproduces the report.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
     pub simulated_items: usize,
-    pub p0: SimulationPercentile,
-    pub p15: SimulationPercentile,
-    pub p50: SimulationPercentile,
-    pub p85: SimulationPercentile,
-    pub p100: SimulationPercentile,
+    #[serde(flatten, with = "flattened")]
+    pub percentiles: Percentiles,
     pub work_packages: Option<Vec<WorkPackageSimulation>>,
+    /// Hit probabilities of the project deadline and the milestone due dates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deadlines: Option<Vec<DeadlineForecast>>,
+    /// Throughput history the samples were drawn from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample_window: Option<SampleWindow>,
+    /// Set if the percentiles include scope growth; holds the forecast
+    /// without it for comparison.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope_growth: Option<ScopeGrowthForecast>,
+    /// Set if estimation errors were correlated; holds the forecast with
+    /// independent work packages for comparison.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation: Option<CorrelationForecast>,
+    /// Occurrence rates and P85 contributions of the project's risks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub risks: Option<Vec<RiskForecast>>,
+    /// Violations and total floats of the work packages with date constraints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<Vec<ConstraintForecast>>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct WorkPackagePercentiles {
-    pub p0: SimulationPercentile,
-    pub p15: SimulationPercentile,
-    pub p50: SimulationPercentile,
-    pub p85: SimulationPercentile,
-    pub p100: SimulationPercentile,
+/// Dates of the first and the last throughput value that were sampled.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SampleWindow {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub samples: usize,
+    /// Set if more recent values were sampled more often.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub half_life_days: Option<f32>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ScopeGrowthForecast {
+    pub model: ScopeModel,
+    /// Percentiles for the issues known at the start, using the same seed.
+    pub percentiles_without_growth: Percentiles,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct CorrelationForecast {
+    pub strength: f32,
+    pub grouping: CorrelationGrouping,
+    /// Number of groups whose work packages share their estimation error.
+    pub groups: usize,
+    /// Percentiles with independently sampled work packages, using the same seed.
+    pub percentiles_without_correlation: Percentiles,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct RiskForecast {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub probability: f32,
+    /// Share of the iterations in which the risk occurred.
+    pub occurrence_rate: f32,
+    pub affects: Vec<String>,
+    /// Days the risk adds to the P85 duration, compared to the iterations
+    /// in which it did not occur.
+    pub p85_contribution_days: f32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConstraintType {
+    StartNoEarlierThan,
+    MustFinishOnOrBefore,
+    FixedDate,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ConstraintForecast {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub constraint_type: ConstraintType,
+    pub date: NaiveDate,
+    /// Number of iterations in which the package finished after `date`.
+    pub violations: usize,
+    /// Total float reached in 85% of the iterations; negative if the
+    /// constraint is missed in more than 15% of them.
+    pub total_float_days_p85: f32,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct WorkPackageSimulation {
     pub id: String,
     pub is_milestone: bool,
-    pub percentiles: WorkPackagePercentiles,
+    pub percentiles: Percentiles,
+    /// Share of the iterations in which the package was on the critical path.
+    #[serde(default)]
+    pub criticality_index: f32,
+    /// Correlation of the sampled duration with the project duration.
+    #[serde(default)]
+    pub duration_correlation: f32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct DeadlineForecast {
+    /// Milestone id, or `None` for the project deadline.
+    pub id: Option<String>,
+    pub due_date: NaiveDate,
+    /// Share of the iterations finishing on or before `due_date`.
+    pub probability: f32,
+    /// Days from the P85 end date to `due_date`; negative if P85 is late.
+    pub slack_days_p85: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -45,6 +139,30 @@ pub struct SimulationOutput {
     pub results: Vec<f32>,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ItemCountPercentile {
+    pub percentile: f64,
+    pub items: usize,
+}
+
+/// Distribution of the number of items finished between `start_date` and
+/// `end_date` (inclusive).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ItemForecastReport {
+    pub data_source: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub iterations: usize,
+    pub seed: Option<u64>,
+    pub percentiles: Vec<ItemCountPercentile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemForecastOutput {
+    pub report: ItemForecastReport,
+    pub results: Vec<f32>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,4 +181,106 @@ mod tests {
         assert_eq!(decoded.end_date, percentile.end_date);
         assert_eq!(decoded.days, percentile.days);
     }
+
+    #[test]
+    fn report_with_legacy_percentile_fields_deserializes() {
+        let yaml = r#"
+data_source: old.yaml
+start_date: 2026-02-01
+velocity: null
+iterations: 10
+simulated_items: 1
+p0: { days: 1.0, end_date: 2026-02-02 }
+p15: { days: 2.0, end_date: 2026-02-03 }
+p50: { days: 3.0, end_date: 2026-02-04 }
+p85: { days: 4.0, end_date: 2026-02-05 }
+p100: { days: 5.0, end_date: 2026-02-06 }
+work_packages:
+- id: WP1
+  is_milestone: false
+  percentiles:
+    p0: { days: 1.0, end_date: 2026-02-02 }
+    p15: { days: 1.0, end_date: 2026-02-02 }
+    p50: { days: 2.0, end_date: 2026-02-03 }
+    p85: { days: 2.0, end_date: 2026-02-03 }
+    p100: { days: 3.0, end_date: 2026-02-04 }
+"#;
+
+        let report: SimulationReport = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(report.seed, None);
+        assert_eq!(report.percentiles.len(), 5);
+        assert_eq!(report.percentiles.get(85.0).unwrap().days, 4.0);
+        let wp = &report.work_packages.as_ref().unwrap()[0];
+        assert_eq!(
+            wp.percentiles.get(100.0).unwrap().end_date,
+            NaiveDate::from_ymd_opt(2026, 2, 4).unwrap()
+        );
+        assert_eq!(wp.criticality_index, 0.0);
+        assert_eq!(report.deadlines, None);
+    }
+
+    #[test]
+    fn report_percentiles_round_trip_as_list() {
+        let end_date = NaiveDate::from_ymd_opt(2026, 2, 4).unwrap();
+        let report = SimulationReport {
+            data_source: "new.yaml".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+            velocity: None,
+            iterations: 10,
+            seed: Some(3),
+            simulated_items: 1,
+            percentiles: [(
+                70.0,
+                SimulationPercentile {
+                    days: 3.0,
+                    end_date,
+                },
+            )]
+            .into_iter()
+            .collect(),
+            work_packages: None,
+            deadlines: None,
+            sample_window: None,
+            scope_growth: None,
+            correlation: None,
+            risks: None,
+            constraints: None,
+        };
+
+        let yaml = serde_yaml::to_string(&report).unwrap();
+        assert!(yaml.contains("percentiles:\n- percentile: 70.0"));
+        assert!(!yaml.contains("p0:"));
+        assert!(!yaml.contains("deadlines:"));
+
+        let decoded: SimulationReport = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(decoded.percentiles, report.percentiles);
+        assert_eq!(decoded.seed, Some(3));
+    }
+
+    #[test]
+    fn report_without_seed_omits_it() {
+        let report = SimulationReport {
+            data_source: "unseeded.yaml".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+            velocity: None,
+            iterations: 10,
+            seed: None,
+            simulated_items: 1,
+            percentiles: Percentiles::default(),
+            work_packages: None,
+            deadlines: None,
+            sample_window: None,
+            scope_growth: None,
+            correlation: None,
+            risks: None,
+            constraints: None,
+        };
+
+        let yaml = serde_yaml::to_string(&report).unwrap();
+        assert!(!yaml.contains("seed:"));
+
+        let decoded: SimulationReport = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(decoded.seed, None);
+    }
 }
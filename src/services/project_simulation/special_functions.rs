@@ -0,0 +1,246 @@
+//! Distribution functions that `rand_distr` does not provide. They map a
+//! quantile to a value, which lets correlated work packages share part of
+//! their randomness.
+
+use std::f64::consts::{PI, SQRT_2};
+
+/// Cumulative distribution function of the standard normal distribution.
+pub(crate) fn standard_normal_cdf(z: f64) -> f64 {
+    0.5 * erfc(-z / SQRT_2)
+}
+
+/// Complementary error function with a fractional error below 1.2e-7
+/// (Chebyshev fit from Numerical Recipes).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let polynomial = [
+        -1.265_512_23,
+        1.000_023_68,
+        0.374_091_96,
+        0.096_784_18,
+        -0.186_288_06,
+        0.278_868_07,
+        -1.135_203_98,
+        1.488_515_87,
+        -0.822_152_23,
+        0.170_872_77,
+    ]
+    .iter()
+    .rev()
+    .fold(0.0, |sum, coefficient| coefficient + t * sum);
+    let result = t * (-z * z + polynomial).exp();
+    if x >= 0.0 { result } else { 2.0 - result }
+}
+
+/// Inverse of `standard_normal_cdf` with a relative error below 1.2e-9
+/// (algorithm by Peter Acklam).
+pub(crate) fn standard_normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    let tail = |q: f64| {
+        let numerator = C.iter().fold(0.0, |sum, c| sum * q + c);
+        let denominator = D.iter().fold(0.0, |sum, d| sum * q + d) * q + 1.0;
+        numerator / denominator
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        let numerator = A.iter().fold(0.0, |sum, a| sum * r + a) * q;
+        let denominator = B.iter().fold(0.0, |sum, b| sum * r + b) * r + 1.0;
+        numerator / denominator
+    }
+}
+
+/// Inverse of the cumulative distribution function of the beta distribution.
+pub(crate) fn beta_quantile(alpha: f64, beta: f64, p: f64) -> f64 {
+    if p <= 0.0 {
+        return 0.0;
+    }
+    if p >= 1.0 {
+        return 1.0;
+    }
+
+    // Bisection: 50 halvings are far below the precision of a day.
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..50 {
+        let middle = 0.5 * (low + high);
+        if regularized_incomplete_beta(alpha, beta, middle) < p {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    0.5 * (low + high)
+}
+
+/// Cumulative distribution function of the beta distribution, I_x(a, b).
+fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // The continued fraction converges quickly on this side of the mean.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Continued fraction of the incomplete beta function, evaluated with the
+/// modified Lentz method (Numerical Recipes).
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const EPSILON: f64 = 1e-14;
+    const TINY: f64 = 1e-300;
+    let not_tiny = |value: f64| if value.abs() < TINY { TINY } else { value };
+
+    let mut c = 1.0;
+    let mut d = 1.0 / not_tiny(1.0 - (a + b) * x / (a + 1.0));
+    let mut result = d;
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / not_tiny(1.0 + even * d);
+        c = not_tiny(1.0 + even / c);
+        result *= d * c;
+
+        let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / not_tiny(1.0 + odd * d);
+        c = not_tiny(1.0 + odd / c);
+        let delta = d * c;
+        result *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    result
+}
+
+/// Natural logarithm of the gamma function (Lanczos approximation).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection formula.
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (index, coefficient)| {
+            sum + coefficient / (x + index as f64 + 1.0)
+        });
+    let t = x + 7.5;
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn standard_normal_cdf_matches_known_values() {
+        assert_close(standard_normal_cdf(0.0), 0.5, 1e-7);
+        assert_close(standard_normal_cdf(1.959_964), 0.975, 1e-7);
+        assert_close(standard_normal_cdf(-1.281_552), 0.1, 1e-7);
+    }
+
+    #[test]
+    fn standard_normal_quantile_inverts_the_cdf() {
+        assert_close(standard_normal_quantile(0.975), 1.959_964, 1e-6);
+        for z in [-4.0, -2.5, -0.3, 0.0, 0.7, 2.2, 4.0] {
+            assert_close(standard_normal_quantile(standard_normal_cdf(z)), z, 1e-5);
+        }
+    }
+
+    #[test]
+    fn ln_gamma_matches_factorials() {
+        assert_close(ln_gamma(1.0), 0.0, 1e-12);
+        assert_close(ln_gamma(5.0), 24.0_f64.ln(), 1e-12);
+        assert_close(ln_gamma(0.5), PI.sqrt().ln(), 1e-12);
+    }
+
+    #[test]
+    fn regularized_incomplete_beta_matches_closed_forms() {
+        for x in [0.1, 0.5, 0.9] {
+            assert_close(regularized_incomplete_beta(1.0, 1.0, x), x, 1e-12);
+            assert_close(regularized_incomplete_beta(2.0, 1.0, x), x * x, 1e-12);
+        }
+        assert_close(regularized_incomplete_beta(3.5, 3.5, 0.5), 0.5, 1e-12);
+    }
+
+    #[test]
+    fn beta_quantile_inverts_the_cdf() {
+        for p in [0.01, 0.15, 0.5, 0.85, 0.99] {
+            let x = beta_quantile(1.8, 4.2, p);
+            assert_close(regularized_incomplete_beta(1.8, 4.2, x), p, 1e-10);
+        }
+        assert_eq!(beta_quantile(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(beta_quantile(2.0, 3.0, 1.0), 1.0);
+    }
+}
@@ -0,0 +1,192 @@
+use chrono::Duration;
+use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::seq::SliceRandom;
+
+use crate::domain::throughput::ThroughputHistory;
+use crate::services::project_simulation::simulation_types::SampleWindow;
+use crate::services::project_simulation::throughput_simulation::SimulationError;
+
+/// Which throughput values a simulation samples from, and how often.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SamplingOptions {
+    /// Only sample values of the last weeks before the latest value.
+    pub window_weeks: Option<u32>,
+    /// Halves the weight of a value for every `half_life_days` it is older
+    /// than the latest value.
+    pub half_life_days: Option<f32>,
+}
+
+/// Draws throughput rates at full capacity from a throughput history.
+pub(crate) struct ThroughputSampler {
+    rates: Vec<f32>,
+    /// Uniform sampling if `None`.
+    weights: Option<WeightedIndex<f64>>,
+    window: SampleWindow,
+}
+
+impl ThroughputSampler {
+    pub fn new(
+        history: &ThroughputHistory,
+        options: SamplingOptions,
+    ) -> Result<Self, SimulationError> {
+        let last_date = history
+            .throughput
+            .iter()
+            .map(|value| value.date)
+            .max()
+            .ok_or(SimulationError::EmptyThroughput)?;
+        let first_date = match options.window_weeks {
+            Some(weeks) => last_date - Duration::weeks(weeks as i64) + Duration::days(1),
+            None => history
+                .throughput
+                .iter()
+                .map(|value| value.date)
+                .min()
+                .unwrap_or(last_date),
+        };
+        if let Some(half_life_days) = options.half_life_days
+            && !(half_life_days > 0.0 && half_life_days.is_finite())
+        {
+            return Err(SimulationError::InvalidHalfLife(half_life_days));
+        }
+
+        let mut dates = Vec::new();
+        let mut rates = Vec::new();
+        let mut weights = Vec::new();
+        for value in history
+            .throughput
+            .iter()
+            .filter(|value| value.date >= first_date)
+        {
+            let Some(rate) = history.bucket.rate_at_full_capacity(value) else {
+                continue;
+            };
+            dates.push(value.date);
+            rates.push(rate);
+            if let Some(half_life_days) = options.half_life_days {
+                let age_days = (last_date - value.date).num_days() as f64;
+                weights.push(0.5_f64.powf(age_days / half_life_days as f64));
+            }
+        }
+        if rates.is_empty() {
+            return Err(SimulationError::EmptyThroughput);
+        }
+
+        let weights = match options.half_life_days {
+            Some(_) => {
+                Some(WeightedIndex::new(&weights).map_err(|_| SimulationError::EmptyThroughput)?)
+            }
+            None => None,
+        };
+        let window = SampleWindow {
+            start_date: dates.iter().copied().min().unwrap_or(first_date),
+            end_date: last_date,
+            samples: rates.len(),
+            half_life_days: options.half_life_days,
+        };
+        Ok(Self {
+            rates,
+            weights,
+            window,
+        })
+    }
+
+    pub fn rates(&self) -> &[f32] {
+        &self.rates
+    }
+
+    pub fn window(&self) -> &SampleWindow {
+        &self.window
+    }
+
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
+        match &self.weights {
+            Some(weights) => self.rates[weights.sample(rng)],
+            None => self.rates.choose(rng).copied().unwrap_or(0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::throughput::{Throughput, ThroughputBucket};
+    use chrono::NaiveDate;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn daily(values: &[(u32, usize)]) -> ThroughputHistory {
+        ThroughputHistory {
+            bucket: ThroughputBucket::Day,
+            throughput: values
+                .iter()
+                .map(|&(day, completed_issues)| Throughput {
+                    date: NaiveDate::from_ymd_opt(2026, 3, day).unwrap(),
+                    completed_issues,
+                    capacity: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn window_keeps_only_the_last_weeks() {
+        let history = daily(&[(2, 9), (9, 1), (16, 2), (20, 3)]);
+
+        let sampler = ThroughputSampler::new(
+            &history,
+            SamplingOptions {
+                window_weeks: Some(1),
+                ..SamplingOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(sampler.rates(), &[2.0, 3.0]);
+        assert_eq!(
+            sampler.window(),
+            &SampleWindow {
+                start_date: NaiveDate::from_ymd_opt(2026, 3, 16).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2026, 3, 20).unwrap(),
+                samples: 2,
+                half_life_days: None,
+            }
+        );
+    }
+
+    #[test]
+    fn half_life_prefers_recent_values() {
+        let history = daily(&[(2, 0), (30, 1)]);
+        let sampler = ThroughputSampler::new(
+            &history,
+            SamplingOptions {
+                half_life_days: Some(7.0),
+                ..SamplingOptions::default()
+            },
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(11);
+
+        let recent = (0..1000)
+            .filter(|_| sampler.sample(&mut rng) == 1.0)
+            .count();
+
+        // Four half-lives apart: the old value has a weight of 1/16.
+        assert!(recent > 900, "recent value sampled {recent} times");
+    }
+
+    #[test]
+    fn invalid_half_life_is_rejected() {
+        for half_life_days in [0.0, f32::NAN, f32::INFINITY] {
+            let options = SamplingOptions {
+                half_life_days: Some(half_life_days),
+                ..SamplingOptions::default()
+            };
+
+            let error = ThroughputSampler::new(&daily(&[(2, 1)]), options).err();
+
+            assert!(matches!(error, Some(SimulationError::InvalidHalfLife(_))));
+        }
+    }
+}
@@ -1,22 +1,26 @@
 use crate::domain::calendar::TeamCalendar;
-use crate::domain::throughput::Throughput;
+use crate::domain::throughput::ThroughputHistory;
 use crate::services::parsing::throughput_yaml::{
     ThroughputYamlError, deserialize_throughput_from_yaml_str,
 };
 use crate::services::util::data_source_name;
 use chrono::NaiveDate;
-use rand::Rng;
-use rand::seq::SliceRandom;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use thiserror::Error;
 
 use crate::services::parsing::team_calendar_yaml::{
     TeamCalendarYamlError, load_team_calendar_if_provided,
 };
-use crate::services::plotting::histogram::{HistogramError, write_histogram_png};
 use crate::services::project_simulation::percentiles;
+use crate::services::project_simulation::report_percentiles::{
+    Percentiles, STANDARD_PERCENTILES, with_standard_percentiles,
+};
+use crate::services::project_simulation::scope_growth::ScopeModel;
 use crate::services::project_simulation::simulation_types::{
-    SimulationOutput, SimulationPercentile, SimulationReport,
+    ScopeGrowthForecast, SimulationOutput, SimulationPercentile, SimulationReport,
 };
+use crate::services::project_simulation::throughput_sampler::{SamplingOptions, ThroughputSampler};
 #[derive(Error, Debug)]
 pub enum SimulationError {
     #[error("failed to read throughput file: {0}")]
@@ -27,61 +31,113 @@ pub enum SimulationError {
     InvalidIterations,
     #[error("number of issues must be greater than zero")]
     InvalidIssueCount,
+    #[error("end date {end_date} is before start date {start_date}")]
+    EndDateBeforeStartDate {
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    },
     #[error("throughput data is empty")]
     EmptyThroughput,
     #[error("throughput data has no nonzero values")]
     ZeroThroughput,
     #[error("failed to read team calendar yaml: {0}")]
     ReadCalendar(#[from] TeamCalendarYamlError),
-    #[error("failed to render histogram: {0}")]
-    Histogram(#[from] HistogramError),
+    #[error("half-life must be greater than zero, got {0}")]
+    InvalidHalfLife(f32),
+    #[error("arrival history is empty")]
+    EmptyArrivals,
+    #[error(
+        "{arrivals:.2} new issues per day outgrow the mean throughput of {throughput:.2}; the backlog would never be done"
+    )]
+    ScopeGrowthExceedsThroughput { arrivals: f32, throughput: f32 },
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn simulate_from_throughput_file(
     throughput_path: &str,
+    sampling: SamplingOptions,
+    scope: Option<ScopeModel>,
     iterations: usize,
     number_of_issues: usize,
     start_date: NaiveDate,
-    histogram_path: &str,
     calendar_path: Option<&str>,
-) -> Result<SimulationReport, SimulationError> {
+    seed: u64,
+    percentiles: &[f64],
+) -> Result<SimulationOutput, SimulationError> {
     let throughput_yaml = std::fs::read_to_string(throughput_path)?;
-    let throughput = deserialize_throughput_from_yaml_str(&throughput_yaml)?;
+    let history = deserialize_throughput_from_yaml_str(&throughput_yaml)?;
 
     let calendar = load_team_calendar_if_provided(calendar_path)?;
 
+    let percentiles = with_standard_percentiles(percentiles);
     let mut simulation = run_simulation(
-        &throughput,
+        &history,
+        sampling,
+        scope,
         iterations,
         number_of_issues,
         start_date,
         &calendar,
+        seed,
     )?;
+    simulation.report.percentiles =
+        days_to_percentiles(&simulation.results, &percentiles, start_date);
+    if let Some(model) = scope {
+        // The same seed keeps the difference down to the scope growth.
+        let baseline = run_simulation(
+            &history,
+            sampling,
+            None,
+            iterations,
+            number_of_issues,
+            start_date,
+            &calendar,
+            seed,
+        )?;
+        simulation.report.scope_growth = Some(ScopeGrowthForecast {
+            model,
+            percentiles_without_growth: days_to_percentiles(
+                &baseline.results,
+                &percentiles,
+                start_date,
+            ),
+        });
+    }
     simulation.report.data_source = data_source_name(throughput_path);
-    write_histogram_png(histogram_path, &simulation.results)?;
-    Ok(simulation.report)
+    Ok(simulation)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn run_simulation(
-    throughput: &[Throughput],
+    history: &ThroughputHistory,
+    sampling: SamplingOptions,
+    scope: Option<ScopeModel>,
     iterations: usize,
     number_of_issues: usize,
     start_date: NaiveDate,
     calendar: &TeamCalendar,
+    seed: u64,
 ) -> Result<SimulationOutput, SimulationError> {
-    let mut rng = rand::thread_rng();
-    run_simulation_with_rng(
-        throughput,
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut simulation = run_simulation_with_rng(
+        history,
+        sampling,
+        scope,
         iterations,
         number_of_issues,
         start_date,
         calendar,
         &mut rng,
-    )
+    )?;
+    simulation.report.seed = Some(seed);
+    Ok(simulation)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn run_simulation_with_rng<R: Rng + ?Sized>(
-    throughput: &[Throughput],
+    history: &ThroughputHistory,
+    sampling: SamplingOptions,
+    scope: Option<ScopeModel>,
     iterations: usize,
     number_of_issues: usize,
     start_date: NaiveDate,
@@ -94,20 +150,32 @@ pub(crate) fn run_simulation_with_rng<R: Rng + ?Sized>(
     if number_of_issues == 0 {
         return Err(SimulationError::InvalidIssueCount);
     }
-    if throughput.is_empty() {
-        return Err(SimulationError::EmptyThroughput);
-    }
-
-    let throughput_values: Vec<usize> = throughput.iter().map(|t| t.completed_issues).collect();
-    if throughput_values.iter().all(|value| *value == 0) {
+    // Values recorded at reduced capacity are scaled up, as the calendar
+    // applies the capacity of the simulated days.
+    let sampler = ThroughputSampler::new(history, sampling)?;
+    if sampler.rates().iter().all(|value| *value == 0.0) {
         return Err(SimulationError::ZeroThroughput);
     }
+    let arrivals_per_day = scope.map_or(0.0, |model| model.arrivals_per_day());
+    let mean_throughput = sampler.rates().iter().sum::<f32>() / sampler.rates().len() as f32;
+    if arrivals_per_day >= mean_throughput {
+        return Err(SimulationError::ScopeGrowthExceedsThroughput {
+            arrivals: arrivals_per_day,
+            throughput: mean_throughput,
+        });
+    }
 
     let mut results = Vec::with_capacity(iterations);
     for _ in 0..iterations {
+        let issue_count = match scope {
+            Some(model) => model.sample_issue_count(number_of_issues, rng),
+            None => number_of_issues as f32,
+        };
         let days = simulate_single_run(
-            &throughput_values,
-            number_of_issues,
+            &sampler,
+            history.bucket.length_days() as usize,
+            issue_count,
+            arrivals_per_day,
             start_date,
             calendar,
             rng,
@@ -116,74 +184,87 @@ pub(crate) fn run_simulation_with_rng<R: Rng + ?Sized>(
     }
     results.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
 
-    let p0_days = percentiles::get_percentile_value_f32(&results, 0.0);
-    let p15_days = percentiles::get_percentile_value_f32(&results, 15.0);
-    let p50_days = percentiles::get_percentile_value_f32(&results, 50.0);
-    let p85_days = percentiles::get_percentile_value_f32(&results, 85.0);
-    let p100_days = percentiles::get_percentile_value_f32(&results, 100.0);
-
     let report = SimulationReport {
         data_source: String::new(),
         start_date,
         velocity: None,
         iterations,
+        seed: None,
         simulated_items: number_of_issues,
-        p0: SimulationPercentile {
-            days: p0_days,
-            end_date: end_date_from_days(start_date, p0_days),
-        },
-        p15: SimulationPercentile {
-            days: p15_days,
-            end_date: end_date_from_days(start_date, p15_days),
-        },
-        p50: SimulationPercentile {
-            days: p50_days,
-            end_date: end_date_from_days(start_date, p50_days),
-        },
-        p85: SimulationPercentile {
-            days: p85_days,
-            end_date: end_date_from_days(start_date, p85_days),
-        },
-        p100: SimulationPercentile {
-            days: p100_days,
-            end_date: end_date_from_days(start_date, p100_days),
-        },
+        percentiles: days_to_percentiles(&results, &STANDARD_PERCENTILES, start_date),
         work_packages: None,
+        deadlines: None,
+        sample_window: Some(sampler.window().clone()),
+        scope_growth: None,
+        correlation: None,
+        risks: None,
+        constraints: None,
     };
 
     Ok(SimulationOutput { report, results })
 }
 
+/// Samples one value per bucket of `bucket_days` and spreads it over the
+/// days of the bucket, so the end date falls on the day the issues are done.
+/// Issues arriving meanwhile are added to `issue_count`.
+#[allow(clippy::too_many_arguments)]
 fn simulate_single_run<R: Rng + ?Sized>(
-    throughput_values: &[usize],
-    number_of_issues: usize,
+    sampler: &ThroughputSampler,
+    bucket_days: usize,
+    issue_count: f32,
+    arrivals_per_day: f32,
     start_date: NaiveDate,
     calendar: &TeamCalendar,
     rng: &mut R,
 ) -> usize {
     let mut completed = 0.0_f32;
+    let mut scope = issue_count;
     let mut days = 0;
     let mut date = start_date;
 
-    while completed < number_of_issues as f32 {
-        days += 1;
-        let sampled_throughput = throughput_values.choose(rng).copied().unwrap_or(0);
+    'buckets: while completed < scope {
+        let sampled_throughput = sampler.sample(rng);
 
-        // The calendar is responsible for setting throughput on weekends to 0.
-        // The default calendar does that, when no calendar dir is provided.
-        let capacity = calendar.get_capacity(date).max(0.0);
-        let effective_throughput = (sampled_throughput as f32) * capacity;
+        for _ in 0..bucket_days {
+            days += 1;
 
-        completed += effective_throughput;
-        if completed >= number_of_issues as f32 {
-            break;
+            // The calendar is responsible for setting throughput on weekends to 0.
+            // The default calendar does that, when no calendar dir is provided.
+            let capacity = calendar.get_capacity(date).max(0.0);
+            let effective_throughput = sampled_throughput * capacity;
+
+            completed += effective_throughput;
+            scope += arrivals_per_day * capacity;
+            if completed >= scope {
+                break 'buckets;
+            }
+            date += chrono::Duration::days(1);
         }
-        date = date + chrono::Duration::days(1);
     }
 
     days
 }
 
+fn days_to_percentiles(
+    sorted_days: &[f32],
+    percentiles: &[f64],
+    start_date: NaiveDate,
+) -> Percentiles {
+    percentiles
+        .iter()
+        .map(|&percentile| {
+            let days = percentiles::get_percentile_value_f32(sorted_days, percentile);
+            (
+                percentile,
+                SimulationPercentile {
+                    days,
+                    end_date: end_date_from_days(start_date, days),
+                },
+            )
+        })
+        .collect()
+}
+
 fn end_date_from_days(start_date: NaiveDate, days: f32) -> NaiveDate {
     let days = days.ceil().max(0.0) as usize;
     if days == 0 {
@@ -197,32 +278,53 @@ fn end_date_from_days(start_date: NaiveDate, days: f32) -> NaiveDate {
 mod tests {
     use super::*;
     use crate::domain::calendar::Calendar;
-    use crate::test_support::on_date;
+    use crate::domain::throughput::{Throughput, ThroughputBucket};
+    use crate::test_support::{create_calendar_without_any_free_days, on_date};
     use chrono::NaiveDate;
     use chrono::Weekday;
     use rand::SeedableRng;
     use rand::rngs::StdRng;
     use std::time::{SystemTime, UNIX_EPOCH};
 
+    fn history(bucket: ThroughputBucket, throughput: Vec<Throughput>) -> ThroughputHistory {
+        ThroughputHistory { bucket, throughput }
+    }
+
     #[test]
     fn run_simulation_with_rng_uses_workdays_for_dates() {
         let throughput = vec![Throughput {
             date: NaiveDate::from_ymd_opt(2026, 1, 29).unwrap(), // Thursday
             completed_issues: 1,
+            capacity: None,
         }];
         let start_date = NaiveDate::from_ymd_opt(2026, 1, 30).unwrap(); // Friday
         let mut rng = StdRng::seed_from_u64(42);
         let calendar = TeamCalendar::new();
-        let simulation =
-            run_simulation_with_rng(&throughput, 3, 2, start_date, &calendar, &mut rng).unwrap();
+        let simulation = run_simulation_with_rng(
+            &history(ThroughputBucket::Day, throughput),
+            SamplingOptions::default(),
+            None,
+            3,
+            2,
+            start_date,
+            &calendar,
+            &mut rng,
+        )
+        .unwrap();
 
         assert_eq!(simulation.results, vec![4.0, 4.0, 4.0]);
-        assert_eq!(simulation.report.p0.days, 4.0);
-        assert_eq!(simulation.report.p100.days, 4.0);
-        assert_eq!(simulation.report.p50.days, 4.0);
-        assert_eq!(simulation.report.p85.days, 4.0);
-        assert_eq!(simulation.report.p0.end_date, on_date(2026, 2, 3));
-        assert_eq!(simulation.report.p100.end_date, on_date(2026, 2, 3));
+        assert_eq!(simulation.report.percentiles.get(0.0).unwrap().days, 4.0);
+        assert_eq!(simulation.report.percentiles.get(100.0).unwrap().days, 4.0);
+        assert_eq!(simulation.report.percentiles.get(50.0).unwrap().days, 4.0);
+        assert_eq!(simulation.report.percentiles.get(85.0).unwrap().days, 4.0);
+        assert_eq!(
+            simulation.report.percentiles.get(0.0).unwrap().end_date,
+            on_date(2026, 2, 3)
+        );
+        assert_eq!(
+            simulation.report.percentiles.get(100.0).unwrap().end_date,
+            on_date(2026, 2, 3)
+        );
         assert_eq!(simulation.report.iterations, 3);
         assert_eq!(simulation.report.velocity, None);
         assert_eq!(simulation.report.data_source, "");
@@ -233,6 +335,7 @@ mod tests {
         let throughput = vec![Throughput {
             date: NaiveDate::from_ymd_opt(2026, 2, 13).unwrap(), // Friday
             completed_issues: 2,
+            capacity: None,
         }];
         let start_date = NaiveDate::from_ymd_opt(2026, 2, 16).unwrap(); // Monday
 
@@ -251,14 +354,90 @@ mod tests {
         };
 
         let mut rng = StdRng::seed_from_u64(123);
-        let simulation =
-            run_simulation_with_rng(&throughput, 1, 2, start_date, &calendar, &mut rng).unwrap();
+        let simulation = run_simulation_with_rng(
+            &history(ThroughputBucket::Day, throughput),
+            SamplingOptions::default(),
+            None,
+            1,
+            2,
+            start_date,
+            &calendar,
+            &mut rng,
+        )
+        .unwrap();
 
         // Day 1: sampled=2, capacity=0.5 => effective 1.0 (not done)
         // Day 2: Tuesday capacity=1.0 => effective 2.0 (done)
         assert_eq!(simulation.results, vec![2.0]);
-        assert_eq!(simulation.report.p50.days, 2.0);
-        assert_eq!(simulation.report.p50.end_date, on_date(2026, 2, 18));
+        assert_eq!(simulation.report.percentiles.get(50.0).unwrap().days, 2.0);
+        assert_eq!(
+            simulation.report.percentiles.get(50.0).unwrap().end_date,
+            on_date(2026, 2, 18)
+        );
+    }
+
+    #[test]
+    fn run_simulation_with_rng_scales_samples_recorded_at_reduced_capacity() {
+        let throughput = vec![
+            Throughput {
+                date: NaiveDate::from_ymd_opt(2026, 2, 13).unwrap(), // Friday
+                completed_issues: 1,
+                capacity: Some(0.5),
+            },
+            Throughput {
+                date: NaiveDate::from_ymd_opt(2026, 2, 16).unwrap(), // Holiday
+                completed_issues: 0,
+                capacity: Some(0.0),
+            },
+        ];
+        let start_date = NaiveDate::from_ymd_opt(2026, 2, 16).unwrap(); // Monday
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let simulation = run_simulation_with_rng(
+            &history(ThroughputBucket::Day, throughput),
+            SamplingOptions::default(),
+            None,
+            5,
+            4,
+            start_date,
+            &TeamCalendar::new(),
+            &mut rng,
+        )
+        .unwrap();
+
+        // One issue at half capacity is two issues per full day; the day
+        // without capacity is not sampled.
+        assert_eq!(simulation.results, vec![2.0; 5]);
+    }
+
+    #[test]
+    fn run_simulation_with_rng_spreads_weekly_samples_over_working_days() {
+        let throughput = vec![Throughput {
+            date: NaiveDate::from_ymd_opt(2026, 2, 2).unwrap(), // Monday
+            completed_issues: 10,
+            capacity: Some(5.0),
+        }];
+        let start_date = NaiveDate::from_ymd_opt(2026, 2, 16).unwrap(); // Monday
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let simulation = run_simulation_with_rng(
+            &history(ThroughputBucket::Week, throughput),
+            SamplingOptions::default(),
+            None,
+            2,
+            12,
+            start_date,
+            &TeamCalendar::new(),
+            &mut rng,
+        )
+        .unwrap();
+
+        // Ten issues in the first week, two more on the Monday after it.
+        assert_eq!(simulation.results, vec![8.0, 8.0]);
+        assert_eq!(
+            simulation.report.percentiles.get(50.0).unwrap().end_date,
+            on_date(2026, 2, 24)
+        );
     }
 
     #[test]
@@ -269,19 +448,22 @@ mod tests {
             .as_nanos();
         let dir = std::env::temp_dir();
         let input_path = dir.join(format!("throughput-{nanos}.yaml"));
-        let histogram_path = dir.join(format!("throughput-{nanos}.png"));
         let yaml = "- date: 2026-01-01\n  completed_issues: 2\n";
         std::fs::write(&input_path, yaml).unwrap();
 
         let report = simulate_from_throughput_file(
             input_path.to_str().unwrap(),
+            SamplingOptions::default(),
+            None,
             7,
             4,
             on_date(2026, 1, 1),
-            histogram_path.to_str().unwrap(),
             None,
+            5,
+            &[90.0],
         )
-        .unwrap();
+        .unwrap()
+        .report;
 
         assert_eq!(
             report.data_source,
@@ -289,5 +471,209 @@ mod tests {
         );
         assert_eq!(report.iterations, 7);
         assert_eq!(report.velocity, None);
+        assert_eq!(report.seed, Some(5));
+        assert_eq!(report.percentiles.len(), 6);
+        assert!(report.percentiles.get(90.0).is_some());
+        assert_eq!(report.sample_window.map(|window| window.samples), Some(1));
+    }
+
+    #[test]
+    fn run_simulation_with_rng_samples_only_the_window() {
+        let throughput = vec![
+            Throughput {
+                date: on_date(2026, 1, 5),
+                completed_issues: 0,
+                capacity: None,
+            },
+            Throughput {
+                date: on_date(2026, 2, 13),
+                completed_issues: 1,
+                capacity: None,
+            },
+        ];
+        let sampling = SamplingOptions {
+            window_weeks: Some(2),
+            ..SamplingOptions::default()
+        };
+        let mut rng = StdRng::seed_from_u64(5);
+
+        let simulation = run_simulation_with_rng(
+            &history(ThroughputBucket::Day, throughput),
+            sampling,
+            None,
+            10,
+            5,
+            on_date(2026, 2, 16),
+            &TeamCalendar::new(),
+            &mut rng,
+        )
+        .unwrap();
+
+        // The day without any finished issue is older than the window.
+        assert_eq!(simulation.results, vec![5.0; 10]);
+        let window = simulation.report.sample_window.unwrap();
+        assert_eq!(window.start_date, on_date(2026, 2, 13));
+        assert_eq!(window.end_date, on_date(2026, 2, 13));
+        assert_eq!(window.samples, 1);
+    }
+
+    #[test]
+    fn run_simulation_with_same_seed_is_reproducible() {
+        let throughput = vec![
+            Throughput {
+                date: on_date(2026, 1, 5),
+                completed_issues: 0,
+                capacity: None,
+            },
+            Throughput {
+                date: on_date(2026, 1, 6),
+                completed_issues: 1,
+                capacity: None,
+            },
+            Throughput {
+                date: on_date(2026, 1, 7),
+                completed_issues: 3,
+                capacity: None,
+            },
+        ];
+        let calendar = TeamCalendar::new();
+        let start_date = on_date(2026, 2, 2);
+
+        let first = run_simulation(
+            &history(ThroughputBucket::Day, throughput.clone()),
+            SamplingOptions::default(),
+            None,
+            200,
+            10,
+            start_date,
+            &calendar,
+            99,
+        )
+        .unwrap();
+        let second = run_simulation(
+            &history(ThroughputBucket::Day, throughput),
+            SamplingOptions::default(),
+            None,
+            200,
+            10,
+            start_date,
+            &calendar,
+            99,
+        )
+        .unwrap();
+
+        assert_eq!(first.results, second.results);
+        assert_eq!(first.report.seed, Some(99));
+    }
+
+    fn constant_history() -> ThroughputHistory {
+        history(
+            ThroughputBucket::Day,
+            vec![Throughput {
+                date: on_date(2026, 1, 5),
+                completed_issues: 1,
+                capacity: None,
+            }],
+        )
+    }
+
+    #[test]
+    fn run_simulation_with_rng_inflates_issues_by_split_factor() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let simulation = run_simulation_with_rng(
+            &constant_history(),
+            SamplingOptions::default(),
+            Some(ScopeModel::SplitFactor { min: 2.0, max: 2.0 }),
+            3,
+            4,
+            on_date(2026, 2, 2),
+            &create_calendar_without_any_free_days(),
+            &mut rng,
+        )
+        .unwrap();
+
+        assert_eq!(simulation.results, vec![8.0; 3]);
+    }
+
+    #[test]
+    fn run_simulation_with_rng_adds_arriving_issues() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let simulation = run_simulation_with_rng(
+            &constant_history(),
+            SamplingOptions::default(),
+            Some(ScopeModel::ArrivalRate {
+                issues_per_day: 0.5,
+            }),
+            3,
+            4,
+            on_date(2026, 2, 2),
+            &create_calendar_without_any_free_days(),
+            &mut rng,
+        )
+        .unwrap();
+
+        // One issue done and half an issue added per day: 8 days for 4 issues.
+        assert_eq!(simulation.results, vec![8.0; 3]);
+    }
+
+    #[test]
+    fn run_simulation_with_rng_rejects_arrivals_outgrowing_throughput() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let error = run_simulation_with_rng(
+            &constant_history(),
+            SamplingOptions::default(),
+            Some(ScopeModel::ArrivalRate {
+                issues_per_day: 1.0,
+            }),
+            3,
+            4,
+            on_date(2026, 2, 2),
+            &TeamCalendar::new(),
+            &mut rng,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            SimulationError::ScopeGrowthExceedsThroughput { .. }
+        ));
+    }
+
+    #[test]
+    fn simulate_from_throughput_file_reports_forecast_without_growth() {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let input_path = std::env::temp_dir().join(format!("scope-{nanos}.yaml"));
+        std::fs::write(&input_path, "- date: 2026-01-05\n  completed_issues: 1\n").unwrap();
+
+        let report = simulate_from_throughput_file(
+            input_path.to_str().unwrap(),
+            SamplingOptions::default(),
+            Some(ScopeModel::SplitFactor { min: 1.5, max: 1.5 }),
+            5,
+            4,
+            on_date(2026, 2, 2), // Monday
+            None,
+            1,
+            &[],
+        )
+        .unwrap()
+        .report;
+
+        let scope_growth = report.scope_growth.unwrap();
+        assert_eq!(report.percentiles.get(50.0).unwrap().days, 8.0);
+        assert_eq!(
+            scope_growth
+                .percentiles_without_growth
+                .get(50.0)
+                .unwrap()
+                .days,
+            4.0
+        );
     }
 }
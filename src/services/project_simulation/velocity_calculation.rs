@@ -66,7 +66,7 @@ pub fn calculate_project_velocity(
         .filter_map(|issue| issue.story_point_value())
         .sum();
 
-    let velocity = total_points / summed_capacity as f32;
+    let velocity = total_points / summed_capacity;
     if velocity <= 0.0 {
         return Err(VelocityCalculationError::InvalidVelocityValue);
     }
@@ -109,6 +109,7 @@ mod tests {
         let project = Project {
             name: "Demo".to_string(),
             work_packages: issues,
+            risks: Vec::new(),
         };
         let no_free_days_calendar = create_calendar_without_any_free_days();
 
@@ -131,6 +132,7 @@ mod tests {
         let project = Project {
             name: "Demo".to_string(),
             work_packages: issues,
+            risks: Vec::new(),
         };
 
         let no_free_days_calendar = create_calendar_without_any_free_days();
@@ -176,6 +178,7 @@ mod tests {
         let project = Project {
             name: "Demo".to_string(),
             work_packages: issues,
+            risks: Vec::new(),
         };
 
         let velocity = calculate_project_velocity(&project, &half_capacity_calendar)
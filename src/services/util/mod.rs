@@ -1,3 +1,12 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, LINK, RETRY_AFTER};
+
+/// Longest wait between two attempts, even if the server asks for more.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(120);
+/// Number of characters of a response body kept in error messages.
+const MAX_ERROR_BODY_CHARS: usize = 500;
+
 /// This function extracts the file name from a given path.
 /// If the path does not contain a valid file name, it returns the original path as a string.
 pub fn data_source_name(path: &str) -> String {
@@ -7,3 +16,113 @@ pub fn data_source_name(path: &str) -> String {
         .unwrap_or(path)
         .to_string()
 }
+
+/// Splits a query like `state=closed&labels=team-a` into key/value pairs.
+pub fn parse_query_pairs(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+/// Returns the URL marked `rel="next"` in a `Link` response header, as sent
+/// by paginated REST APIs like GitHub and GitLab.
+pub fn next_page_link(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let (target, relation) = part.split_once(';')?;
+        if relation.trim() != "rel=\"next\"" {
+            return None;
+        }
+        Some(
+            target
+                .trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string(),
+        )
+    })
+}
+
+/// Delay before retry number `attempt` (starting at 0): the `Retry-After`
+/// seconds if the server sent them, otherwise `base` doubled per attempt.
+pub fn retry_delay(headers: &HeaderMap, attempt: u32, base: Duration) -> Duration {
+    let retry_after = headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs);
+    let backoff = base.saturating_mul(2u32.saturating_pow(attempt));
+    retry_after.unwrap_or(backoff).min(MAX_RETRY_DELAY)
+}
+
+/// Shortens a response body for error messages.
+pub fn truncate_body(body: &str) -> String {
+    let body = body.trim();
+    match body.char_indices().nth(MAX_ERROR_BODY_CHARS) {
+        Some((end, _)) => format!("{}...", &body[..end]),
+        None => body.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn retry_delay_prefers_retry_after_header() {
+        let base = Duration::from_millis(100);
+        let mut headers = HeaderMap::new();
+
+        assert_eq!(retry_delay(&headers, 0, base), Duration::from_millis(100));
+        assert_eq!(retry_delay(&headers, 3, base), Duration::from_millis(800));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_delay(&headers, 3, base), Duration::from_secs(7));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("86400"));
+        assert_eq!(retry_delay(&headers, 0, base), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn truncate_body_keeps_short_bodies() {
+        assert_eq!(truncate_body("  busy \n"), "busy");
+
+        let long = "ä".repeat(MAX_ERROR_BODY_CHARS + 10);
+        let truncated = truncate_body(&long);
+        assert_eq!(truncated.chars().count(), MAX_ERROR_BODY_CHARS + 3);
+        assert!(truncated.ends_with("..."));
+    }
+
+    #[test]
+    fn parse_query_pairs_splits_pairs() {
+        assert_eq!(
+            parse_query_pairs("state=closed&labels=team-a,bug"),
+            vec![
+                ("state".to_string(), "closed".to_string()),
+                ("labels".to_string(), "team-a,bug".to_string()),
+            ]
+        );
+        assert!(parse_query_pairs("").is_empty());
+    }
+
+    #[test]
+    fn next_page_link_reads_link_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            "<http://host/issues?page=2>; rel=\"next\", <http://host/issues?page=5>; rel=\"last\""
+                .parse()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            next_page_link(&headers),
+            Some("http://host/issues?page=2".to_string())
+        );
+        assert_eq!(next_page_link(&HeaderMap::new()), None);
+    }
+}
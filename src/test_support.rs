@@ -63,7 +63,7 @@ pub fn build_done_issue_with_deps(
 }
 
 pub fn build_constant_three_point_issue(id: &str, days: f32, deps: &[&str]) -> Issue {
-    return build_three_point_issue(id, days, days, days, deps);
+    build_three_point_issue(id, days, days, days, deps)
 }
 
 pub fn build_three_point_issue(
@@ -79,6 +79,7 @@ pub fn build_three_point_issue(
         optimistic: Some(optimistic),
         most_likely: Some(most_likely),
         pessimistic: Some(pessimistic),
+        distribution: None,
     }));
     issue.dependencies = if deps.is_empty() {
         None
@@ -0,0 +1,167 @@
+use assert_fs::prelude::*;
+use predicates::prelude::*;
+use std::fs;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::task;
+use warp::Filter;
+
+#[tokio::test]
+async fn get_throughput_replays_cached_issues_offline_and_refreshes_on_demand() {
+    let issues_response = serde_json::json!({
+        "issues": [
+            {
+                "fields": {
+                    "actualEndDate": "2026-01-26T08:42:00.000+0100",
+                    "statusCategory": { "name": "Done" },
+                    "summary": "A task"
+                },
+                "key": "ABC-1"
+            }
+        ],
+        "isLast": true
+    });
+    let calls = Arc::new(AtomicUsize::new(0));
+    let route_calls = calls.clone();
+    let issues_route = warp::path!("search" / "jql").and(warp::get()).map(move || {
+        route_calls.fetch_add(1, Ordering::SeqCst);
+        warp::reply::json(&issues_response)
+    });
+    let (addr, server) = warp::serve(issues_route).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let dir = assert_fs::TempDir::new().unwrap();
+    let config_file = dir.child("jira.yaml");
+    config_file
+        .write_str(&format!(
+            "base_url: http://{addr}\nproject_key: MOCK\nthroughput_query: project = TEST\nactual_end_date_field_id: actualEndDate\n"
+        ))
+        .unwrap();
+    let cache_dir = dir.child("cache");
+
+    let online = run_get_throughput(&config_file, &cache_dir, &[]).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    cache_dir.assert(predicate::path::is_dir());
+
+    let cached = run_get_throughput(&config_file, &cache_dir, &[]).await;
+    let offline = run_get_throughput(&config_file, &cache_dir, &["--offline"]).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(cached, online);
+    assert_eq!(offline, online);
+
+    run_get_throughput(&config_file, &cache_dir, &["--refresh"]).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn get_throughput_offline_fails_without_cached_issues() {
+    let dir = assert_fs::TempDir::new().unwrap();
+    let config_file = dir.child("jira.yaml");
+    config_file
+        .write_str(
+            "base_url: http://127.0.0.1:9\nproject_key: MOCK\nthroughput_query: project = TEST\n",
+        )
+        .unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "get",
+        "throughput",
+        "-c",
+        config_file.path().to_str().unwrap(),
+        "-o",
+        dir.child("out.yaml").path().to_str().unwrap(),
+        "--cache-dir",
+        dir.child("cache").path().to_str().unwrap(),
+        "--offline",
+    ])
+    .env_remove("JIRA_USERNAME")
+    .env_remove("JIRA_API_TOKEN");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "no cached data for query 'project = TEST'",
+    ));
+}
+
+#[test]
+fn get_throughput_from_csv_does_not_replay_issues_of_an_edited_file() {
+    let dir = assert_fs::TempDir::new().unwrap();
+    let csv_file = dir.child("issues.csv");
+    csv_file
+        .write_str("Key,State,Resolved\nA-1,Done,2026-01-05\n")
+        .unwrap();
+    let config_file = dir.child("csv.yaml");
+    config_file
+        .write_str(
+            "file_path: issues.csv\nthroughput_query: State=Done\ncolumns:\n  id: Key\n  status: State\n  done_date: Resolved\n",
+        )
+        .unwrap();
+    let output_file = dir.child("throughput.yaml");
+
+    let run = || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args([
+            "get",
+            "throughput",
+            "-c",
+            config_file.path().to_str().unwrap(),
+            "-o",
+            output_file.path().to_str().unwrap(),
+            "--source",
+            "csv",
+            "--cache-dir",
+            dir.child("cache").path().to_str().unwrap(),
+        ]);
+        cmd.assert().success();
+        fs::read_to_string(output_file.path()).unwrap()
+    };
+
+    assert!(run().contains("date: 2026-01-05\n  completed_issues: 1"));
+    csv_file
+        .write_str("Key,State,Resolved\nA-1,Done,2026-01-05\nA-2,Done,2026-01-05\n")
+        .unwrap();
+    assert!(run().contains("date: 2026-01-05\n  completed_issues: 2"));
+}
+
+async fn run_get_throughput(
+    config_file: &assert_fs::fixture::ChildPath,
+    cache_dir: &assert_fs::fixture::ChildPath,
+    extra_args: &[&str],
+) -> String {
+    let output_file = assert_fs::NamedTempFile::new("throughput.yaml").unwrap();
+    let mut args: Vec<String> = [
+        "get",
+        "throughput",
+        "-c",
+        config_file.path().to_str().unwrap(),
+        "-o",
+        output_file.path().to_str().unwrap(),
+        "--cache-dir",
+        cache_dir.path().to_str().unwrap(),
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    args.extend(extra_args.iter().map(|arg| arg.to_string()));
+    let offline = extra_args.contains(&"--offline");
+
+    task::spawn_blocking(move || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args(&args);
+        if offline {
+            // Offline runs must not need credentials.
+            cmd.env_remove("JIRA_USERNAME").env_remove("JIRA_API_TOKEN");
+        } else {
+            cmd.env("JIRA_USERNAME", "mockuser")
+                .env("JIRA_API_TOKEN", "mocktoken");
+        }
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Throughput data written to"));
+    })
+    .await
+    .unwrap();
+
+    fs::read_to_string(output_file.path()).unwrap()
+}
@@ -0,0 +1,71 @@
+use assert_fs::prelude::*;
+use predicates::prelude::*;
+use std::fs;
+
+const ISSUES_CSV: &str = "\
+Key,Title,State,Started,Resolved,Points,Blocked By,Team
+A-1,Design,Done,2026-01-05,2026-01-07,2,,Payments
+A-2,Build,In Progress,2026-01-08,,3,A-1,Payments
+A-3,Ship,To Do,,,1,A-1;A-2,Payments
+B-1,Other team,Done,2026-01-05,2026-01-06,5,,Search
+";
+
+const CONFIG_YAML: &str = r#"
+file_path: issues.csv
+project_name: Payments
+throughput_query: Team=Payments&State=Done
+project_query: Team=Payments
+columns:
+  id: Key
+  summary: Title
+  status: State
+  start_date: Started
+  done_date: Resolved
+  estimate: Points
+  dependencies: Blocked By
+"#;
+
+#[test]
+fn get_throughput_from_csv_counts_filtered_done_rows() {
+    let output = run_get("throughput");
+
+    assert!(output.contains("date: 2026-01-07\n  completed_issues: 1"));
+    assert!(!output.contains("date: 2026-01-06\n  completed_issues: 1"));
+}
+
+#[test]
+fn get_project_from_csv_maps_columns() {
+    let output = run_get("project");
+
+    assert!(output.contains("name: Payments"));
+    assert!(output.contains("id: A-3"));
+    assert!(output.contains("done_date: 2026-01-07"));
+    assert!(output.contains("- A-2"));
+    assert!(!output.contains("B-1"));
+}
+
+fn run_get(command: &str) -> String {
+    let dir = assert_fs::TempDir::new().unwrap();
+    dir.child("issues.csv").write_str(ISSUES_CSV).unwrap();
+    let config_file = dir.child("csv_config.yaml");
+    config_file.write_str(CONFIG_YAML).unwrap();
+    let output_file = dir.child("output.yaml");
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "get",
+        command,
+        "-c",
+        config_file.path().to_str().unwrap(),
+        "-o",
+        output_file.path().to_str().unwrap(),
+        "--source",
+        "csv",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("data written to"));
+
+    fs::read_to_string(output_file.path()).unwrap()
+}
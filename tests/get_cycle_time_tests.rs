@@ -0,0 +1,332 @@
+use assert_fs::prelude::*;
+use predicates::prelude::*;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use tokio::task;
+use warp::Filter;
+
+#[tokio::test]
+async fn get_cycle_time_from_jira_changelog() {
+    let issues_response = serde_json::json!({
+        "issues": [
+            {
+                "key": "ABC-1",
+                "fields": {
+                    "summary": "Reopened task",
+                    "statusCategory": { "name": "Done" }
+                },
+                "changelog": {
+                    "histories": [
+                        {
+                            "created": "2026-01-05T09:00:00.000+0100",
+                            "items": [{ "field": "status", "fromString": "Open", "toString": "In Work" }]
+                        },
+                        {
+                            "created": "2026-01-06T09:00:00.000+0100",
+                            "items": [{ "field": "status", "fromString": "In Work", "toString": "Closed" }]
+                        },
+                        {
+                            "created": "2026-01-07T09:00:00.000+0100",
+                            "items": [{ "field": "status", "fromString": "Closed", "toString": "In Work" }]
+                        },
+                        {
+                            "created": "2026-01-09T09:00:00.000+0100",
+                            "items": [{ "field": "status", "fromString": "In Work", "toString": "Closed" }]
+                        }
+                    ]
+                }
+            },
+            {
+                "key": "ABC-2",
+                "fields": {
+                    "summary": "Never started",
+                    "statusCategory": { "name": "Done" }
+                },
+                "changelog": {
+                    "histories": [
+                        {
+                            "created": "2026-01-08T09:00:00.000+0100",
+                            "items": [{ "field": "status", "fromString": "Open", "toString": "Closed" }]
+                        }
+                    ]
+                }
+            }
+        ]
+    });
+    let statuses_response = serde_json::json!([
+        { "name": "Open", "statusCategory": { "key": "new" } },
+        { "name": "In Work", "statusCategory": { "key": "indeterminate" } },
+        { "name": "Closed", "statusCategory": { "key": "done" } }
+    ]);
+
+    let issues_route = warp::path!("search" / "jql")
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .map(move |query: HashMap<String, String>| {
+            assert_eq!(query.get("expand").map(String::as_str), Some("changelog"));
+            warp::reply::json(&issues_response)
+        });
+    let status_route = warp::path!("status")
+        .and(warp::get())
+        .map(move || warp::reply::json(&statuses_response));
+    let (addr, server) =
+        warp::serve(issues_route.or(status_route)).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let config_yaml = format!(
+        r#"
+base_url: http://{addr}
+project_key: MOCK
+throughput_query: project = TEST
+use_changelog: true
+"#
+    );
+    let config_file = assert_fs::NamedTempFile::new("test_cycle_time_config.yaml").unwrap();
+    config_file.write_str(&config_yaml).unwrap();
+
+    unsafe {
+        env::set_var("JIRA_USERNAME", "mockuser");
+        env::set_var("JIRA_API_TOKEN", "mocktoken");
+    }
+
+    let output_file = assert_fs::NamedTempFile::new("test_cycle_time.yaml").unwrap();
+    let config_arg = config_file.path().to_str().unwrap().to_string();
+    let output_arg = output_file.path().to_str().unwrap().to_string();
+    task::spawn_blocking(move || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args(["get", "cycle-time", "-c", &config_arg, "-o", &output_arg]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Cycle time data written to"));
+    })
+    .await
+    .unwrap();
+
+    let output = fs::read_to_string(output_file.path()).unwrap();
+    assert_eq!(
+        output,
+        "- id: ABC-1\n  start_date: 2026-01-05\n  done_date: 2026-01-09\n  cycle_time_days: 5\n"
+    );
+}
+
+#[tokio::test]
+async fn get_cycle_time_pages_through_truncated_jira_changelogs() {
+    // The search embeds only the first of three histories.
+    let issues_response = serde_json::json!({
+        "issues": [
+            {
+                "key": "ABC-1",
+                "fields": {
+                    "summary": "Long discussion",
+                    "statusCategory": { "name": "Done" }
+                },
+                "changelog": {
+                    "startAt": 0,
+                    "maxResults": 1,
+                    "total": 3,
+                    "histories": [
+                        {
+                            "created": "2026-01-05T09:00:00.000+0100",
+                            "items": [{ "field": "status", "fromString": "Open", "toString": "In Work" }]
+                        }
+                    ]
+                }
+            }
+        ]
+    });
+    let histories = serde_json::json!([
+        {
+            "created": "2026-01-05T09:00:00.000+0100",
+            "items": [{ "field": "status", "fromString": "Open", "toString": "In Work" }]
+        },
+        {
+            "created": "2026-01-06T09:00:00.000+0100",
+            "items": [{ "field": "assignee", "fromString": null, "toString": "Ann" }]
+        },
+        {
+            "created": "2026-01-08T09:00:00.000+0100",
+            "items": [{ "field": "status", "fromString": "In Work", "toString": "Closed" }]
+        }
+    ]);
+    let statuses_response = serde_json::json!([
+        { "name": "Open", "statusCategory": { "key": "new" } },
+        { "name": "In Work", "statusCategory": { "key": "indeterminate" } },
+        { "name": "Closed", "statusCategory": { "key": "done" } }
+    ]);
+
+    let issues_route = warp::path!("search" / "jql")
+        .and(warp::get())
+        .map(move || warp::reply::json(&issues_response));
+    // Two histories per page.
+    let changelog_route = warp::path!("issue" / "ABC-1" / "changelog")
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .map(move |query: HashMap<String, String>| {
+            let start_at: usize = query.get("startAt").unwrap().parse().unwrap();
+            let values = histories.as_array().unwrap();
+            let end = (start_at + 2).min(values.len());
+            warp::reply::json(&serde_json::json!({
+                "startAt": start_at,
+                "maxResults": 2,
+                "total": values.len(),
+                "isLast": end == values.len(),
+                "values": values[start_at..end]
+            }))
+        });
+    let status_route = warp::path!("status")
+        .and(warp::get())
+        .map(move || warp::reply::json(&statuses_response));
+    let (addr, server) = warp::serve(issues_route.or(changelog_route).or(status_route))
+        .bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let config_yaml = format!(
+        r#"
+base_url: http://{addr}
+project_key: MOCK
+throughput_query: project = TEST
+use_changelog: true
+"#
+    );
+    let config_file = assert_fs::NamedTempFile::new("test_cycle_time_config.yaml").unwrap();
+    config_file.write_str(&config_yaml).unwrap();
+
+    unsafe {
+        env::set_var("JIRA_USERNAME", "mockuser");
+        env::set_var("JIRA_API_TOKEN", "mocktoken");
+    }
+
+    let output_file = assert_fs::NamedTempFile::new("test_cycle_time.yaml").unwrap();
+    let config_arg = config_file.path().to_str().unwrap().to_string();
+    let output_arg = output_file.path().to_str().unwrap().to_string();
+    task::spawn_blocking(move || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args(["get", "cycle-time", "-c", &config_arg, "-o", &output_arg]);
+
+        cmd.assert().success();
+    })
+    .await
+    .unwrap();
+
+    let output = fs::read_to_string(output_file.path()).unwrap();
+    assert_eq!(
+        output,
+        "- id: ABC-1\n  start_date: 2026-01-05\n  done_date: 2026-01-08\n  cycle_time_days: 4\n"
+    );
+}
+
+#[tokio::test]
+async fn get_cycle_time_pages_through_truncated_jira_server_changelogs() {
+    // The search embeds only the first of three histories.
+    let issues_response = serde_json::json!({
+        "startAt": 0,
+        "maxResults": 50,
+        "total": 1,
+        "issues": [
+            {
+                "key": "ABC-1",
+                "fields": {
+                    "summary": "Long discussion",
+                    "statusCategory": { "name": "Done" }
+                },
+                "changelog": {
+                    "startAt": 0,
+                    "maxResults": 1,
+                    "total": 3,
+                    "histories": [
+                        {
+                            "created": "2026-01-05T09:00:00.000+0100",
+                            "items": [{ "field": "status", "fromString": "Open", "toString": "In Work" }]
+                        }
+                    ]
+                }
+            }
+        ]
+    });
+    let histories = serde_json::json!([
+        {
+            "created": "2026-01-05T09:00:00.000+0100",
+            "items": [{ "field": "status", "fromString": "Open", "toString": "In Work" }]
+        },
+        {
+            "created": "2026-01-06T09:00:00.000+0100",
+            "items": [{ "field": "assignee", "fromString": null, "toString": "Ann" }]
+        },
+        {
+            "created": "2026-01-08T09:00:00.000+0100",
+            "items": [{ "field": "status", "fromString": "In Work", "toString": "Closed" }]
+        }
+    ]);
+    let statuses_response = serde_json::json!([
+        { "name": "Open", "statusCategory": { "key": "new" } },
+        { "name": "In Work", "statusCategory": { "key": "indeterminate" } },
+        { "name": "Closed", "statusCategory": { "key": "done" } }
+    ]);
+
+    let issues_route = warp::path!("search")
+        .and(warp::get())
+        .map(move || warp::reply::json(&issues_response));
+    // Jira Server has no changelog resource; the expanded changelog of the
+    // issue is paged with two histories per page.
+    let issue_route = warp::path!("issue" / "ABC-1")
+        .and(warp::get())
+        .and(warp::query::<HashMap<String, String>>())
+        .map(move |query: HashMap<String, String>| {
+            assert_eq!(query.get("expand").map(String::as_str), Some("changelog"));
+            let start_at: usize = query.get("startAt").unwrap().parse().unwrap();
+            let values = histories.as_array().unwrap();
+            let end = (start_at + 2).min(values.len());
+            warp::reply::json(&serde_json::json!({
+                "key": "ABC-1",
+                "changelog": {
+                    "startAt": start_at,
+                    "maxResults": 2,
+                    "total": values.len(),
+                    "histories": values[start_at..end]
+                }
+            }))
+        });
+    let status_route = warp::path!("status")
+        .and(warp::get())
+        .map(move || warp::reply::json(&statuses_response));
+    let (addr, server) = warp::serve(issues_route.or(issue_route).or(status_route))
+        .bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let config_yaml = format!(
+        r#"
+base_url: http://{addr}
+project_key: MOCK
+throughput_query: project = TEST
+api_flavor: server
+use_changelog: true
+"#
+    );
+    let config_file = assert_fs::NamedTempFile::new("test_cycle_time_config.yaml").unwrap();
+    config_file.write_str(&config_yaml).unwrap();
+
+    unsafe {
+        env::set_var("JIRA_USERNAME", "mockuser");
+        env::set_var("JIRA_API_TOKEN", "mocktoken");
+    }
+
+    let output_file = assert_fs::NamedTempFile::new("test_cycle_time.yaml").unwrap();
+    let config_arg = config_file.path().to_str().unwrap().to_string();
+    let output_arg = output_file.path().to_str().unwrap().to_string();
+    task::spawn_blocking(move || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args(["get", "cycle-time", "-c", &config_arg, "-o", &output_arg]);
+
+        cmd.assert().success();
+    })
+    .await
+    .unwrap();
+
+    let output = fs::read_to_string(output_file.path()).unwrap();
+    assert_eq!(
+        output,
+        "- id: ABC-1\n  start_date: 2026-01-05\n  done_date: 2026-01-08\n  cycle_time_days: 4\n"
+    );
+}
@@ -0,0 +1,224 @@
+use assert_fs::prelude::*;
+use predicates::prelude::*;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use tokio::task;
+use warp::Filter;
+
+fn issues_route(
+    page1: serde_json::Value,
+    page2: serde_json::Value,
+) -> impl Filter<Extract = (Box<dyn warp::Reply>,), Error = warp::Rejection> + Clone {
+    warp::path!("repos" / "octo" / "demo" / "issues")
+        .and(warp::get())
+        .and(warp::host::optional())
+        .and(warp::query::<HashMap<String, String>>())
+        .map(
+            move |host: Option<warp::host::Authority>,
+                  query: HashMap<String, String>|
+                  -> Box<dyn warp::Reply> {
+                if query.get("page").map(|value| value.as_str()) == Some("2") {
+                    return Box::new(warp::reply::json(&page2));
+                }
+                let next = format!(
+                    "<http://{}/repos/octo/demo/issues?page=2&per_page=100>; rel=\"next\"",
+                    host.unwrap()
+                );
+                Box::new(warp::reply::with_header(
+                    warp::reply::json(&page1),
+                    "link",
+                    next,
+                ))
+            },
+        )
+}
+
+#[tokio::test]
+async fn get_throughput_from_github_follows_pagination() {
+    let page1 = serde_json::json!([
+        {
+            "number": 1,
+            "title": "First",
+            "state": "closed",
+            "created_at": "2026-01-12T10:13:04Z",
+            "closed_at": "2026-01-26T08:42:00Z",
+            "labels": []
+        },
+        {
+            "number": 2,
+            "title": "A pull request",
+            "state": "closed",
+            "created_at": "2026-01-12T10:13:04Z",
+            "closed_at": "2026-01-27T08:42:00Z",
+            "labels": [],
+            "pull_request": {}
+        }
+    ]);
+    let page2 = serde_json::json!([
+        {
+            "number": 3,
+            "title": "Second",
+            "state": "closed",
+            "created_at": "2026-01-11T10:13:04Z",
+            "closed_at": "2026-01-28T08:42:00Z",
+            "labels": []
+        }
+    ]);
+
+    let (addr, server) =
+        warp::serve(issues_route(page1, page2)).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let output = run_get(addr, "throughput").await;
+
+    assert!(output.contains("date: 2026-01-26\n  completed_issues: 1"));
+    assert!(output.contains("date: 2026-01-27\n  completed_issues: 0"));
+    assert!(output.contains("date: 2026-01-28\n  completed_issues: 1"));
+}
+
+#[tokio::test]
+async fn get_project_from_github_maps_labels_and_blockers() {
+    let page1 = serde_json::json!([
+        {
+            "number": 7,
+            "title": "Build the API",
+            "body": "Blocked by #5",
+            "state": "open",
+            "created_at": "2026-01-12T10:13:04Z",
+            "closed_at": null,
+            "labels": [{ "name": "sp: 3" }, { "name": "in progress" }]
+        }
+    ]);
+    let page2 = serde_json::json!([
+        {
+            "number": 5,
+            "title": "Design the API",
+            "body": null,
+            "state": "closed",
+            "created_at": "2026-01-10T10:13:04Z",
+            "closed_at": "2026-01-20T08:42:00Z",
+            "labels": [{ "name": "sp: 2" }]
+        }
+    ]);
+
+    let events_route = warp::path!("repos" / "octo" / "demo" / "issues" / u64 / "events")
+        .and(warp::get())
+        .map(|number: u64| {
+            let events = match number {
+                7 => serde_json::json!([
+                    { "event": "labeled", "label": { "name": "sp: 3" }, "created_at": "2026-01-12T10:13:04Z" },
+                    { "event": "labeled", "label": { "name": "in progress" }, "created_at": "2026-01-21T09:00:00Z" }
+                ]),
+                _ => serde_json::json!([]),
+            };
+            warp::reply::json(&events)
+        });
+
+    let (addr, server) = warp::serve(events_route.or(issues_route(page1, page2)))
+        .bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let output = run_get(addr, "project").await;
+
+    assert!(output.contains("name: demo"));
+    assert!(output.contains("id: '#5'"));
+    assert!(output.contains("id: '#7'"));
+    assert!(output.contains("status: InProgress"));
+    assert!(output.contains("start_date: 2026-01-21"));
+    assert!(output.contains("done_date: 2026-01-20"));
+    assert!(output.contains("value: 3"));
+    // The done issue is sorted first.
+    assert!(output.find("id: '#5'").unwrap() < output.find("id: '#7'").unwrap());
+}
+
+#[tokio::test]
+async fn get_throughput_from_github_reports_status_url_and_body() {
+    let route = warp::path!("repos" / "octo" / "demo" / "issues")
+        .and(warp::get())
+        .map(|| {
+            warp::reply::with_status("API rate limit exceeded", warp::http::StatusCode::FORBIDDEN)
+        });
+    let (addr, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let config_file = assert_fs::NamedTempFile::new("test_github_config.yaml").unwrap();
+    config_file
+        .write_str(&format!(
+            "base_url: http://{addr}\nowner: octo\nrepository: demo\nthroughput_query: state=closed\n"
+        ))
+        .unwrap();
+    let output_file = assert_fs::NamedTempFile::new("test_output.yaml").unwrap();
+
+    let config_arg = config_file.path().to_str().unwrap().to_string();
+    let output_arg = output_file.path().to_str().unwrap().to_string();
+    task::spawn_blocking(move || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args([
+            "get",
+            "throughput",
+            "-c",
+            &config_arg,
+            "-o",
+            &output_arg,
+            "--source",
+            "github",
+        ])
+        .env("GITHUB_TOKEN", "mocktoken");
+
+        cmd.assert().failure().stderr(
+            predicate::str::contains("HTTP 403")
+                .and(predicate::str::contains("/repos/octo/demo/issues"))
+                .and(predicate::str::contains("API rate limit exceeded")),
+        );
+    })
+    .await
+    .unwrap();
+}
+
+async fn run_get(socket_addr: std::net::SocketAddr, command: &'static str) -> String {
+    let config_yaml = format!(
+        r#"
+base_url: http://{socket_addr}
+owner: octo
+repository: demo
+throughput_query: state=closed
+project_query: state=all
+story_points_label_pattern: "sp: {{}}"
+in_progress_label: in progress
+"#
+    );
+
+    let config_file = assert_fs::NamedTempFile::new("test_github_config.yaml").unwrap();
+    config_file.write_str(&config_yaml).unwrap();
+
+    unsafe {
+        env::set_var("GITHUB_TOKEN", "mocktoken");
+    }
+
+    let output_file = assert_fs::NamedTempFile::new("test_output.yaml").unwrap();
+
+    let config_arg = config_file.path().to_str().unwrap().to_string();
+    let output_arg = output_file.path().to_str().unwrap().to_string();
+    task::spawn_blocking(move || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args([
+            "get",
+            command,
+            "-c",
+            &config_arg,
+            "-o",
+            &output_arg,
+            "--source",
+            "github",
+        ]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("data written to"));
+    })
+    .await
+    .unwrap();
+
+    fs::read_to_string(output_file.path()).unwrap()
+}
@@ -0,0 +1,182 @@
+use assert_fs::prelude::*;
+use predicates::prelude::*;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use tokio::task;
+use warp::Filter;
+
+#[tokio::test]
+async fn get_throughput_from_gitlab_merge_requests_follows_keyset_pagination() {
+    let page1 = serde_json::json!([
+        { "iid": 1, "title": "First", "state": "merged", "merged_at": "2026-01-26T08:42:00.000Z", "labels": [] }
+    ]);
+    let page2 = serde_json::json!([
+        { "iid": 2, "title": "Second", "state": "merged", "merged_at": "2026-01-28T08:42:00.000Z", "labels": [] }
+    ]);
+
+    let route = warp::path!("projects" / "42" / "merge_requests")
+        .and(warp::get())
+        .and(warp::host::optional())
+        .and(warp::query::<HashMap<String, String>>())
+        .map(
+            move |host: Option<warp::host::Authority>,
+                  query: HashMap<String, String>|
+                  -> Box<dyn warp::Reply> {
+                assert_eq!(query.get("state").map(String::as_str), Some("merged"));
+                if query.get("id_after").map(String::as_str) == Some("1") {
+                    return Box::new(warp::reply::json(&page2));
+                }
+                assert_eq!(query.get("pagination").map(String::as_str), Some("keyset"));
+                let next = format!(
+                    "<http://{}/projects/42/merge_requests?state=merged&id_after=1>; rel=\"next\"",
+                    host.unwrap()
+                );
+                Box::new(warp::reply::with_header(
+                    warp::reply::json(&page1),
+                    "link",
+                    next,
+                ))
+            },
+        );
+
+    let (addr, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let output = run_get(addr, "throughput", "merge_requests?state=merged").await;
+
+    assert!(output.contains("date: 2026-01-26\n  completed_issues: 1"));
+    assert!(output.contains("date: 2026-01-27\n  completed_issues: 0"));
+    assert!(output.contains("date: 2026-01-28\n  completed_issues: 1"));
+}
+
+#[tokio::test]
+async fn get_project_from_gitlab_maps_weights_milestones_and_links() {
+    let issues = serde_json::json!([
+        {
+            "iid": 5,
+            "title": "Design",
+            "state": "closed",
+            "weight": 2,
+            "created_at": "2026-01-10T10:00:00.000Z",
+            "closed_at": "2026-01-20T10:00:00.000Z",
+            "labels": [],
+            "milestone": { "title": "Release 1" }
+        },
+        {
+            "iid": 6,
+            "title": "Build",
+            "state": "opened",
+            "weight": 3,
+            "created_at": "2026-01-11T10:00:00.000Z",
+            "labels": [],
+            "milestone": { "title": "Release 1" }
+        },
+        {
+            "iid": 7,
+            "title": "Ship",
+            "state": "opened",
+            "weight": 1,
+            "created_at": "2026-01-12T10:00:00.000Z",
+            "labels": []
+        }
+    ]);
+
+    let issues_route = warp::path!("projects" / "42" / "issues")
+        .and(warp::get())
+        .map(move || warp::reply::json(&issues));
+    let links_route = warp::path!("projects" / "42" / "issues" / u64 / "links")
+        .and(warp::get())
+        .map(|iid: u64| {
+            let links = match iid {
+                // 7 is blocked by 6, announced from both sides.
+                6 => serde_json::json!([
+                    { "iid": 7, "link_type": "blocks", "references": { "relative": "#7" } }
+                ]),
+                7 => serde_json::json!([
+                    { "iid": 6, "link_type": "is_blocked_by", "references": { "relative": "#6" } }
+                ]),
+                _ => serde_json::json!([]),
+            };
+            warp::reply::json(&links)
+        });
+
+    let (addr, server) =
+        warp::serve(issues_route.or(links_route)).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let output = run_get(addr, "project", "merge_requests?state=merged").await;
+
+    assert!(output.contains("name: '42'"));
+    assert!(output.contains("subgraph: Release 1"));
+    assert!(output.contains("value: 3"));
+    assert!(output.contains("done_date: 2026-01-20"));
+    assert_eq!(output.matches("- '#6'").count(), 1);
+}
+
+#[tokio::test]
+async fn get_throughput_from_gitlab_issues_does_not_read_issue_links() {
+    let issues = serde_json::json!([
+        { "iid": 3, "title": "Fix", "state": "closed", "closed_at": "2026-01-27T08:42:00.000Z", "labels": [] }
+    ]);
+
+    // Without a links route, any links request fails the command.
+    let route = warp::path!("projects" / "42" / "issues")
+        .and(warp::get())
+        .map(move || warp::reply::json(&issues));
+
+    let (addr, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let output = run_get(addr, "throughput", "state=closed").await;
+
+    assert!(output.contains("date: 2026-01-27\n  completed_issues: 1"));
+}
+
+async fn run_get(
+    socket_addr: std::net::SocketAddr,
+    command: &'static str,
+    throughput_query: &str,
+) -> String {
+    let config_yaml = format!(
+        r#"
+base_url: http://{socket_addr}
+project: "42"
+throughput_query: {throughput_query}
+project_query: state=all
+"#
+    );
+
+    let config_file = assert_fs::NamedTempFile::new("test_gitlab_config.yaml").unwrap();
+    config_file.write_str(&config_yaml).unwrap();
+
+    unsafe {
+        env::set_var("GITLAB_TOKEN", "mocktoken");
+    }
+
+    let output_file = assert_fs::NamedTempFile::new("test_output.yaml").unwrap();
+
+    let config_arg = config_file.path().to_str().unwrap().to_string();
+    let output_arg = output_file.path().to_str().unwrap().to_string();
+    task::spawn_blocking(move || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args([
+            "get",
+            command,
+            "-c",
+            &config_arg,
+            "-o",
+            &output_arg,
+            "--source",
+            "gitlab",
+        ]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("data written to"));
+    })
+    .await
+    .unwrap();
+
+    fs::read_to_string(output_file.path()).unwrap()
+}
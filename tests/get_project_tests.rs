@@ -46,7 +46,7 @@ async fn test_get_project_data() {
     let (addr, server) = warp::serve(issues_route).bind_ephemeral(([127, 0, 0, 1], 0));
     tokio::spawn(server);
 
-    let output = run_get_project(addr).await.unwrap();
+    let (output, _) = run_get_project(addr).await.unwrap();
 
     assert!(output.contains("name: MOCK"));
     assert!(output.contains("id: ABC-123"));
@@ -68,9 +68,79 @@ async fn test_get_project_data() {
     assert!(!output.contains("dependencies: []"));
 }
 
+#[tokio::test()]
+async fn test_get_project_data_reads_blocking_issue_links() {
+    let issues_response = serde_json::json!({
+        "issues": [
+            {
+                "fields": {
+                    "statusCategory": { "name": "To Do" },
+                    "summary": "Ship",
+                    "issuelinks": [
+                        {
+                            "type": { "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                            "inwardIssue": { "key": "ABC-1" }
+                        },
+                        {
+                            "type": { "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                            "inwardIssue": { "key": "OTHER-9" }
+                        },
+                        {
+                            "type": { "name": "Relates", "inward": "relates to", "outward": "relates to" },
+                            "inwardIssue": { "key": "ABC-3" }
+                        }
+                    ]
+                },
+                "key": "ABC-2"
+            },
+            {
+                "fields": {
+                    "statusCategory": { "name": "To Do" },
+                    "summary": "Build",
+                    "issuelinks": [
+                        {
+                            "type": { "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                            "outwardIssue": { "key": "ABC-2" }
+                        }
+                    ]
+                },
+                "key": "ABC-1"
+            },
+            {
+                "fields": {
+                    "statusCategory": { "name": "To Do" },
+                    "summary": "Related"
+                },
+                "key": "ABC-3"
+            }
+        ]
+    });
+
+    let issues_route = warp::path("search")
+        .and(warp::path("jql"))
+        .and(warp::get())
+        .and(warp::query::<std::collections::HashMap<String, String>>())
+        .map(move |query: std::collections::HashMap<String, String>| {
+            assert!(query.get("fields").unwrap().contains("issuelinks"));
+            warp::reply::json(&issues_response)
+        });
+    let (addr, server) = warp::serve(issues_route).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let (output, stdout) = run_get_project(addr).await.unwrap();
+
+    assert!(output.contains("id: ABC-2"));
+    assert!(output.contains("dependencies:\n  - ABC-1\n"));
+    assert!(!output.contains("OTHER-9"));
+    assert!(!output.contains("- ABC-3"));
+    // Issues without links do not depend on their predecessor.
+    assert_eq!(output.matches("dependencies: null").count(), 2);
+    assert!(stdout.contains("ABC-2 -> OTHER-9"));
+}
+
 async fn run_get_project(
     socket_addr: std::net::SocketAddr,
-) -> Result<String, Box<dyn std::error::Error>> {
+) -> Result<(String, String), Box<dyn std::error::Error>> {
     let base_url = format!("http://{}", socket_addr);
     let config_yaml = format!(
         r#"
@@ -99,13 +169,15 @@ actual_end_date_field_id: actualEndDate
 
     let config_arg = config_path.to_str().unwrap().to_string();
     let output_arg = output_path.to_str().unwrap().to_string();
-    task::spawn_blocking(move || {
+    let stdout = task::spawn_blocking(move || {
         let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-        cmd.args(&["get", "project", "-c", &config_arg, "-o", &output_arg]);
+        cmd.args(["get", "project", "-c", &config_arg, "-o", &output_arg]);
 
-        cmd.assert()
+        let assert = cmd
+            .assert()
             .success()
             .stdout(predicate::str::contains("Project data written to"));
+        String::from_utf8(assert.get_output().stdout.clone()).unwrap()
     })
     .await
     .unwrap();
@@ -115,5 +187,104 @@ actual_end_date_field_id: actualEndDate
     let _ = fs::remove_file(config_path);
     let _ = fs::remove_file(output_path);
 
-    Ok(output)
+    Ok((output, stdout))
+}
+
+#[tokio::test()]
+async fn test_get_project_initiative_writes_one_project_per_epic() {
+    let issue = |key: &str, status: &str, parent: &str, parent_type: &str| {
+        serde_json::json!({
+            "key": key,
+            "fields": {
+                "summary": format!("Summary of {key}"),
+                "statusCategory": { "name": status },
+                "parent": { "key": parent, "fields": { "issuetype": { "name": parent_type } } }
+            }
+        })
+    };
+    let epics = serde_json::json!({
+        "issues": [
+            issue("EPIC-1", "Done", "INIT-1", "Initiative"),
+            issue("EPIC-2", "In Progress", "INIT-1", "Initiative")
+        ]
+    });
+    let epic1_stories = serde_json::json!({
+        "issues": [issue("ABC-1", "Done", "EPIC-1", "Epic")]
+    });
+    let epic2_stories = serde_json::json!({
+        "issues": [
+            issue("ABC-2", "In Progress", "EPIC-2", "Epic"),
+            issue("ABC-3", "To Do", "EPIC-2", "Epic")
+        ]
+    });
+
+    let issues_route = warp::path("search")
+        .and(warp::path("jql"))
+        .and(warp::get())
+        .and(warp::query::<std::collections::HashMap<String, String>>())
+        .map(move |query: std::collections::HashMap<String, String>| {
+            let jql = query.get("jql").unwrap();
+            let response = if jql.starts_with("parent = INIT-1") {
+                &epics
+            } else if jql.starts_with("parent = EPIC-1") {
+                &epic1_stories
+            } else if jql.starts_with("parent = EPIC-2") {
+                &epic2_stories
+            } else {
+                panic!("unexpected jql {jql}");
+            };
+            warp::reply::json(response)
+        });
+    let (addr, server) = warp::serve(issues_route).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+
+    let config_file = assert_fs::NamedTempFile::new("test_jira_config.yaml").unwrap();
+    config_file
+        .write_str(&format!(
+            "base_url: http://{addr}\nproject_key: MOCK\nproject_query: project = TEST\n"
+        ))
+        .unwrap();
+    unsafe {
+        env::set_var("JIRA_USERNAME", "mockuser");
+        env::set_var("JIRA_API_TOKEN", "mocktoken");
+    }
+
+    let output_dir = assert_fs::TempDir::new().unwrap();
+    let output = output_dir.child("roadmap.yaml");
+    let config_arg = config_file.path().to_str().unwrap().to_string();
+    let output_arg = output.path().to_str().unwrap().to_string();
+    task::spawn_blocking(move || {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args([
+            "get",
+            "project",
+            "-c",
+            &config_arg,
+            "-o",
+            &output_arg,
+            "--initiative",
+            "INIT-1",
+        ]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("roadmap.EPIC-2.report.yaml"));
+    })
+    .await
+    .unwrap();
+
+    let top_level = fs::read_to_string(output.path()).unwrap();
+    assert!(top_level.contains("name: INIT-1"));
+    assert!(top_level.contains("id: EPIC-2"));
+    assert!(top_level.contains("type: reference"));
+    assert!(top_level.contains("roadmap.EPIC-2.report.yaml"));
+    assert!(!top_level.contains("roadmap.EPIC-1.report.yaml"));
+
+    let epic2 = fs::read_to_string(output_dir.child("roadmap.EPIC-2.yaml").path()).unwrap();
+    assert!(epic2.contains("name: EPIC-2"));
+    assert!(epic2.contains("id: ABC-3"));
+    assert!(epic2.contains("subgraph: EPIC-2"));
+    output_dir
+        .child("roadmap.EPIC-1.yaml")
+        .assert(predicate::path::exists());
 }
@@ -95,7 +95,7 @@ work_packages:
     let output_arg = output_file.path().to_str().unwrap().to_string();
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "plot",
         "burndown",
         "-i",
@@ -192,7 +192,7 @@ work_packages:
     let calendar_arg = calendar_dir.path().to_str().unwrap().to_string();
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "plot",
         "burndown",
         "-i",
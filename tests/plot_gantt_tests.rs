@@ -38,7 +38,7 @@ work_packages:
     let output_arg = output_file.path().to_str().unwrap().to_string();
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "plot",
         "gantt",
         "-i",
@@ -109,7 +109,7 @@ free_date_ranges: []
     let calendar_arg = calendar_dir.path().to_str().unwrap().to_string();
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "plot",
         "gantt",
         "-i",
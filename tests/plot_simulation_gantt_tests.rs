@@ -117,7 +117,7 @@ work_packages:
     let output_file = assert_fs::NamedTempFile::new("gantt.md").unwrap();
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "plot",
         "simulation-gantt",
         "-i",
@@ -18,7 +18,7 @@ fn simulate_by_throughput() {
 
     let thoughput_file_name = "test_throughput.yaml";
 
-    let throughput_file = assert_fs::NamedTempFile::new(&thoughput_file_name).unwrap();
+    let throughput_file = assert_fs::NamedTempFile::new(thoughput_file_name).unwrap();
     throughput_file.write_str(throughput_yaml).unwrap();
     let throughput_arg = throughput_file.path().to_str().unwrap();
 
@@ -30,19 +30,19 @@ fn simulate_by_throughput() {
     let start_date_arg = "2026-01-30";
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "simulate",
         "throughput",
         "-f",
-        &throughput_arg,
+        throughput_arg,
         "-o",
-        &output_arg,
+        output_arg,
         "-n",
-        &iterations_arg,
+        iterations_arg,
         "-k",
-        &number_of_issues_arg,
+        number_of_issues_arg,
         "-s",
-        &start_date_arg,
+        start_date_arg,
     ]);
 
     cmd.assert().success().stdout(
@@ -62,24 +62,21 @@ fn simulate_by_throughput() {
     // The output should contain a report with the following structure:
     //  start_date: 2026-01-30
     //  simulated_items: 10
-    //  p0:
+    //  percentiles:
+    //  - percentile: 0.0
     //    days: 1
-    //    date: 2026-01-31
-    //  p50:
+    //    end_date: 2026-01-31
+    //  - percentile: 50.0
     //    days: 5
-    //    date: 2026-02-04
-    //  p85:
-    //    days: 10
-    //    date: 2026-02-09
-    //  p100:
-    //    days: 20
-    //    date: 2026-02-19
+    //    end_date: 2026-02-04
+    //  ...
     assert!(output.contains("start_date:"));
     assert!(output.contains("simulated_items:"));
-    assert!(output.contains("p0:"));
-    assert!(output.contains("p50:"));
-    assert!(output.contains("p85:"));
-    assert!(output.contains("p100:"));
+    assert!(output.contains("percentiles:"));
+    assert!(output.contains("percentile: 0.0"));
+    assert!(output.contains("percentile: 50.0"));
+    assert!(output.contains("percentile: 85.0"));
+    assert!(output.contains("percentile: 100.0"));
 }
 
 #[test]
@@ -100,7 +97,7 @@ fn simulate_by_throughput_parses_calendar_dir() {
         .unwrap();
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "simulate",
         "throughput",
         "-f",
@@ -121,18 +118,268 @@ fn simulate_by_throughput_parses_calendar_dir() {
 
     let output = std::fs::read_to_string(output_file.path()).unwrap();
     let value: Value = serde_yaml::from_str(&output).unwrap();
-    let p50_days = value
-        .get("p50")
-        .and_then(|v| v.get("days"))
-        .and_then(|v| v.as_f64())
-        .unwrap();
-    let p50_date = value
-        .get("p50")
-        .and_then(|v| v.get("end_date"))
-        .and_then(|v| v.as_str())
+    let p50 = value
+        .get("percentiles")
+        .and_then(|v| v.as_sequence())
+        .and_then(|entries| {
+            entries
+                .iter()
+                .find(|entry| entry.get("percentile").and_then(|v| v.as_f64()) == Some(50.0))
+        })
         .unwrap();
+    let p50_days = p50.get("days").and_then(|v| v.as_f64()).unwrap();
+    let p50_date = p50.get("end_date").and_then(|v| v.as_str()).unwrap();
 
     // Day 1 (Mon): capacity 0 => 0 progress; Day 2 (Tue): 1; Day 3 (Wed): 1 => done.
     assert_eq!(p50_days, 3.0);
     assert_eq!(p50_date, "2026-02-19");
 }
+
+#[test]
+fn simulate_by_throughput_samples_whole_weeks() {
+    // A week with 10 issues over 5 working days: 2 issues per working day.
+    let throughput_yaml =
+        "bucket: week\nthroughput:\n- date: 2026-02-02\n  completed_issues: 10\n  capacity: 5.0\n";
+
+    let throughput_file = assert_fs::NamedTempFile::new("test_throughput.yaml").unwrap();
+    throughput_file.write_str(throughput_yaml).unwrap();
+
+    let output_file = assert_fs::NamedTempFile::new("output.yaml").unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "throughput",
+        "-f",
+        throughput_file.path().to_str().unwrap(),
+        "-o",
+        output_file.path().to_str().unwrap(),
+        "-n",
+        "3",
+        "-k",
+        "14",
+        "-s",
+        "2026-02-16",
+    ]);
+
+    cmd.assert().success();
+
+    let output = std::fs::read_to_string(output_file.path()).unwrap();
+    let value: Value = serde_yaml::from_str(&output).unwrap();
+    let p50 = value
+        .get("percentiles")
+        .and_then(|v| v.as_sequence())
+        .and_then(|entries| {
+            entries
+                .iter()
+                .find(|entry| entry.get("percentile").and_then(|v| v.as_f64()) == Some(50.0))
+        })
+        .unwrap();
+
+    // Ten issues in the first week, four more on Monday and Tuesday after it.
+    assert_eq!(p50.get("days").and_then(|v| v.as_f64()), Some(9.0));
+    assert_eq!(
+        p50.get("end_date").and_then(|v| v.as_str()),
+        Some("2026-02-25")
+    );
+}
+
+#[test]
+fn simulate_by_throughput_records_sample_window() {
+    let throughput_yaml = "- date: 2026-01-05
+  completed_issues: 0
+- date: 2026-01-26
+  completed_issues: 1
+- date: 2026-01-30
+  completed_issues: 2";
+
+    let throughput_file = assert_fs::NamedTempFile::new("test_throughput.yaml").unwrap();
+    throughput_file.write_str(throughput_yaml).unwrap();
+
+    let output_file = assert_fs::NamedTempFile::new("output.yaml").unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "throughput",
+        "-f",
+        throughput_file.path().to_str().unwrap(),
+        "-o",
+        output_file.path().to_str().unwrap(),
+        "-n",
+        "20",
+        "-k",
+        "4",
+        "-s",
+        "2026-02-02",
+        "--window-weeks",
+        "2",
+        "--half-life-days",
+        "7",
+    ]);
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        "Sample window: 2026-01-26 - 2026-01-30 (2 samples, half-life 7 days)",
+    ));
+
+    let output = std::fs::read_to_string(output_file.path()).unwrap();
+    let value: Value = serde_yaml::from_str(&output).unwrap();
+    let window = value.get("sample_window").unwrap();
+    assert_eq!(
+        window.get("start_date").and_then(|v| v.as_str()),
+        Some("2026-01-26")
+    );
+    assert_eq!(window.get("samples").and_then(|v| v.as_u64()), Some(2));
+    assert_eq!(
+        window.get("half_life_days").and_then(|v| v.as_f64()),
+        Some(7.0)
+    );
+}
+
+#[test]
+fn simulate_by_throughput_compares_forecast_without_scope_growth() {
+    let throughput_file = assert_fs::NamedTempFile::new("test_throughput.yaml").unwrap();
+    throughput_file
+        .write_str("- date: 2026-01-26\n  completed_issues: 2\n")
+        .unwrap();
+    // One issue created per day on average.
+    let arrivals_file = assert_fs::NamedTempFile::new("test_arrivals.yaml").unwrap();
+    arrivals_file
+        .write_str("- date: 2026-01-26\n  completed_issues: 0\n- date: 2026-01-27\n  completed_issues: 2\n")
+        .unwrap();
+    let output_file = assert_fs::NamedTempFile::new("output.yaml").unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "throughput",
+        "-f",
+        throughput_file.path().to_str().unwrap(),
+        "-o",
+        output_file.path().to_str().unwrap(),
+        "-n",
+        "5",
+        "-k",
+        "4",
+        "-s",
+        "2026-02-02",
+        "--arrivals",
+        arrivals_file.path().to_str().unwrap(),
+    ]);
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("Scope growth: 1.00 new issues per working day").and(
+            predicate::str::contains("P50 | 4.00 | 2026-02-06 | 2.00 | 2026-02-04"),
+        ),
+    );
+
+    let output = std::fs::read_to_string(output_file.path()).unwrap();
+    let value: Value = serde_yaml::from_str(&output).unwrap();
+    let scope_growth = value.get("scope_growth").unwrap();
+    assert_eq!(
+        scope_growth
+            .get("model")
+            .and_then(|model| model.get("type"))
+            .and_then(|v| v.as_str()),
+        Some("arrival_rate")
+    );
+    assert!(scope_growth.get("percentiles_without_growth").is_some());
+}
+
+#[test]
+fn simulate_by_throughput_rejects_split_factor_with_arrivals() {
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "throughput",
+        "-f",
+        "throughput.yaml",
+        "-o",
+        "output.yaml",
+        "-k",
+        "4",
+        "--split-factor",
+        "1.1,1.5",
+        "--arrivals",
+        "arrivals.yaml",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn simulate_by_throughput_rejects_non_positive_half_life() {
+    let throughput_file = assert_fs::NamedTempFile::new("test_throughput.yaml").unwrap();
+    throughput_file
+        .write_str("- date: 2026-01-26\n  completed_issues: 1\n")
+        .unwrap();
+    let output_file = assert_fs::NamedTempFile::new("output.yaml").unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "throughput",
+        "-f",
+        throughput_file.path().to_str().unwrap(),
+        "-o",
+        output_file.path().to_str().unwrap(),
+        "-k",
+        "4",
+        "--half-life-days",
+        "0",
+    ]);
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "half-life must be greater than zero",
+    ));
+}
+
+#[test]
+fn simulate_items_until_date() {
+    // Deterministic throughput distribution: always 2 issues/day.
+    let throughput_yaml = "- date: 2026-02-13\n  completed_issues: 2\n";
+
+    let throughput_file = assert_fs::NamedTempFile::new("test_throughput.yaml").unwrap();
+    throughput_file.write_str(throughput_yaml).unwrap();
+
+    let output_file = assert_fs::NamedTempFile::new("items.yaml").unwrap();
+    let output_arg = output_file.path().to_str().unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "items",
+        "-f",
+        throughput_file.path().to_str().unwrap(),
+        "-o",
+        output_arg,
+        "-n",
+        "10",
+        "-s",
+        "2026-02-16",
+        "--until",
+        "2026-02-27",
+        "--percentiles",
+        "70",
+    ]);
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("Item Forecast Report")
+            .and(predicate::str::contains("Percentile | Items"))
+            .and(predicate::str::contains("P70 | 20")),
+    );
+
+    // Two weeks with the default calendar: 10 workdays at 2 items/day.
+    let output = fs::read_to_string(output_arg).unwrap();
+    let value: Value = serde_yaml::from_str(&output).unwrap();
+    let percentiles = value.get("percentiles").unwrap().as_sequence().unwrap();
+    assert_eq!(percentiles.len(), 6);
+    assert!(
+        percentiles
+            .iter()
+            .all(|entry| entry.get("items").and_then(|v| v.as_u64()) == Some(20))
+    );
+    assert!(fs::metadata(format!("{output_arg}.png")).is_ok());
+}
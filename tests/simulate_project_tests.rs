@@ -72,7 +72,7 @@ work_packages:
     let histogram_path = format!("{output_arg}.png");
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "simulate",
         "project",
         "-i",
@@ -83,21 +83,29 @@ work_packages:
         "2026-02-01",
         "--iterations",
         "25",
+        "--percentiles",
+        "70,95",
     ]);
 
     cmd.assert().success().stdout(
         predicate::str::contains("Simulation result written to")
             .and(predicate::str::contains("Simulation Report"))
             .and(predicate::str::contains("Percentile | Days | Date"))
-            .and(predicate::str::contains("P85")),
+            .and(predicate::str::contains("P85"))
+            .and(predicate::str::contains("P70 |"))
+            .and(predicate::str::contains("P95 |")),
     );
 
     let output = fs::read_to_string(output_file.path()).unwrap();
     assert!(output.contains("start_date: 2026-02-01"));
     assert!(output.contains("simulated_items:"));
-    assert!(output.contains("p0:"));
+    assert!(output.contains("percentile: 0.0"));
+    assert!(output.contains("percentile: 95.0"));
 
     assert!(fs::metadata(&histogram_path).is_ok());
+    assert!(fs::metadata(format!("{output_arg}.tornado.png")).is_ok());
+    assert!(output.contains("criticality_index:"));
+    assert!(output.contains("duration_correlation:"));
 }
 
 #[test]
@@ -166,7 +174,7 @@ work_packages:
     let output_arg = output_file.path().to_str().unwrap().to_string();
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "simulate",
         "project",
         "-i",
@@ -190,9 +198,9 @@ work_packages:
     let output = fs::read_to_string(output_file.path()).unwrap();
 
     // End date of done issue
-    assert!(output.contains("      end_date: 2026-01-06"));
+    assert!(output.contains("    end_date: 2026-01-06"));
     // End date of in-progress issue
-    assert!(output.contains("      end_date: 2026-01-23"));
+    assert!(output.contains("    end_date: 2026-01-23"));
 }
 
 #[test]
@@ -221,7 +229,7 @@ work_packages:
 "#;
 
     let input_file = assert_fs::NamedTempFile::new("project.yaml").unwrap();
-    input_file.write_str(&project_yaml).unwrap();
+    input_file.write_str(project_yaml).unwrap();
     let input_arg = input_file.path().to_str().unwrap().to_string();
 
     let calendar_yaml = r#"
@@ -241,19 +249,19 @@ free_date_ranges:
     let output_arg = output_file.path().to_str().unwrap();
 
     let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
-    cmd.args(&[
+    cmd.args([
         "simulate",
         "project",
         "-i",
         &input_arg,
         "-o",
-        &output_arg,
+        output_arg,
         "-s",
         "2026-02-01",
         "--iterations",
         "25",
         "--calendar-dir",
-        &calendar_dir_path,
+        calendar_dir_path,
     ]);
 
     cmd.assert().success().stdout(
@@ -266,5 +274,458 @@ free_date_ranges:
     let output = fs::read_to_string(output_arg).unwrap();
     assert!(output.contains("start_date: 2026-02-01"));
     assert!(output.contains("simulated_items:"));
-    assert!(output.contains("p0:"));
+    assert!(output.contains("percentile: 0.0"));
+}
+
+#[test]
+fn simulate_project_reports_deadline_probabilities() {
+    let project_yaml = r#"
+name: Demo
+work_packages:
+  - id: WP0
+    estimate:
+      type: three_point
+      optimistic: 4
+      most_likely: 4
+      pessimistic: 4
+  - id: M1
+    estimate:
+      type: milestone
+    due_date: 2026-02-10
+    dependencies: [WP0]
+"#;
+
+    let input_file = assert_fs::NamedTempFile::new("project.yaml").unwrap();
+    input_file.write_str(project_yaml).unwrap();
+    let output_file = assert_fs::NamedTempFile::new("simulation.yaml").unwrap();
+    let output_arg = output_file.path().to_str().unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "project",
+        "-i",
+        input_file.path().to_str().unwrap(),
+        "-o",
+        output_arg,
+        "-s",
+        "2026-02-02",
+        "--iterations",
+        "10",
+        "--deadline",
+        "2026-02-05",
+    ]);
+
+    // WP0 and the milestone end on 2026-02-06, a Friday.
+    cmd.assert().success().stdout(
+        predicate::str::contains("Target | Date | Probability | Slack at P85")
+            .and(predicate::str::contains(
+                "Project | 2026-02-05 | 0.0% | -1 days",
+            ))
+            .and(predicate::str::contains(
+                "M1 | 2026-02-10 | 100.0% | 4 days",
+            )),
+    );
+
+    let output = fs::read_to_string(output_arg).unwrap();
+    assert!(output.contains("deadlines:"));
+    assert!(output.contains("due_date: 2026-02-10"));
+}
+
+#[test]
+fn simulate_project_adds_requested_percentiles_to_the_standard_set() {
+    let project_yaml = r#"
+name: Demo
+work_packages:
+  - id: WP0
+    estimate:
+      type: three_point
+      optimistic: 4
+      most_likely: 4
+      pessimistic: 4
+"#;
+
+    let input_file = assert_fs::NamedTempFile::new("project.yaml").unwrap();
+    input_file.write_str(project_yaml).unwrap();
+    let output_file = assert_fs::NamedTempFile::new("simulation.yaml").unwrap();
+    let output_arg = output_file.path().to_str().unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "project",
+        "-i",
+        input_file.path().to_str().unwrap(),
+        "-o",
+        output_arg,
+        "-s",
+        "2026-02-02",
+        "--iterations",
+        "10",
+        "--percentiles",
+        "95,70",
+    ]);
+
+    cmd.assert().success();
+
+    let output = fs::read_to_string(output_arg).unwrap();
+    let report: serde_yaml::Value = serde_yaml::from_str(&output).unwrap();
+    let percentiles: Vec<f64> = report
+        .get("percentiles")
+        .and_then(|value| value.as_sequence())
+        .unwrap()
+        .iter()
+        .filter_map(|entry| entry.get("percentile").and_then(|value| value.as_f64()))
+        .collect();
+    assert_eq!(percentiles, vec![0.0, 15.0, 50.0, 70.0, 85.0, 95.0, 100.0]);
+}
+
+#[test]
+fn simulate_project_samples_cycle_times_by_label_with_a_wip_limit() {
+    let project_yaml = r#"
+name: Demo
+work_packages:
+  - id: WP0
+    labels: [backend]
+  - id: WP1
+    labels: [frontend]
+"#;
+    let cycle_time_yaml = r#"
+- id: DONE-1
+  start_date: 2026-01-05
+  done_date: 2026-01-07
+  cycle_time_days: 3
+  labels: [backend]
+- id: DONE-2
+  start_date: 2026-01-12
+  done_date: 2026-01-13
+  cycle_time_days: 2
+  labels: [frontend]
+"#;
+
+    let input_file = assert_fs::NamedTempFile::new("project.yaml").unwrap();
+    input_file.write_str(project_yaml).unwrap();
+    let cycle_time_file = assert_fs::NamedTempFile::new("cycle_times.yaml").unwrap();
+    cycle_time_file.write_str(cycle_time_yaml).unwrap();
+    let output_file = assert_fs::NamedTempFile::new("simulation.yaml").unwrap();
+    let output_arg = output_file.path().to_str().unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "project",
+        "-i",
+        input_file.path().to_str().unwrap(),
+        "-o",
+        output_arg,
+        "-s",
+        "2026-02-02",
+        "--iterations",
+        "10",
+        "--cycle-times",
+        cycle_time_file.path().to_str().unwrap(),
+        "--group-by",
+        "label",
+        "--wip-limit",
+        "1",
+    ]);
+
+    cmd.assert().success();
+
+    // One package after the other: 3 + 2 calendar days.
+    let output = fs::read_to_string(output_arg).unwrap();
+    assert!(output.contains("end_date: 2026-02-07"));
+    assert!(!output.contains("end_date: 2026-02-05"));
+}
+
+#[test]
+fn simulate_project_group_by_requires_cycle_times() {
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "project",
+        "-i",
+        "project.yaml",
+        "-o",
+        "simulation.yaml",
+        "--group-by",
+        "size",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--cycle-times"));
+}
+
+#[test]
+fn simulate_project_pert_shape_requires_modified_pert() {
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "project",
+        "-i",
+        "project.yaml",
+        "-o",
+        "simulation.yaml",
+        "--distribution",
+        "triangular",
+        "--pert-shape",
+        "6",
+    ]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("--pert-shape"));
+}
+
+#[test]
+fn simulate_project_samples_the_selected_distribution() {
+    let project_yaml = r#"
+name: Demo
+work_packages:
+  - id: WP0
+    estimate:
+      type: three_point
+      optimistic: 0
+      most_likely: 1
+      pessimistic: 4
+  - id: WP1
+    estimate:
+      type: three_point
+      optimistic: 0
+      most_likely: 1
+      pessimistic: 4
+      distribution: uniform
+"#;
+
+    let input_file = assert_fs::NamedTempFile::new("project.yaml").unwrap();
+    input_file.write_str(project_yaml).unwrap();
+    let output_file = assert_fs::NamedTempFile::new("simulation.yaml").unwrap();
+    let output_arg = output_file.path().to_str().unwrap();
+
+    let simulate = |distribution: &str| {
+        let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+        cmd.args([
+            "simulate",
+            "project",
+            "-i",
+            input_file.path().to_str().unwrap(),
+            "-o",
+            output_arg,
+            "--iterations",
+            "10",
+            "--distribution",
+            distribution,
+        ]);
+        cmd.assert()
+    };
+
+    simulate("triangular").success();
+    // A log-normal distribution cannot be fitted to an optimistic value of 0.
+    simulate("log-normal")
+        .failure()
+        .stderr(predicate::str::contains("WP0"));
+}
+
+#[test]
+fn simulate_project_compares_correlated_and_independent_estimates() {
+    let project_yaml = r#"
+name: Demo
+work_packages:
+  - id: WP0
+    correlation_group: backend
+    estimate:
+      type: three_point
+      optimistic: 2
+      most_likely: 4
+      pessimistic: 12
+  - id: WP1
+    correlation_group: backend
+    estimate:
+      type: three_point
+      optimistic: 2
+      most_likely: 4
+      pessimistic: 12
+  - id: WP2
+    estimate:
+      type: three_point
+      optimistic: 1
+      most_likely: 2
+      pessimistic: 3
+"#;
+
+    let input_file = assert_fs::NamedTempFile::new("project.yaml").unwrap();
+    input_file.write_str(project_yaml).unwrap();
+    let output_file = assert_fs::NamedTempFile::new("simulation.yaml").unwrap();
+    let output_arg = output_file.path().to_str().unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "project",
+        "-i",
+        input_file.path().to_str().unwrap(),
+        "-o",
+        output_arg,
+        "--iterations",
+        "100",
+        "--seed",
+        "1",
+        "--correlation",
+        "0.6",
+    ]);
+
+    cmd.assert().success().stdout(
+        predicate::str::contains("Correlation: 0.60 within 1 correlation group").and(
+            predicate::str::contains("Days without correlation | Date without correlation"),
+        ),
+    );
+
+    let output = fs::read_to_string(output_arg).unwrap();
+    assert!(output.contains("correlation:"));
+    assert!(output.contains("grouping: correlation_group"));
+    assert!(output.contains("percentiles_without_correlation:"));
+}
+
+#[test]
+fn simulate_project_reports_risks_and_lists_them() {
+    let project_yaml = r#"
+name: Demo
+work_packages:
+  - id: WP0
+    estimate:
+      type: three_point
+      optimistic: 2
+      most_likely: 4
+      pessimistic: 8
+  - id: WP1
+    dependencies: [WP0]
+    estimate:
+      type: three_point
+      optimistic: 1
+      most_likely: 2
+      pessimistic: 3
+risks:
+  - id: R1
+    description: Supplier delivers late
+    probability: 0.5
+    impact:
+      optimistic: 2
+      most_likely: 5
+      pessimistic: 10
+    affects: [WP1]
+"#;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input_file = temp.child("project.yaml");
+    input_file.write_str(project_yaml).unwrap();
+    let output_file = temp.child("simulation.yaml");
+    let output_arg = output_file.path().to_str().unwrap();
+    let markdown_file = temp.child("risks.md");
+
+    let mut simulate = assert_cmd::cargo_bin_cmd!("forecasts");
+    simulate.args([
+        "simulate",
+        "project",
+        "-i",
+        input_file.path().to_str().unwrap(),
+        "-o",
+        output_arg,
+        "--iterations",
+        "200",
+        "--seed",
+        "1",
+    ]);
+    simulate
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Risks:"))
+        .stdout(predicate::str::contains(
+            "Risk | Probability | Occurred | Added to P85",
+        ));
+
+    let output = fs::read_to_string(output_arg).unwrap();
+    assert!(output.contains("risks:"));
+    assert!(output.contains("p85_contribution_days:"));
+
+    let mut plot = assert_cmd::cargo_bin_cmd!("forecasts");
+    plot.args([
+        "plot",
+        "risks",
+        "-r",
+        output_arg,
+        "-o",
+        markdown_file.path().to_str().unwrap(),
+    ]);
+    plot.assert()
+        .success()
+        .stdout(predicate::str::contains("List of risks written to"));
+
+    let markdown = fs::read_to_string(markdown_file.path()).unwrap();
+    assert!(markdown.starts_with("# List of risks"));
+    assert!(markdown.contains("| R1 | Supplier delivers late | 50% |"));
+}
+
+#[test]
+fn simulate_project_reports_date_constraint_violations() {
+    let project_yaml = r#"
+name: Demo
+work_packages:
+  - id: WP0
+    estimate:
+      type: three_point
+      optimistic: 4
+      most_likely: 5
+      pessimistic: 8
+  - id: WP1
+    dependencies: [WP0]
+    estimate:
+      type: three_point
+      optimistic: 1
+      most_likely: 2
+      pessimistic: 3
+    constraint:
+      type: must_finish_on_or_before
+      date: 2026-01-03
+  - id: M1
+    dependencies: [WP1]
+    estimate:
+      type: milestone
+    constraint:
+      type: fixed_date
+      date: 2026-03-01
+"#;
+
+    let input_file = assert_fs::NamedTempFile::new("project.yaml").unwrap();
+    input_file.write_str(project_yaml).unwrap();
+    let output_file = assert_fs::NamedTempFile::new("simulation.yaml").unwrap();
+    let output_arg = output_file.path().to_str().unwrap();
+
+    let mut cmd = assert_cmd::cargo_bin_cmd!("forecasts");
+    cmd.args([
+        "simulate",
+        "project",
+        "-i",
+        input_file.path().to_str().unwrap(),
+        "-o",
+        output_arg,
+        "--start-date",
+        "2026-01-01",
+        "--iterations",
+        "100",
+    ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Date constraints:"))
+        .stdout(predicate::str::contains(
+            "WP1 | must finish on or before | 2026-01-03 | 100.0% |",
+        ))
+        .stdout(predicate::str::contains(
+            "M1 | fixed date | 2026-03-01 | 0.0% | 0.0 days",
+        ));
+
+    let output = fs::read_to_string(output_arg).unwrap();
+    assert!(output.contains("type: must_finish_on_or_before"));
+    assert!(output.contains("violations: 100"));
 }